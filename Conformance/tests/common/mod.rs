@@ -1,2 +1 @@
-pub mod loader;
 pub mod runner;
@@ -7,18 +7,18 @@ use std::{
 };
 
 use datatest_stable::Result;
-use luau_builder::LuauBuilder;
+use luau_compiler::{Compiler, Output};
 use luau_printer::{
 	LuauPrinter,
 	library::{LibraryPrinter, LibrarySections, NamesFinder},
 };
 use wast::{
 	QuoteWat, WastArg, WastExecute, WastInvoke, WastRet, WastThread, Wat,
-	core::{NanPattern, WastArgCore, WastRetCore},
+	core::{NanPattern, V128Const, V128Pattern, WastArgCore, WastRetCore},
 	token::{F32, F64, Id, Span},
 };
 
-use self::common::{loader::Loader, runner::Runner};
+use self::common::runner::Runner;
 
 mod common;
 
@@ -33,8 +33,7 @@ struct Luau {
 	library_printer: LibraryPrinter,
 	references: Vec<&'static str>,
 
-	loader: Loader,
-	luau_builder: LuauBuilder,
+	compiler: Compiler,
 	luau_printer: LuauPrinter,
 }
 
@@ -52,8 +51,7 @@ impl Luau {
 			library_printer: LibraryPrinter::new(),
 			references: Vec::new(),
 
-			loader: Loader::new(),
-			luau_builder: LuauBuilder::new(),
+			compiler: Compiler::new().optimize(true),
 			luau_printer: LuauPrinter::new(),
 		}
 	}
@@ -83,8 +81,7 @@ impl Luau {
 	}
 
 	fn fmt_source(&mut self, data: &[u8]) -> Result<()> {
-		let graph = self.loader.run(data);
-		let tree = self.luau_builder.run(&graph);
+		let Output { tree, .. } = self.compiler.compile(data)?;
 
 		NamesFinder::new(&mut self.references).run(&tree);
 
@@ -184,6 +181,21 @@ impl Luau {
 		Ok(())
 	}
 
+	fn fmt_argument_v128(&mut self, source: &V128Const) -> Result<()> {
+		let source = u128::from_le_bytes(source.to_le_bytes());
+		let [source_1, source_2, source_3, source_4] =
+			[0, 32, 64, 96].map(|shift| u32::try_from((source >> shift) & 0xFFFF_FFFF).unwrap());
+
+		self.references.push("create_v128_from_u32");
+
+		write!(
+			self.file,
+			"rt_create_v128_from_u32(0x{source_1:08X}, 0x{source_2:08X}, 0x{source_3:08X}, 0x{source_4:08X}) --[[ {source:#034x}_v128 ]]"
+		)?;
+
+		Ok(())
+	}
+
 	fn fmt_argument(&mut self, argument: WastArg) -> Result<()> {
 		let WastArg::Core(argument) = argument else {
 			unimplemented!()
@@ -210,7 +222,11 @@ impl Luau {
 
 				Ok(())
 			}
-			WastArgCore::V128(_) => unimplemented!(),
+			WastArgCore::V128(v128) => {
+				self.fmt_argument_v128(&v128)?;
+
+				Ok(())
+			}
 			WastArgCore::RefNull(_) => write!(self.file, "nil --[[ heap ]]"),
 			WastArgCore::RefExtern(_) => write!(self.file, "newproxy(false) --[[ extern ]]"),
 			WastArgCore::RefHost(_) => write!(self.file, "newproxy(false) --[[ host ]]"),
@@ -340,6 +356,57 @@ impl Luau {
 		Ok(())
 	}
 
+	fn fmt_assert_equal_v128(&mut self, source: &V128Const) -> Result<()> {
+		let source = u128::from_le_bytes(source.to_le_bytes());
+		let [source_1, source_2, source_3, source_4] =
+			[0, 32, 64, 96].map(|shift| u32::try_from((source >> shift) & 0xFFFF_FFFF).unwrap());
+
+		self.references.push("assert_equal_v128");
+
+		write!(
+			self.file,
+			"hn_assert_equal_v128(0x{source_1:08X}, 0x{source_2:08X}, 0x{source_3:08X}, 0x{source_4:08X}) --[[ {source:#034x}_v128 ]]"
+		)?;
+
+		Ok(())
+	}
+
+	fn fmt_assert_lanes<'a, I>(&mut self, name: &'static str, lanes: I) -> Result<()>
+	where
+		I: IntoIterator<Item = WastRetCore<'a>>,
+	{
+		self.references.push(name);
+
+		write!(self.file, "hn_{name}({{ ")?;
+
+		for (lane, index) in lanes.into_iter().zip(0..) {
+			if index != 0 {
+				write!(self.file, ", ")?;
+			}
+
+			self.fmt_assert_pattern(WastRet::Core(lane))?;
+		}
+
+		write!(self.file, " }})")?;
+
+		Ok(())
+	}
+
+	fn fmt_assert_pattern_v128(&mut self, pattern: V128Pattern) -> Result<()> {
+		match pattern {
+			V128Pattern::I8x16(lanes) => self.fmt_assert_equal_v128(&V128Const::I8x16(lanes)),
+			V128Pattern::I16x8(lanes) => self.fmt_assert_equal_v128(&V128Const::I16x8(lanes)),
+			V128Pattern::I32x4(lanes) => self.fmt_assert_equal_v128(&V128Const::I32x4(lanes)),
+			V128Pattern::I64x2(lanes) => self.fmt_assert_equal_v128(&V128Const::I64x2(lanes)),
+			V128Pattern::F32x4(lanes) => {
+				self.fmt_assert_lanes("assert_lanes_f32x4", lanes.map(WastRetCore::F32))
+			}
+			V128Pattern::F64x2(lanes) => {
+				self.fmt_assert_lanes("assert_lanes_f64x2", lanes.map(WastRetCore::F64))
+			}
+		}
+	}
+
 	fn fmt_assert_pattern(&mut self, result: WastRet) -> Result<()> {
 		let WastRet::Core(result) = result else {
 			unimplemented!()
@@ -400,8 +467,13 @@ impl Luau {
 				write!(self.file, "hn_assert_ref_extern")
 			}
 
-			WastRetCore::V128(_)
-			| WastRetCore::RefHost(_)
+			WastRetCore::V128(v128) => {
+				self.fmt_assert_pattern_v128(v128)?;
+
+				Ok(())
+			}
+
+			WastRetCore::RefHost(_)
 			| WastRetCore::RefFunc(_)
 			| WastRetCore::RefAny
 			| WastRetCore::RefEq
@@ -528,7 +600,15 @@ impl Runner for Luau {
 		Ok(())
 	}
 
-	fn on_assert_exception(&mut self, _span: Span, _exec: WastExecute) -> Result<()> {
+	fn on_assert_exception(&mut self, _span: Span, exec: WastExecute) -> Result<()> {
+		self.references.push("assert_exception");
+
+		writeln!(self.file, "hn_assert_exception(function()")?;
+
+		self.fmt_execute(exec)?;
+
+		writeln!(self.file, "\nend)")?;
+
 		Ok(())
 	}
 
@@ -619,5 +699,5 @@ fn luau(path: &Path) -> Result<()> {
 }
 
 datatest_stable::harness! {
-	{ test = luau, root = "Suite", pattern = r"^(?!simd_)\w+\.wast$" },
+	{ test = luau, root = "Suite", pattern = r"^\w+\.wast$" },
 }
@@ -1,12 +1,17 @@
+use alloc::vec::Vec;
 use control_flow_graph::{
 	ControlFlowGraph,
 	instruction::{
 		ExtendType, IntegerBinaryOperator, IntegerCompareOperator, IntegerType,
-		IntegerUnaryOperator, LoadType, Location, NumberBinaryOperator, NumberCompareOperator,
-		NumberType, NumberUnaryOperator, StoreType,
+		IntegerUnaryOperator, LaneType, LoadType, Location, NumberBinaryOperator,
+		NumberCompareOperator, NumberType, NumberUnaryOperator, StoreType, V128BinaryOperator,
+		V128ReduceOperator, V128UnaryOperator,
 	},
 };
-use wasmparser::{BlockType, BrTable, FuncType, Ieee32, Ieee64, MemArg, Operator, OperatorsReader};
+use wasmparser::{
+	BlockType, BrTable, Catch, FuncType, Ieee32, Ieee64, MemArg, Operator, OperatorsReader,
+	TryTable, V128,
+};
 
 use crate::{
 	code_builder::CodeBuilder,
@@ -14,9 +19,16 @@ use crate::{
 	types::Types,
 };
 
+struct Handler {
+	catches: Vec<Catch>,
+	level: usize,
+}
+
 pub struct BasicBlockBuilder {
 	code_builder: CodeBuilder,
 	stack_builder: StackBuilder,
+	handlers: Vec<Handler>,
+	memory_64_flags: Vec<bool>,
 }
 
 impl BasicBlockBuilder {
@@ -24,6 +36,8 @@ impl BasicBlockBuilder {
 		Self {
 			code_builder: CodeBuilder::new(),
 			stack_builder: StackBuilder::new(),
+			handlers: Vec::new(),
+			memory_64_flags: Vec::new(),
 		}
 	}
 
@@ -79,11 +93,32 @@ impl BasicBlockBuilder {
 		self.stack_builder.set_top(top);
 	}
 
+	fn handle_try_table(&mut self, types: &Types, try_table: TryTable) {
+		let TryTable { ty, catches } = try_table;
+
+		self.handlers.push(Handler {
+			catches,
+			level: self.stack_builder.get_level_count(),
+		});
+
+		self.stack_builder.push_level(types, ty, None);
+	}
+
 	fn handle_end(&mut self) {
 		let top = self.stack_builder.get_top();
 		let level = self.stack_builder.pull_level();
 
 		self.code_builder.handle_level(level, top);
+
+		let count = self.stack_builder.get_level_count();
+
+		if self
+			.handlers
+			.last()
+			.is_some_and(|handler| handler.level == count)
+		{
+			self.handlers.pop();
+		}
 	}
 
 	fn handle_br(&mut self, relative_depth: u32) {
@@ -115,25 +150,176 @@ impl BasicBlockBuilder {
 		self.stack_builder.jump_to_level(id, 0, 0);
 	}
 
-	fn handle_call(&mut self, function: u32, r#type: &FuncType) {
+	fn add_catch(&mut self, types: &Types, exception: u16, level: usize, catch: Catch) {
+		let (tag, label, reference) = match catch {
+			Catch::One { tag, label } => (Some(tag), label, false),
+			Catch::OneRef { tag, label } => (Some(tag), label, true),
+			Catch::All { label } => (None, label, false),
+			Catch::AllRef { label } => (None, label, true),
+		};
+
+		let top = self.stack_builder.get_top();
+		let test = tag.map(|tag| {
+			self.code_builder
+				.add_exception_test(SHARED_LOCAL, exception, tag.try_into().unwrap());
+
+			self.code_builder.add_local_branch(SHARED_LOCAL, 2)
+		});
+
+		if let Some(tag) = tag {
+			let count = types.get_tag_type(tag).params().len().try_into().unwrap();
+
+			for index in 0..count {
+				let destination = self.stack_builder.push_local();
+
+				self.code_builder
+					.add_exception_get(destination, exception, index);
+			}
+		}
+
+		if reference {
+			let destination = self.stack_builder.push_local();
+
+			self.code_builder.add_local_set(destination, exception);
+		}
+
+		let id = self.code_builder.add_basic_block(1);
+		let label = usize::try_from(label).unwrap();
+
+		// Labels of catch clauses are relative to the outside of the `try_table`.
+		self.stack_builder.jump_to_level(id, 0, level - label - 1);
+		self.stack_builder.set_top(top);
+
+		if let Some(test) = test {
+			self.code_builder.set_jump_destination(test, 0, id + 1);
+		}
+	}
+
+	// Exceptions are tested against every catch clause from the innermost
+	// `try_table` outwards, and are thrown again if none of them match.
+	fn add_exception_dispatch(&mut self, types: &Types, exception: u16) -> u16 {
+		let handlers = core::mem::take(&mut self.handlers);
+
+		for Handler { catches, level } in handlers.iter().rev() {
+			for &catch in catches {
+				self.add_catch(types, exception, *level, catch);
+			}
+		}
+
+		self.handlers = handlers;
+
+		let top = self.stack_builder.get_top();
+		let id = self.code_builder.add_throw(exception);
+
+		self.stack_builder.set_top(u16::MAX);
+		self.stack_builder.jump_to_level(id, 0, 0);
+		self.stack_builder.set_top(top);
+
+		id
+	}
+
+	fn add_call(
+		&mut self,
+		types: &Types,
+		destinations: (u16, u16),
+		sources: (u16, u16),
+		tail: bool,
+	) {
+		// Tail calls leave the function before their callee runs, so they
+		// are never protected by its handlers.
+		if tail || self.handlers.is_empty() {
+			self.code_builder
+				.add_call(destinations, sources, SHARED_LOCAL, tail, false);
+
+			return;
+		}
+
+		// Protected calls produce the exception they threw, or null, as an
+		// extra result after their normal ones.
+		let exception = self.stack_builder.push_local();
+
+		self.code_builder.add_call(
+			(destinations.0, exception + 1),
+			sources,
+			SHARED_LOCAL,
+			false,
+			true,
+		);
+
+		self.code_builder.add_ref_is_null(SHARED_LOCAL, exception);
+
+		let check = self.code_builder.add_local_branch(SHARED_LOCAL, 2);
+		let throw = self.add_exception_dispatch(types, exception);
+
+		self.code_builder.set_jump_destination(check, 1, throw + 1);
+		self.stack_builder.pull_local();
+	}
+
+	fn handle_call(&mut self, types: &Types, function: u32, r#type: &FuncType, tail: bool) {
 		let function = function.try_into().unwrap();
 		let (destinations, sources) = self.stack_builder.load_function_type(r#type);
 
 		self.code_builder.add_ref_function(SHARED_LOCAL, function);
-		self.code_builder
-			.add_call(destinations, sources, SHARED_LOCAL);
+		self.add_call(types, destinations, sources, tail);
 	}
 
-	fn handle_call_indirect(&mut self, table: u32, r#type: &FuncType) {
+	fn handle_call_indirect(&mut self, types: &Types, table: u32, r#type: u32, tail: bool) {
 		let function = Location {
 			reference: table.try_into().unwrap(),
 			offset: self.stack_builder.pull_local(),
 		};
-		let (destinations, sources) = self.stack_builder.load_function_type(r#type);
+		let (destinations, sources) = self
+			.stack_builder
+			.load_function_type(types.get_type(r#type).unwrap_func());
 
 		self.code_builder.add_table_get(SHARED_LOCAL, function);
 		self.code_builder
-			.add_call(destinations, sources, SHARED_LOCAL);
+			.add_function_check(SHARED_LOCAL, SHARED_LOCAL, r#type);
+		self.add_call(types, destinations, sources, tail);
+	}
+
+	// The results of a tail call are the results of the function, so we
+	// treat it as a normal call that is immediately followed by a return.
+	fn handle_return_call(&mut self, types: &Types, function: u32, r#type: &FuncType) {
+		self.handle_call(types, function, r#type, true);
+		self.handle_return();
+	}
+
+	fn handle_return_call_indirect(&mut self, types: &Types, table: u32, r#type: u32) {
+		self.handle_call_indirect(types, table, r#type, true);
+		self.handle_return();
+	}
+
+	fn handle_throw(&mut self, types: &Types, tag: u32) {
+		let (_, sources) = self
+			.stack_builder
+			.load_function_type(types.get_tag_type(tag));
+
+		let exception = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_exception_new(exception, sources, tag.try_into().unwrap());
+
+		self.add_exception_dispatch(types, exception);
+		self.stack_builder.set_top(u16::MAX);
+	}
+
+	fn handle_throw_ref(&mut self, types: &Types) {
+		let exception = self.stack_builder.get_top() - 1;
+
+		if self.handlers.is_empty() {
+			self.add_exception_dispatch(types, exception);
+		} else {
+			self.code_builder.add_ref_is_null(SHARED_LOCAL, exception);
+
+			let check = self.code_builder.add_local_branch(SHARED_LOCAL, 2);
+			let throw = self.add_exception_dispatch(types, exception);
+
+			// Null references skip the handlers so that throwing them traps.
+			self.code_builder.set_jump_destination(check, 1, throw);
+		}
+
+		self.stack_builder.set_top(u16::MAX);
 	}
 
 	const fn handle_drop(&mut self) {
@@ -207,7 +393,54 @@ impl BasicBlockBuilder {
 		self.code_builder.add_global_set(destination, source);
 	}
 
-	fn add_memory_offset(&mut self, destination: u16, offset: u64) {
+	fn is_memory_64(&self, memory: u16) -> bool {
+		self.memory_64_flags[usize::from(memory)]
+	}
+
+	// 64-bit addresses become plain numbers so that any address past the
+	// end of memory, including ones that would overflow, stays out of bounds.
+	// Accesses then share the `memory.luau` sections of 32-bit memories, so
+	// there are no sections specific to 64-bit memories in the runtime.
+	fn add_memory_64_address(&mut self, destination: u16) {
+		self.code_builder.add_integer_convert_to_number(
+			destination,
+			destination,
+			false,
+			NumberType::F64,
+			IntegerType::I64,
+		);
+	}
+
+	fn add_memory_64_offset(&mut self, destination: u16, offset: u64) {
+		self.add_memory_64_address(destination);
+
+		if offset == 0 {
+			return;
+		}
+
+		let high = f64::from(u32::try_from(offset >> 32).unwrap());
+		let low = f64::from(u32::try_from(offset & 0xFFFF_FFFF).unwrap());
+
+		self.code_builder
+			.add_f64_constant(SHARED_LOCAL, high * 4_294_967_296.0 + low);
+		self.code_builder.add_number_binary_operation(
+			destination,
+			destination,
+			SHARED_LOCAL,
+			NumberType::F64,
+			NumberBinaryOperator::Add,
+		);
+	}
+
+	fn add_memory_offset(&mut self, location: Location, offset: u64) {
+		let destination = location.offset;
+
+		if self.is_memory_64(location.reference) {
+			self.add_memory_64_offset(destination, offset);
+
+			return;
+		}
+
 		if offset == 0 {
 			return;
 		}
@@ -231,7 +464,7 @@ impl BasicBlockBuilder {
 			offset: self.stack_builder.pull_local(),
 		};
 
-		self.add_memory_offset(source.offset, info.offset);
+		self.add_memory_offset(source, info.offset);
 
 		let destination = self.stack_builder.push_local();
 
@@ -246,7 +479,7 @@ impl BasicBlockBuilder {
 			offset: self.stack_builder.pull_local(),
 		};
 
-		self.add_memory_offset(destination.offset, info.offset);
+		self.add_memory_offset(destination, info.offset);
 
 		self.code_builder
 			.add_memory_store(destination, source, r#type);
@@ -257,6 +490,11 @@ impl BasicBlockBuilder {
 		let destination = self.stack_builder.push_local();
 
 		self.code_builder.add_memory_size(reference, destination);
+
+		if self.is_memory_64(reference) {
+			self.code_builder
+				.add_integer_widen(destination, destination);
+		}
 	}
 
 	fn handle_memory_grow(&mut self, memory: u32) {
@@ -264,158 +502,459 @@ impl BasicBlockBuilder {
 		let size = self.stack_builder.pull_local();
 		let destination = self.stack_builder.push_local();
 
+		if self.is_memory_64(reference) {
+			self.add_memory_64_address(size);
+		}
+
 		self.code_builder
 			.add_memory_grow(reference, destination, size);
-	}
 
-	fn handle_i32_const(&mut self, data: i32) {
-		let destination = self.stack_builder.push_local();
-
-		self.code_builder.add_i32_constant(destination, data);
+		// A failed grow gives back `-1`, which must stay `-1` when widened.
+		if self.is_memory_64(reference) {
+			self.code_builder
+				.add_integer_widen(destination, destination);
+			self.code_builder
+				.add_integer_extend(destination, destination, ExtendType::I64_S32);
+		}
 	}
 
-	fn handle_i64_const(&mut self, data: i64) {
-		let destination = self.stack_builder.push_local();
-
-		self.code_builder.add_i64_constant(destination, data);
+	const fn get_atomic_layout(r#type: LoadType) -> (IntegerType, StoreType) {
+		match r#type {
+			LoadType::I32_U8 => (IntegerType::I32, StoreType::I32_I8),
+			LoadType::I32_U16 => (IntegerType::I32, StoreType::I32_I16),
+			LoadType::I32 => (IntegerType::I32, StoreType::I32),
+			LoadType::I64_U8 => (IntegerType::I64, StoreType::I64_I8),
+			LoadType::I64_U16 => (IntegerType::I64, StoreType::I64_I16),
+			LoadType::I64_U32 => (IntegerType::I64, StoreType::I64_I32),
+			LoadType::I64 => (IntegerType::I64, StoreType::I64),
+			_ => unreachable!(),
+		}
 	}
 
-	fn handle_f32_const(&mut self, data: Ieee32) {
-		let destination = self.stack_builder.push_local();
-
-		self.code_builder.add_f32_constant(destination, data.into());
+	const fn get_atomic_size(r#type: LoadType) -> u64 {
+		match r#type {
+			LoadType::I32_U8 | LoadType::I64_U8 => 1,
+			LoadType::I32_U16 | LoadType::I64_U16 => 2,
+			LoadType::I32 | LoadType::I64_U32 => 4,
+			LoadType::I64 => 8,
+			_ => unreachable!(),
+		}
 	}
 
-	fn handle_f64_const(&mut self, data: Ieee64) {
-		let destination = self.stack_builder.push_local();
+	fn add_integer_constant(&mut self, destination: u16, data: u64, r#type: IntegerType) {
+		match r#type {
+			IntegerType::I32 => {
+				let data = data.try_into().unwrap();
 
-		self.code_builder.add_f64_constant(destination, data.into());
+				self.code_builder.add_i32_constant(destination, data);
+			}
+			IntegerType::I64 => {
+				let data = data.try_into().unwrap();
+
+				self.code_builder.add_i64_constant(destination, data);
+			}
+		}
 	}
 
-	fn handle_integer_equals_zero(&mut self, r#type: IntegerType) {
-		let lhs = self.stack_builder.pull_local();
-		let destination = self.stack_builder.push_local();
+	// Atomic accesses trap unless their address is a multiple of their size,
+	// which only depends on the low bits of the address and the offset, so
+	// the check is done before the address is widened or offset.
+	fn add_atomic_alignment_check(
+		&mut self,
+		location: Location,
+		offset: u64,
+		r#type: LoadType,
+		scratch: u16,
+	) {
+		let mask = Self::get_atomic_size(r#type) - 1;
 
-		match r#type {
-			IntegerType::I32 => self.code_builder.add_i32_constant(SHARED_LOCAL, 0),
-			IntegerType::I64 => self.code_builder.add_i64_constant(SHARED_LOCAL, 0),
+		if mask == 0 {
+			return;
 		}
 
+		let integer_type = if self.is_memory_64(location.reference) {
+			IntegerType::I64
+		} else {
+			IntegerType::I32
+		};
+
+		self.add_integer_constant(scratch, offset & mask, integer_type);
+		self.code_builder.add_integer_binary_operation(
+			scratch,
+			location.offset,
+			scratch,
+			integer_type,
+			IntegerBinaryOperator::Add,
+		);
+
+		self.add_integer_constant(SHARED_LOCAL, mask, integer_type);
+		self.code_builder.add_integer_binary_operation(
+			scratch,
+			scratch,
+			SHARED_LOCAL,
+			integer_type,
+			IntegerBinaryOperator::And,
+		);
+
+		self.add_integer_constant(SHARED_LOCAL, 0, integer_type);
 		self.code_builder.add_integer_compare_operation(
-			destination,
-			lhs,
+			scratch,
+			scratch,
 			SHARED_LOCAL,
-			r#type,
-			IntegerCompareOperator::Equal,
+			integer_type,
+			IntegerCompareOperator::NotEqual,
 		);
-	}
 
-	fn handle_integer_unary_operation(
-		&mut self,
-		r#type: IntegerType,
-		operator: IntegerUnaryOperator,
-	) {
-		let source = self.stack_builder.pull_local();
-		let destination = self.stack_builder.push_local();
+		let condition = self.code_builder.add_local_branch(scratch, 2);
+		let on_misaligned = self.code_builder.add_unreachable();
 
 		self.code_builder
-			.add_integer_unary_operation(destination, source, r#type, operator);
+			.set_jump_destination(condition, 0, on_misaligned + 1);
 	}
 
-	fn handle_i32_unary(&mut self, operator: IntegerUnaryOperator) {
-		self.handle_integer_unary_operation(IntegerType::I32, operator);
-	}
+	// The `scratch` local must be above every operand of the access, which
+	// is the top of the stack before any of them are pulled.
+	fn pull_atomic_location(&mut self, info: MemArg, r#type: LoadType, scratch: u16) -> Location {
+		let location = Location {
+			reference: info.memory.try_into().unwrap(),
+			offset: self.stack_builder.pull_local(),
+		};
 
-	fn handle_i64_unary(&mut self, operator: IntegerUnaryOperator) {
-		self.handle_integer_unary_operation(IntegerType::I64, operator);
+		self.add_atomic_alignment_check(location, info.offset, r#type, scratch);
+		self.add_memory_offset(location, info.offset);
+
+		location
 	}
 
-	fn handle_integer_compare_operation(
-		&mut self,
-		r#type: IntegerType,
-		operator: IntegerCompareOperator,
-	) {
-		let rhs = self.stack_builder.pull_local();
-		let lhs = self.stack_builder.pull_local();
+	fn handle_atomic_load(&mut self, info: MemArg, r#type: LoadType) {
+		let scratch = self.stack_builder.get_top();
+		let source = self.pull_atomic_location(info, r#type, scratch);
 		let destination = self.stack_builder.push_local();
 
 		self.code_builder
-			.add_integer_compare_operation(destination, lhs, rhs, r#type, operator);
+			.add_memory_load(destination, source, r#type);
 	}
 
-	fn handle_i32_compare(&mut self, operator: IntegerCompareOperator) {
-		self.handle_integer_compare_operation(IntegerType::I32, operator);
-	}
+	fn handle_atomic_store(&mut self, info: MemArg, r#type: LoadType) {
+		let (_, store_type) = Self::get_atomic_layout(r#type);
+		let scratch = self.stack_builder.get_top();
+		let source = self.stack_builder.pull_local();
+		let destination = self.pull_atomic_location(info, r#type, scratch);
 
-	fn handle_i64_compare(&mut self, operator: IntegerCompareOperator) {
-		self.handle_integer_compare_operation(IntegerType::I64, operator);
+		self.code_builder
+			.add_memory_store(destination, source, store_type);
 	}
 
-	fn handle_number_compare_operation(
+	// Luau only ever runs one thread, so read-modify-write operations
+	// are lowered to a plain load followed by a plain store.
+	fn handle_atomic_rmw(
 		&mut self,
-		r#type: NumberType,
-		operator: NumberCompareOperator,
+		info: MemArg,
+		r#type: LoadType,
+		operator: IntegerBinaryOperator,
 	) {
-		let rhs = self.stack_builder.pull_local();
-		let lhs = self.stack_builder.pull_local();
-		let destination = self.stack_builder.push_local();
+		let (integer_type, store_type) = Self::get_atomic_layout(r#type);
+		let scratch = self.stack_builder.get_top();
+		let source = self.stack_builder.pull_local();
+		let location = self.pull_atomic_location(info, r#type, scratch);
 
 		self.code_builder
-			.add_number_compare_operation(destination, lhs, rhs, r#type, operator);
-	}
+			.add_memory_load(SHARED_LOCAL, location, r#type);
 
-	fn handle_f32_compare(&mut self, operator: NumberCompareOperator) {
-		self.handle_number_compare_operation(NumberType::F32, operator);
-	}
+		self.code_builder.add_integer_binary_operation(
+			source,
+			SHARED_LOCAL,
+			source,
+			integer_type,
+			operator,
+		);
 
-	fn handle_f64_compare(&mut self, operator: NumberCompareOperator) {
-		self.handle_number_compare_operation(NumberType::F64, operator);
-	}
+		self.code_builder
+			.add_memory_store(location, source, store_type);
 
-	fn handle_integer_binary_operation(
-		&mut self,
-		r#type: IntegerType,
-		operator: IntegerBinaryOperator,
-	) {
-		let rhs = self.stack_builder.pull_local();
-		let lhs = self.stack_builder.pull_local();
 		let destination = self.stack_builder.push_local();
 
-		self.code_builder
-			.add_integer_binary_operation(destination, lhs, rhs, r#type, operator);
+		self.code_builder.add_local_set(destination, SHARED_LOCAL);
 	}
 
-	fn handle_i32_binary(&mut self, operator: IntegerBinaryOperator) {
-		self.handle_integer_binary_operation(IntegerType::I32, operator);
-	}
+	fn handle_atomic_exchange(&mut self, info: MemArg, r#type: LoadType) {
+		let (_, store_type) = Self::get_atomic_layout(r#type);
+		let scratch = self.stack_builder.get_top();
+		let source = self.stack_builder.pull_local();
+		let location = self.pull_atomic_location(info, r#type, scratch);
 
-	fn handle_i64_binary(&mut self, operator: IntegerBinaryOperator) {
-		self.handle_integer_binary_operation(IntegerType::I64, operator);
-	}
+		self.code_builder
+			.add_memory_load(SHARED_LOCAL, location, r#type);
+
+		self.code_builder
+			.add_memory_store(location, source, store_type);
 
-	fn handle_number_unary_operation(&mut self, r#type: NumberType, operator: NumberUnaryOperator) {
-		let source = self.stack_builder.pull_local();
 		let destination = self.stack_builder.push_local();
 
-		self.code_builder
-			.add_number_unary_operation(destination, source, r#type, operator);
+		self.code_builder.add_local_set(destination, SHARED_LOCAL);
 	}
 
-	fn handle_f32_unary(&mut self, operator: NumberUnaryOperator) {
-		self.handle_number_unary_operation(NumberType::F32, operator);
-	}
+	fn add_atomic_mask(&mut self, destination: u16, scratch: u16, r#type: LoadType) {
+		let mask: i64 = match r#type {
+			LoadType::I32_U8 | LoadType::I64_U8 => 0xFF,
+			LoadType::I32_U16 | LoadType::I64_U16 => 0xFFFF,
+			LoadType::I64_U32 => 0xFFFF_FFFF,
+			_ => return,
+		};
 
-	fn handle_f64_unary(&mut self, operator: NumberUnaryOperator) {
-		self.handle_number_unary_operation(NumberType::F64, operator);
-	}
+		let (integer_type, _) = Self::get_atomic_layout(r#type);
 
-	fn handle_number_binary_operation(
-		&mut self,
-		r#type: NumberType,
-		operator: NumberBinaryOperator,
-	) {
-		let rhs = self.stack_builder.pull_local();
-		let lhs = self.stack_builder.pull_local();
+		match integer_type {
+			IntegerType::I32 => {
+				let mask = mask.try_into().unwrap();
+
+				self.code_builder.add_i32_constant(scratch, mask);
+			}
+			IntegerType::I64 => self.code_builder.add_i64_constant(scratch, mask),
+		}
+
+		self.code_builder.add_integer_binary_operation(
+			destination,
+			destination,
+			scratch,
+			integer_type,
+			IntegerBinaryOperator::And,
+		);
+	}
+
+	fn handle_atomic_compare_exchange(&mut self, info: MemArg, r#type: LoadType) {
+		let (integer_type, store_type) = Self::get_atomic_layout(r#type);
+		let scratch = self.stack_builder.get_top();
+		let replacement = self.stack_builder.pull_local();
+		let expected = self.stack_builder.pull_local();
+		let location = self.pull_atomic_location(info, r#type, scratch);
+
+		self.code_builder
+			.add_memory_load(SHARED_LOCAL, location, r#type);
+
+		// The expected value is compared only within the accessed width.
+		self.add_atomic_mask(expected, scratch, r#type);
+
+		self.code_builder.add_integer_compare_operation(
+			expected,
+			expected,
+			SHARED_LOCAL,
+			integer_type,
+			IntegerCompareOperator::Equal,
+		);
+
+		let condition = self.code_builder.add_local_branch(expected, 2);
+
+		self.code_builder
+			.add_memory_store(location, replacement, store_type);
+
+		let on_equal = self.code_builder.add_basic_block(1);
+
+		self.code_builder
+			.set_jump_destination(condition, 0, on_equal + 1);
+
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_local_set(destination, SHARED_LOCAL);
+	}
+
+	// Nothing could ever wake a waiting thread, so a wait that would
+	// block traps instead of hanging forever.
+	fn handle_atomic_wait(&mut self, info: MemArg, r#type: LoadType) {
+		let (integer_type, _) = Self::get_atomic_layout(r#type);
+		let scratch = self.stack_builder.get_top();
+		let _timeout = self.stack_builder.pull_local();
+		let expected = self.stack_builder.pull_local();
+		let location = self.pull_atomic_location(info, r#type, scratch);
+
+		self.code_builder
+			.add_memory_load(SHARED_LOCAL, location, r#type);
+
+		self.code_builder.add_integer_compare_operation(
+			expected,
+			expected,
+			SHARED_LOCAL,
+			integer_type,
+			IntegerCompareOperator::NotEqual,
+		);
+
+		let condition = self.code_builder.add_local_branch(expected, 2);
+		let on_equal = self.code_builder.add_unreachable();
+
+		self.code_builder
+			.set_jump_destination(condition, 1, on_equal + 1);
+
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_i32_constant(destination, 1);
+	}
+
+	// Nothing can be waiting to be woken, but the address must still be
+	// checked, so the word is read and written back unchanged.
+	fn handle_atomic_notify(&mut self, info: MemArg) {
+		let scratch = self.stack_builder.get_top();
+		let _count = self.stack_builder.pull_local();
+		let location = self.pull_atomic_location(info, LoadType::I32, scratch);
+
+		self.code_builder
+			.add_memory_load(SHARED_LOCAL, location, LoadType::I32);
+
+		self.code_builder
+			.add_memory_store(location, SHARED_LOCAL, StoreType::I32);
+
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_i32_constant(destination, 0);
+	}
+
+	fn handle_i32_const(&mut self, data: i32) {
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_i32_constant(destination, data);
+	}
+
+	fn handle_i64_const(&mut self, data: i64) {
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_i64_constant(destination, data);
+	}
+
+	fn handle_f32_const(&mut self, data: Ieee32) {
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_f32_constant(destination, data.into());
+	}
+
+	fn handle_f64_const(&mut self, data: Ieee64) {
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_f64_constant(destination, data.into());
+	}
+
+	fn handle_integer_equals_zero(&mut self, r#type: IntegerType) {
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		match r#type {
+			IntegerType::I32 => self.code_builder.add_i32_constant(SHARED_LOCAL, 0),
+			IntegerType::I64 => self.code_builder.add_i64_constant(SHARED_LOCAL, 0),
+		}
+
+		self.code_builder.add_integer_compare_operation(
+			destination,
+			lhs,
+			SHARED_LOCAL,
+			r#type,
+			IntegerCompareOperator::Equal,
+		);
+	}
+
+	fn handle_integer_unary_operation(
+		&mut self,
+		r#type: IntegerType,
+		operator: IntegerUnaryOperator,
+	) {
+		let source = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_integer_unary_operation(destination, source, r#type, operator);
+	}
+
+	fn handle_i32_unary(&mut self, operator: IntegerUnaryOperator) {
+		self.handle_integer_unary_operation(IntegerType::I32, operator);
+	}
+
+	fn handle_i64_unary(&mut self, operator: IntegerUnaryOperator) {
+		self.handle_integer_unary_operation(IntegerType::I64, operator);
+	}
+
+	fn handle_integer_compare_operation(
+		&mut self,
+		r#type: IntegerType,
+		operator: IntegerCompareOperator,
+	) {
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_integer_compare_operation(destination, lhs, rhs, r#type, operator);
+	}
+
+	fn handle_i32_compare(&mut self, operator: IntegerCompareOperator) {
+		self.handle_integer_compare_operation(IntegerType::I32, operator);
+	}
+
+	fn handle_i64_compare(&mut self, operator: IntegerCompareOperator) {
+		self.handle_integer_compare_operation(IntegerType::I64, operator);
+	}
+
+	fn handle_number_compare_operation(
+		&mut self,
+		r#type: NumberType,
+		operator: NumberCompareOperator,
+	) {
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_number_compare_operation(destination, lhs, rhs, r#type, operator);
+	}
+
+	fn handle_f32_compare(&mut self, operator: NumberCompareOperator) {
+		self.handle_number_compare_operation(NumberType::F32, operator);
+	}
+
+	fn handle_f64_compare(&mut self, operator: NumberCompareOperator) {
+		self.handle_number_compare_operation(NumberType::F64, operator);
+	}
+
+	fn handle_integer_binary_operation(
+		&mut self,
+		r#type: IntegerType,
+		operator: IntegerBinaryOperator,
+	) {
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_integer_binary_operation(destination, lhs, rhs, r#type, operator);
+	}
+
+	fn handle_i32_binary(&mut self, operator: IntegerBinaryOperator) {
+		self.handle_integer_binary_operation(IntegerType::I32, operator);
+	}
+
+	fn handle_i64_binary(&mut self, operator: IntegerBinaryOperator) {
+		self.handle_integer_binary_operation(IntegerType::I64, operator);
+	}
+
+	fn handle_number_unary_operation(&mut self, r#type: NumberType, operator: NumberUnaryOperator) {
+		let source = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_number_unary_operation(destination, source, r#type, operator);
+	}
+
+	fn handle_f32_unary(&mut self, operator: NumberUnaryOperator) {
+		self.handle_number_unary_operation(NumberType::F32, operator);
+	}
+
+	fn handle_f64_unary(&mut self, operator: NumberUnaryOperator) {
+		self.handle_number_unary_operation(NumberType::F64, operator);
+	}
+
+	fn handle_number_binary_operation(
+		&mut self,
+		r#type: NumberType,
+		operator: NumberBinaryOperator,
+	) {
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
 		let destination = self.stack_builder.push_local();
 
 		self.code_builder
@@ -555,6 +1094,10 @@ impl BasicBlockBuilder {
 			offset: self.stack_builder.pull_local(),
 		};
 
+		if self.is_memory_64(destination.reference) {
+			self.add_memory_64_address(destination.offset);
+		}
+
 		self.code_builder.add_memory_init(destination, source, size);
 	}
 
@@ -575,6 +1118,22 @@ impl BasicBlockBuilder {
 			offset: self.stack_builder.pull_local(),
 		};
 
+		let source_64 = self.is_memory_64(source.reference);
+		let destination_64 = self.is_memory_64(destination.reference);
+
+		if source_64 {
+			self.add_memory_64_address(source.offset);
+		}
+
+		if destination_64 {
+			self.add_memory_64_address(destination.offset);
+		}
+
+		// The size is only 64-bit when both memories are.
+		if source_64 && destination_64 {
+			self.add_memory_64_address(size);
+		}
+
 		self.code_builder.add_memory_copy(destination, source, size);
 	}
 
@@ -586,6 +1145,11 @@ impl BasicBlockBuilder {
 			offset: self.stack_builder.pull_local(),
 		};
 
+		if self.is_memory_64(destination.reference) {
+			self.add_memory_64_address(destination.offset);
+			self.add_memory_64_address(size);
+		}
+
 		self.code_builder.add_memory_fill(destination, byte, size);
 	}
 
@@ -691,58 +1255,436 @@ impl BasicBlockBuilder {
 		self.code_builder.add_table_size(reference, destination);
 	}
 
-	#[expect(clippy::too_many_lines)]
-	fn handle_operator(&mut self, types: &Types, operator: Operator) {
-		match operator {
-			Operator::Unreachable => self.handle_unreachable(),
-			Operator::Nop => {}
-			Operator::Block { blockty } => self.handle_block(types, blockty),
-			Operator::Loop { blockty } => self.handle_loop(types, blockty),
-			Operator::If { blockty } => self.handle_if(types, blockty),
-			Operator::Else => self.handle_else(),
-			Operator::End => self.handle_end(),
-			Operator::Br { relative_depth } => self.handle_br(relative_depth),
-			Operator::BrIf { relative_depth } => self.handle_br_if(relative_depth),
-			Operator::BrTable { ref targets } => self.handle_br_table(targets),
-			Operator::Return => self.handle_return(),
-			Operator::Call { function_index } => {
-				self.handle_call(function_index, types.get_function_type(function_index));
-			}
-			Operator::CallIndirect {
-				type_index,
-				table_index,
-			} => self.handle_call_indirect(table_index, types.get_type(type_index).unwrap_func()),
-			Operator::Drop => self.handle_drop(),
-			Operator::Select | Operator::TypedSelect { .. } => self.handle_select(),
-			Operator::LocalGet { local_index } => self.handle_local_get(local_index),
-			Operator::LocalSet { local_index } => self.handle_local_set(local_index),
-			Operator::LocalTee { local_index } => self.handle_local_tee(local_index),
-			Operator::GlobalGet { global_index } => self.handle_global_get(global_index),
-			Operator::GlobalSet { global_index } => self.handle_global_set(global_index),
-			Operator::I32Load { memarg } => self.handle_load(memarg, LoadType::I32),
-			Operator::I64Load { memarg } => self.handle_load(memarg, LoadType::I64),
-			Operator::F32Load { memarg } => self.handle_load(memarg, LoadType::F32),
-			Operator::F64Load { memarg } => self.handle_load(memarg, LoadType::F64),
-			Operator::I32Load8S { memarg } => self.handle_load(memarg, LoadType::I32_S8),
-			Operator::I32Load8U { memarg } => self.handle_load(memarg, LoadType::I32_U8),
-			Operator::I32Load16S { memarg } => self.handle_load(memarg, LoadType::I32_S16),
-			Operator::I32Load16U { memarg } => self.handle_load(memarg, LoadType::I32_U16),
-			Operator::I64Load8S { memarg } => self.handle_load(memarg, LoadType::I64_S8),
-			Operator::I64Load8U { memarg } => self.handle_load(memarg, LoadType::I64_U8),
-			Operator::I64Load16S { memarg } => self.handle_load(memarg, LoadType::I64_S16),
-			Operator::I64Load16U { memarg } => self.handle_load(memarg, LoadType::I64_U16),
-			Operator::I64Load32S { memarg } => self.handle_load(memarg, LoadType::I64_S32),
-			Operator::I64Load32U { memarg } => self.handle_load(memarg, LoadType::I64_U32),
-			Operator::I32Store { memarg } => self.handle_store(memarg, StoreType::I32),
-			Operator::I64Store { memarg } => self.handle_store(memarg, StoreType::I64),
-			Operator::F32Store { memarg } => self.handle_store(memarg, StoreType::F32),
-			Operator::F64Store { memarg } => self.handle_store(memarg, StoreType::F64),
-			Operator::I32Store8 { memarg } => self.handle_store(memarg, StoreType::I32_I8),
+	fn handle_v128_const(&mut self, data: V128) {
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_constant(destination, u128::from_le_bytes(*data.bytes()));
+	}
+
+	fn handle_v128_unary(&mut self, operator: V128UnaryOperator) {
+		let source = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_unary_operation(destination, source, operator);
+	}
+
+	fn handle_v128_binary(&mut self, operator: V128BinaryOperator) {
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_binary_operation(destination, lhs, rhs, operator);
+	}
+
+	fn handle_v128_integer_compare(&mut self, r#type: LaneType, operator: IntegerCompareOperator) {
+		self.handle_v128_binary(V128BinaryOperator::IntegerCompare(r#type, operator));
+	}
+
+	fn handle_v128_number_compare(&mut self, r#type: LaneType, operator: NumberCompareOperator) {
+		self.handle_v128_binary(V128BinaryOperator::NumberCompare(r#type, operator));
+	}
+
+	fn handle_v128_reduce(&mut self, operator: V128ReduceOperator) {
+		let source = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_reduce_operation(destination, source, operator);
+	}
+
+	fn handle_v128_splat(&mut self, r#type: LaneType) {
+		let source = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_splat(destination, source, r#type);
+	}
+
+	fn handle_v128_extract_lane(&mut self, r#type: LaneType, lane: u8, signed: bool) {
+		let source = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_extract_lane(destination, source, r#type, lane, signed);
+	}
+
+	fn handle_v128_replace_lane(&mut self, r#type: LaneType, lane: u8) {
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_replace_lane(destination, lhs, rhs, r#type, lane);
+	}
+
+	fn handle_v128_shuffle(&mut self, lanes: [u8; 16]) {
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_shuffle(destination, lhs, rhs, lanes);
+	}
+
+	fn handle_v128_bit_select(&mut self) {
+		let condition = self.stack_builder.pull_local();
+		let rhs = self.stack_builder.pull_local();
+		let lhs = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_v128_bit_select(destination, lhs, rhs, condition);
+	}
+
+	fn handle_v128_load_extend(&mut self, info: MemArg, from: LaneType, signed: bool) {
+		self.handle_load(info, LoadType::I64);
+		self.handle_v128_splat(LaneType::I64);
+		self.handle_v128_unary(V128UnaryOperator::ExtendLow { from, signed });
+	}
+
+	fn handle_v128_load_splat(&mut self, info: MemArg, r#type: LoadType, lane: LaneType) {
+		self.handle_load(info, r#type);
+		self.handle_v128_splat(lane);
+	}
+
+	fn handle_v128_load_zero(&mut self, info: MemArg, r#type: LoadType, lane: LaneType) {
+		self.handle_load(info, r#type);
+
+		let source = self.stack_builder.pull_local();
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder.add_v128_constant(SHARED_LOCAL, 0);
+		self.code_builder
+			.add_v128_replace_lane(destination, SHARED_LOCAL, source, lane, 0);
+	}
+
+	fn handle_v128_load_lane(
+		&mut self,
+		info: MemArg,
+		r#type: LoadType,
+		lane_type: LaneType,
+		lane: u8,
+	) {
+		let vector = self.stack_builder.pull_local();
+		let source = Location {
+			reference: info.memory.try_into().unwrap(),
+			offset: self.stack_builder.pull_local(),
+		};
+
+		self.add_memory_offset(source, info.offset);
+
+		let destination = self.stack_builder.push_local();
+
+		self.code_builder
+			.add_memory_load(SHARED_LOCAL, source, r#type);
+		self.code_builder
+			.add_v128_replace_lane(destination, vector, SHARED_LOCAL, lane_type, lane);
+	}
+
+	fn handle_v128_store_lane(
+		&mut self,
+		info: MemArg,
+		r#type: StoreType,
+		lane_type: LaneType,
+		lane: u8,
+	) {
+		let vector = self.stack_builder.pull_local();
+		let destination = Location {
+			reference: info.memory.try_into().unwrap(),
+			offset: self.stack_builder.pull_local(),
+		};
+
+		self.add_memory_offset(destination, info.offset);
+
+		self.code_builder
+			.add_v128_extract_lane(SHARED_LOCAL, vector, lane_type, lane, false);
+		self.code_builder
+			.add_memory_store(destination, SHARED_LOCAL, r#type);
+	}
+
+	#[expect(clippy::too_many_lines)]
+	fn handle_operator(&mut self, types: &Types, operator: Operator) {
+		match operator {
+			Operator::Unreachable => self.handle_unreachable(),
+			Operator::Nop => {}
+			Operator::Block { blockty } => self.handle_block(types, blockty),
+			Operator::Loop { blockty } => self.handle_loop(types, blockty),
+			Operator::If { blockty } => self.handle_if(types, blockty),
+			Operator::Else => self.handle_else(),
+			Operator::End => self.handle_end(),
+			Operator::Br { relative_depth } => self.handle_br(relative_depth),
+			Operator::BrIf { relative_depth } => self.handle_br_if(relative_depth),
+			Operator::BrTable { ref targets } => self.handle_br_table(targets),
+			Operator::Return => self.handle_return(),
+			Operator::Call { function_index } => {
+				self.handle_call(
+					types,
+					function_index,
+					types.get_function_type(function_index),
+					false,
+				);
+			}
+			Operator::CallIndirect {
+				type_index,
+				table_index,
+			} => self.handle_call_indirect(types, table_index, type_index, false),
+			Operator::ReturnCall { function_index } => {
+				self.handle_return_call(
+					types,
+					function_index,
+					types.get_function_type(function_index),
+				);
+			}
+			Operator::ReturnCallIndirect {
+				type_index,
+				table_index,
+			} => self.handle_return_call_indirect(types, table_index, type_index),
+			Operator::TryTable { try_table } => self.handle_try_table(types, try_table),
+			Operator::Throw { tag_index } => self.handle_throw(types, tag_index),
+			Operator::ThrowRef => self.handle_throw_ref(types),
+			Operator::Drop => self.handle_drop(),
+			Operator::Select | Operator::TypedSelect { .. } => self.handle_select(),
+			Operator::LocalGet { local_index } => self.handle_local_get(local_index),
+			Operator::LocalSet { local_index } => self.handle_local_set(local_index),
+			Operator::LocalTee { local_index } => self.handle_local_tee(local_index),
+			Operator::GlobalGet { global_index } => self.handle_global_get(global_index),
+			Operator::GlobalSet { global_index } => self.handle_global_set(global_index),
+			Operator::I32Load { memarg } => self.handle_load(memarg, LoadType::I32),
+			Operator::I64Load { memarg } => self.handle_load(memarg, LoadType::I64),
+			Operator::F32Load { memarg } => self.handle_load(memarg, LoadType::F32),
+			Operator::F64Load { memarg } => self.handle_load(memarg, LoadType::F64),
+			Operator::I32Load8S { memarg } => self.handle_load(memarg, LoadType::I32_S8),
+			Operator::I32Load8U { memarg } => self.handle_load(memarg, LoadType::I32_U8),
+			Operator::I32Load16S { memarg } => self.handle_load(memarg, LoadType::I32_S16),
+			Operator::I32Load16U { memarg } => self.handle_load(memarg, LoadType::I32_U16),
+			Operator::I64Load8S { memarg } => self.handle_load(memarg, LoadType::I64_S8),
+			Operator::I64Load8U { memarg } => self.handle_load(memarg, LoadType::I64_U8),
+			Operator::I64Load16S { memarg } => self.handle_load(memarg, LoadType::I64_S16),
+			Operator::I64Load16U { memarg } => self.handle_load(memarg, LoadType::I64_U16),
+			Operator::I64Load32S { memarg } => self.handle_load(memarg, LoadType::I64_S32),
+			Operator::I64Load32U { memarg } => self.handle_load(memarg, LoadType::I64_U32),
+			Operator::I32Store { memarg } => self.handle_store(memarg, StoreType::I32),
+			Operator::I64Store { memarg } => self.handle_store(memarg, StoreType::I64),
+			Operator::F32Store { memarg } => self.handle_store(memarg, StoreType::F32),
+			Operator::F64Store { memarg } => self.handle_store(memarg, StoreType::F64),
+			Operator::I32Store8 { memarg } => self.handle_store(memarg, StoreType::I32_I8),
 			Operator::I32Store16 { memarg } => self.handle_store(memarg, StoreType::I32_I16),
 			Operator::I64Store8 { memarg } => self.handle_store(memarg, StoreType::I64_I8),
 			Operator::I64Store16 { memarg } => self.handle_store(memarg, StoreType::I64_I16),
 			Operator::I64Store32 { memarg } => self.handle_store(memarg, StoreType::I64_I32),
 			Operator::MemorySize { mem } => self.handle_memory_size(mem),
+			Operator::I32AtomicLoad { memarg } => self.handle_atomic_load(memarg, LoadType::I32),
+			Operator::I64AtomicLoad { memarg } => self.handle_atomic_load(memarg, LoadType::I64),
+			Operator::I32AtomicLoad8U { memarg } => {
+				self.handle_atomic_load(memarg, LoadType::I32_U8)
+			}
+			Operator::I32AtomicLoad16U { memarg } => {
+				self.handle_atomic_load(memarg, LoadType::I32_U16)
+			}
+			Operator::I64AtomicLoad8U { memarg } => {
+				self.handle_atomic_load(memarg, LoadType::I64_U8)
+			}
+			Operator::I64AtomicLoad16U { memarg } => {
+				self.handle_atomic_load(memarg, LoadType::I64_U16)
+			}
+			Operator::I64AtomicLoad32U { memarg } => {
+				self.handle_atomic_load(memarg, LoadType::I64_U32)
+			}
+			Operator::I32AtomicStore { memarg } => self.handle_atomic_store(memarg, LoadType::I32),
+			Operator::I64AtomicStore { memarg } => self.handle_atomic_store(memarg, LoadType::I64),
+			Operator::I32AtomicStore8 { memarg } => {
+				self.handle_atomic_store(memarg, LoadType::I32_U8)
+			}
+			Operator::I32AtomicStore16 { memarg } => {
+				self.handle_atomic_store(memarg, LoadType::I32_U16)
+			}
+			Operator::I64AtomicStore8 { memarg } => {
+				self.handle_atomic_store(memarg, LoadType::I64_U8)
+			}
+			Operator::I64AtomicStore16 { memarg } => {
+				self.handle_atomic_store(memarg, LoadType::I64_U16)
+			}
+			Operator::I64AtomicStore32 { memarg } => {
+				self.handle_atomic_store(memarg, LoadType::I64_U32)
+			}
+			Operator::I32AtomicRmwAdd { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32, IntegerBinaryOperator::Add);
+			}
+			Operator::I32AtomicRmw8AddU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U8, IntegerBinaryOperator::Add);
+			}
+			Operator::I32AtomicRmw16AddU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U16, IntegerBinaryOperator::Add);
+			}
+			Operator::I64AtomicRmwAdd { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64, IntegerBinaryOperator::Add);
+			}
+			Operator::I64AtomicRmw8AddU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U8, IntegerBinaryOperator::Add);
+			}
+			Operator::I64AtomicRmw16AddU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U16, IntegerBinaryOperator::Add);
+			}
+			Operator::I64AtomicRmw32AddU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U32, IntegerBinaryOperator::Add);
+			}
+			Operator::I32AtomicRmwSub { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32, IntegerBinaryOperator::Subtract);
+			}
+			Operator::I32AtomicRmw8SubU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U8, IntegerBinaryOperator::Subtract);
+			}
+			Operator::I32AtomicRmw16SubU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U16, IntegerBinaryOperator::Subtract);
+			}
+			Operator::I64AtomicRmwSub { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64, IntegerBinaryOperator::Subtract);
+			}
+			Operator::I64AtomicRmw8SubU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U8, IntegerBinaryOperator::Subtract);
+			}
+			Operator::I64AtomicRmw16SubU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U16, IntegerBinaryOperator::Subtract);
+			}
+			Operator::I64AtomicRmw32SubU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U32, IntegerBinaryOperator::Subtract);
+			}
+			Operator::I32AtomicRmwAnd { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32, IntegerBinaryOperator::And);
+			}
+			Operator::I32AtomicRmw8AndU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U8, IntegerBinaryOperator::And);
+			}
+			Operator::I32AtomicRmw16AndU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U16, IntegerBinaryOperator::And);
+			}
+			Operator::I64AtomicRmwAnd { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64, IntegerBinaryOperator::And);
+			}
+			Operator::I64AtomicRmw8AndU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U8, IntegerBinaryOperator::And);
+			}
+			Operator::I64AtomicRmw16AndU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U16, IntegerBinaryOperator::And);
+			}
+			Operator::I64AtomicRmw32AndU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U32, IntegerBinaryOperator::And);
+			}
+			Operator::I32AtomicRmwOr { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32, IntegerBinaryOperator::Or);
+			}
+			Operator::I32AtomicRmw8OrU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U8, IntegerBinaryOperator::Or);
+			}
+			Operator::I32AtomicRmw16OrU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32_U16, IntegerBinaryOperator::Or);
+			}
+			Operator::I64AtomicRmwOr { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64, IntegerBinaryOperator::Or);
+			}
+			Operator::I64AtomicRmw8OrU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U8, IntegerBinaryOperator::Or);
+			}
+			Operator::I64AtomicRmw16OrU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U16, IntegerBinaryOperator::Or);
+			}
+			Operator::I64AtomicRmw32OrU { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64_U32, IntegerBinaryOperator::Or);
+			}
+			Operator::I32AtomicRmwXor { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I32, IntegerBinaryOperator::ExclusiveOr);
+			}
+			Operator::I32AtomicRmw8XorU { memarg } => {
+				self.handle_atomic_rmw(
+					memarg,
+					LoadType::I32_U8,
+					IntegerBinaryOperator::ExclusiveOr,
+				);
+			}
+			Operator::I32AtomicRmw16XorU { memarg } => {
+				self.handle_atomic_rmw(
+					memarg,
+					LoadType::I32_U16,
+					IntegerBinaryOperator::ExclusiveOr,
+				);
+			}
+			Operator::I64AtomicRmwXor { memarg } => {
+				self.handle_atomic_rmw(memarg, LoadType::I64, IntegerBinaryOperator::ExclusiveOr);
+			}
+			Operator::I64AtomicRmw8XorU { memarg } => {
+				self.handle_atomic_rmw(
+					memarg,
+					LoadType::I64_U8,
+					IntegerBinaryOperator::ExclusiveOr,
+				);
+			}
+			Operator::I64AtomicRmw16XorU { memarg } => {
+				self.handle_atomic_rmw(
+					memarg,
+					LoadType::I64_U16,
+					IntegerBinaryOperator::ExclusiveOr,
+				);
+			}
+			Operator::I64AtomicRmw32XorU { memarg } => {
+				self.handle_atomic_rmw(
+					memarg,
+					LoadType::I64_U32,
+					IntegerBinaryOperator::ExclusiveOr,
+				);
+			}
+			Operator::I32AtomicRmwXchg { memarg } => {
+				self.handle_atomic_exchange(memarg, LoadType::I32)
+			}
+			Operator::I32AtomicRmw8XchgU { memarg } => {
+				self.handle_atomic_exchange(memarg, LoadType::I32_U8)
+			}
+			Operator::I32AtomicRmw16XchgU { memarg } => {
+				self.handle_atomic_exchange(memarg, LoadType::I32_U16)
+			}
+			Operator::I64AtomicRmwXchg { memarg } => {
+				self.handle_atomic_exchange(memarg, LoadType::I64)
+			}
+			Operator::I64AtomicRmw8XchgU { memarg } => {
+				self.handle_atomic_exchange(memarg, LoadType::I64_U8)
+			}
+			Operator::I64AtomicRmw16XchgU { memarg } => {
+				self.handle_atomic_exchange(memarg, LoadType::I64_U16)
+			}
+			Operator::I64AtomicRmw32XchgU { memarg } => {
+				self.handle_atomic_exchange(memarg, LoadType::I64_U32)
+			}
+			Operator::I32AtomicRmwCmpxchg { memarg } => {
+				self.handle_atomic_compare_exchange(memarg, LoadType::I32)
+			}
+			Operator::I32AtomicRmw8CmpxchgU { memarg } => {
+				self.handle_atomic_compare_exchange(memarg, LoadType::I32_U8)
+			}
+			Operator::I32AtomicRmw16CmpxchgU { memarg } => {
+				self.handle_atomic_compare_exchange(memarg, LoadType::I32_U16)
+			}
+			Operator::I64AtomicRmwCmpxchg { memarg } => {
+				self.handle_atomic_compare_exchange(memarg, LoadType::I64)
+			}
+			Operator::I64AtomicRmw8CmpxchgU { memarg } => {
+				self.handle_atomic_compare_exchange(memarg, LoadType::I64_U8)
+			}
+			Operator::I64AtomicRmw16CmpxchgU { memarg } => {
+				self.handle_atomic_compare_exchange(memarg, LoadType::I64_U16)
+			}
+			Operator::I64AtomicRmw32CmpxchgU { memarg } => {
+				self.handle_atomic_compare_exchange(memarg, LoadType::I64_U32)
+			}
+			Operator::MemoryAtomicNotify { memarg } => self.handle_atomic_notify(memarg),
+			Operator::MemoryAtomicWait32 { memarg } => {
+				self.handle_atomic_wait(memarg, LoadType::I32)
+			}
+			Operator::MemoryAtomicWait64 { memarg } => {
+				self.handle_atomic_wait(memarg, LoadType::I64)
+			}
+			Operator::AtomicFence => {}
 			Operator::MemoryGrow { mem } => self.handle_memory_grow(mem),
 			Operator::I32Const { value } => self.handle_i32_const(value),
 			Operator::I64Const { value } => self.handle_i64_const(value),
@@ -958,6 +1900,944 @@ impl BasicBlockBuilder {
 			Operator::TableSet { table } => self.handle_table_set(table),
 			Operator::TableGrow { table } => self.handle_table_grow(table),
 			Operator::TableSize { table } => self.handle_table_size(table),
+			Operator::V128Load { memarg } => self.handle_load(memarg, LoadType::V128),
+			Operator::V128Load8x8S { memarg } => {
+				self.handle_v128_load_extend(memarg, LaneType::I8, true);
+			}
+			Operator::V128Load8x8U { memarg } => {
+				self.handle_v128_load_extend(memarg, LaneType::I8, false);
+			}
+			Operator::V128Load16x4S { memarg } => {
+				self.handle_v128_load_extend(memarg, LaneType::I16, true);
+			}
+			Operator::V128Load16x4U { memarg } => {
+				self.handle_v128_load_extend(memarg, LaneType::I16, false);
+			}
+			Operator::V128Load32x2S { memarg } => {
+				self.handle_v128_load_extend(memarg, LaneType::I32, true);
+			}
+			Operator::V128Load32x2U { memarg } => {
+				self.handle_v128_load_extend(memarg, LaneType::I32, false);
+			}
+			Operator::V128Load8Splat { memarg } => {
+				self.handle_v128_load_splat(memarg, LoadType::I32_U8, LaneType::I8);
+			}
+			Operator::V128Load16Splat { memarg } => {
+				self.handle_v128_load_splat(memarg, LoadType::I32_U16, LaneType::I16);
+			}
+			Operator::V128Load32Splat { memarg } => {
+				self.handle_v128_load_splat(memarg, LoadType::I32, LaneType::I32);
+			}
+			Operator::V128Load64Splat { memarg } => {
+				self.handle_v128_load_splat(memarg, LoadType::I64, LaneType::I64);
+			}
+			Operator::V128Load32Zero { memarg } => {
+				self.handle_v128_load_zero(memarg, LoadType::I32, LaneType::I32);
+			}
+			Operator::V128Load64Zero { memarg } => {
+				self.handle_v128_load_zero(memarg, LoadType::I64, LaneType::I64);
+			}
+			Operator::V128Store { memarg } => self.handle_store(memarg, StoreType::V128),
+			Operator::V128Load8Lane { memarg, lane } => {
+				self.handle_v128_load_lane(memarg, LoadType::I32_U8, LaneType::I8, lane);
+			}
+			Operator::V128Load16Lane { memarg, lane } => {
+				self.handle_v128_load_lane(memarg, LoadType::I32_U16, LaneType::I16, lane);
+			}
+			Operator::V128Load32Lane { memarg, lane } => {
+				self.handle_v128_load_lane(memarg, LoadType::I32, LaneType::I32, lane);
+			}
+			Operator::V128Load64Lane { memarg, lane } => {
+				self.handle_v128_load_lane(memarg, LoadType::I64, LaneType::I64, lane);
+			}
+			Operator::V128Store8Lane { memarg, lane } => {
+				self.handle_v128_store_lane(memarg, StoreType::I32_I8, LaneType::I8, lane);
+			}
+			Operator::V128Store16Lane { memarg, lane } => {
+				self.handle_v128_store_lane(memarg, StoreType::I32_I16, LaneType::I16, lane);
+			}
+			Operator::V128Store32Lane { memarg, lane } => {
+				self.handle_v128_store_lane(memarg, StoreType::I32, LaneType::I32, lane);
+			}
+			Operator::V128Store64Lane { memarg, lane } => {
+				self.handle_v128_store_lane(memarg, StoreType::I64, LaneType::I64, lane);
+			}
+			Operator::V128Const { value } => self.handle_v128_const(value),
+			Operator::I8x16Shuffle { lanes } => self.handle_v128_shuffle(lanes),
+			Operator::I8x16ExtractLaneS { lane } => {
+				self.handle_v128_extract_lane(LaneType::I8, lane, true);
+			}
+			Operator::I8x16ExtractLaneU { lane } => {
+				self.handle_v128_extract_lane(LaneType::I8, lane, false);
+			}
+			Operator::I8x16ReplaceLane { lane } => {
+				self.handle_v128_replace_lane(LaneType::I8, lane);
+			}
+			Operator::I16x8ExtractLaneS { lane } => {
+				self.handle_v128_extract_lane(LaneType::I16, lane, true);
+			}
+			Operator::I16x8ExtractLaneU { lane } => {
+				self.handle_v128_extract_lane(LaneType::I16, lane, false);
+			}
+			Operator::I16x8ReplaceLane { lane } => {
+				self.handle_v128_replace_lane(LaneType::I16, lane);
+			}
+			Operator::I32x4ExtractLane { lane } => {
+				self.handle_v128_extract_lane(LaneType::I32, lane, false);
+			}
+			Operator::I32x4ReplaceLane { lane } => {
+				self.handle_v128_replace_lane(LaneType::I32, lane);
+			}
+			Operator::I64x2ExtractLane { lane } => {
+				self.handle_v128_extract_lane(LaneType::I64, lane, false);
+			}
+			Operator::I64x2ReplaceLane { lane } => {
+				self.handle_v128_replace_lane(LaneType::I64, lane);
+			}
+			Operator::F32x4ExtractLane { lane } => {
+				self.handle_v128_extract_lane(LaneType::F32, lane, false);
+			}
+			Operator::F32x4ReplaceLane { lane } => {
+				self.handle_v128_replace_lane(LaneType::F32, lane);
+			}
+			Operator::F64x2ExtractLane { lane } => {
+				self.handle_v128_extract_lane(LaneType::F64, lane, false);
+			}
+			Operator::F64x2ReplaceLane { lane } => {
+				self.handle_v128_replace_lane(LaneType::F64, lane);
+			}
+			Operator::I8x16Swizzle => self.handle_v128_binary(V128BinaryOperator::Swizzle),
+			Operator::I8x16Splat => self.handle_v128_splat(LaneType::I8),
+			Operator::I16x8Splat => self.handle_v128_splat(LaneType::I16),
+			Operator::I32x4Splat => self.handle_v128_splat(LaneType::I32),
+			Operator::I64x2Splat => self.handle_v128_splat(LaneType::I64),
+			Operator::F32x4Splat => self.handle_v128_splat(LaneType::F32),
+			Operator::F64x2Splat => self.handle_v128_splat(LaneType::F64),
+			Operator::I8x16Eq => {
+				self.handle_v128_integer_compare(LaneType::I8, IntegerCompareOperator::Equal);
+			}
+			Operator::I8x16Ne => {
+				self.handle_v128_integer_compare(LaneType::I8, IntegerCompareOperator::NotEqual);
+			}
+			Operator::I8x16LtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::LessThan { signed: true },
+				);
+			}
+			Operator::I8x16LtU => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::LessThan { signed: false },
+				);
+			}
+			Operator::I8x16GtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::GreaterThan { signed: true },
+				);
+			}
+			Operator::I8x16GtU => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::GreaterThan { signed: false },
+				);
+			}
+			Operator::I8x16LeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::LessThanEqual { signed: true },
+				);
+			}
+			Operator::I8x16LeU => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::LessThanEqual { signed: false },
+				);
+			}
+			Operator::I8x16GeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::GreaterThanEqual { signed: true },
+				);
+			}
+			Operator::I8x16GeU => {
+				self.handle_v128_integer_compare(
+					LaneType::I8,
+					IntegerCompareOperator::GreaterThanEqual { signed: false },
+				);
+			}
+			Operator::I16x8Eq => {
+				self.handle_v128_integer_compare(LaneType::I16, IntegerCompareOperator::Equal);
+			}
+			Operator::I16x8Ne => {
+				self.handle_v128_integer_compare(LaneType::I16, IntegerCompareOperator::NotEqual);
+			}
+			Operator::I16x8LtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::LessThan { signed: true },
+				);
+			}
+			Operator::I16x8LtU => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::LessThan { signed: false },
+				);
+			}
+			Operator::I16x8GtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::GreaterThan { signed: true },
+				);
+			}
+			Operator::I16x8GtU => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::GreaterThan { signed: false },
+				);
+			}
+			Operator::I16x8LeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::LessThanEqual { signed: true },
+				);
+			}
+			Operator::I16x8LeU => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::LessThanEqual { signed: false },
+				);
+			}
+			Operator::I16x8GeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::GreaterThanEqual { signed: true },
+				);
+			}
+			Operator::I16x8GeU => {
+				self.handle_v128_integer_compare(
+					LaneType::I16,
+					IntegerCompareOperator::GreaterThanEqual { signed: false },
+				);
+			}
+			Operator::I32x4Eq => {
+				self.handle_v128_integer_compare(LaneType::I32, IntegerCompareOperator::Equal);
+			}
+			Operator::I32x4Ne => {
+				self.handle_v128_integer_compare(LaneType::I32, IntegerCompareOperator::NotEqual);
+			}
+			Operator::I32x4LtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::LessThan { signed: true },
+				);
+			}
+			Operator::I32x4LtU => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::LessThan { signed: false },
+				);
+			}
+			Operator::I32x4GtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::GreaterThan { signed: true },
+				);
+			}
+			Operator::I32x4GtU => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::GreaterThan { signed: false },
+				);
+			}
+			Operator::I32x4LeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::LessThanEqual { signed: true },
+				);
+			}
+			Operator::I32x4LeU => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::LessThanEqual { signed: false },
+				);
+			}
+			Operator::I32x4GeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::GreaterThanEqual { signed: true },
+				);
+			}
+			Operator::I32x4GeU => {
+				self.handle_v128_integer_compare(
+					LaneType::I32,
+					IntegerCompareOperator::GreaterThanEqual { signed: false },
+				);
+			}
+			Operator::I64x2Eq => {
+				self.handle_v128_integer_compare(LaneType::I64, IntegerCompareOperator::Equal);
+			}
+			Operator::I64x2Ne => {
+				self.handle_v128_integer_compare(LaneType::I64, IntegerCompareOperator::NotEqual);
+			}
+			Operator::I64x2LtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I64,
+					IntegerCompareOperator::LessThan { signed: true },
+				);
+			}
+			Operator::I64x2GtS => {
+				self.handle_v128_integer_compare(
+					LaneType::I64,
+					IntegerCompareOperator::GreaterThan { signed: true },
+				);
+			}
+			Operator::I64x2LeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I64,
+					IntegerCompareOperator::LessThanEqual { signed: true },
+				);
+			}
+			Operator::I64x2GeS => {
+				self.handle_v128_integer_compare(
+					LaneType::I64,
+					IntegerCompareOperator::GreaterThanEqual { signed: true },
+				);
+			}
+			Operator::F32x4Eq => {
+				self.handle_v128_number_compare(LaneType::F32, NumberCompareOperator::Equal);
+			}
+			Operator::F32x4Ne => {
+				self.handle_v128_number_compare(LaneType::F32, NumberCompareOperator::NotEqual);
+			}
+			Operator::F32x4Lt => {
+				self.handle_v128_number_compare(LaneType::F32, NumberCompareOperator::LessThan);
+			}
+			Operator::F32x4Gt => {
+				self.handle_v128_number_compare(LaneType::F32, NumberCompareOperator::GreaterThan);
+			}
+			Operator::F32x4Le => {
+				self.handle_v128_number_compare(
+					LaneType::F32,
+					NumberCompareOperator::LessThanEqual,
+				);
+			}
+			Operator::F32x4Ge => {
+				self.handle_v128_number_compare(
+					LaneType::F32,
+					NumberCompareOperator::GreaterThanEqual,
+				);
+			}
+			Operator::F64x2Eq => {
+				self.handle_v128_number_compare(LaneType::F64, NumberCompareOperator::Equal);
+			}
+			Operator::F64x2Ne => {
+				self.handle_v128_number_compare(LaneType::F64, NumberCompareOperator::NotEqual);
+			}
+			Operator::F64x2Lt => {
+				self.handle_v128_number_compare(LaneType::F64, NumberCompareOperator::LessThan);
+			}
+			Operator::F64x2Gt => {
+				self.handle_v128_number_compare(LaneType::F64, NumberCompareOperator::GreaterThan);
+			}
+			Operator::F64x2Le => {
+				self.handle_v128_number_compare(
+					LaneType::F64,
+					NumberCompareOperator::LessThanEqual,
+				);
+			}
+			Operator::F64x2Ge => {
+				self.handle_v128_number_compare(
+					LaneType::F64,
+					NumberCompareOperator::GreaterThanEqual,
+				);
+			}
+			Operator::V128Not => self.handle_v128_unary(V128UnaryOperator::Not),
+			Operator::V128And => self.handle_v128_binary(V128BinaryOperator::And),
+			Operator::V128AndNot => self.handle_v128_binary(V128BinaryOperator::AndNot),
+			Operator::V128Or => self.handle_v128_binary(V128BinaryOperator::Or),
+			Operator::V128Xor => self.handle_v128_binary(V128BinaryOperator::ExclusiveOr),
+			Operator::V128Bitselect => self.handle_v128_bit_select(),
+			Operator::V128AnyTrue => self.handle_v128_reduce(V128ReduceOperator::AnyTrue),
+			Operator::I8x16Abs => self.handle_v128_unary(V128UnaryOperator::Absolute(LaneType::I8)),
+			Operator::I8x16Neg => self.handle_v128_unary(V128UnaryOperator::Negate(LaneType::I8)),
+			Operator::I8x16Popcnt => self.handle_v128_unary(V128UnaryOperator::CountOnes),
+			Operator::I8x16AllTrue => {
+				self.handle_v128_reduce(V128ReduceOperator::AllTrue(LaneType::I8));
+			}
+			Operator::I8x16Bitmask => {
+				self.handle_v128_reduce(V128ReduceOperator::BitMask(LaneType::I8));
+			}
+			Operator::I8x16NarrowI16x8S => {
+				self.handle_v128_binary(V128BinaryOperator::Narrow {
+					from: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I8x16NarrowI16x8U => {
+				self.handle_v128_binary(V128BinaryOperator::Narrow {
+					from: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I8x16Shl => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftLeft(LaneType::I8));
+			}
+			Operator::I8x16ShrS => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I8x16ShrU => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I8x16Add => self.handle_v128_binary(V128BinaryOperator::Add(LaneType::I8)),
+			Operator::I8x16AddSatS => {
+				self.handle_v128_binary(V128BinaryOperator::AddSaturate {
+					r#type: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I8x16AddSatU => {
+				self.handle_v128_binary(V128BinaryOperator::AddSaturate {
+					r#type: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I8x16Sub => {
+				self.handle_v128_binary(V128BinaryOperator::Subtract(LaneType::I8));
+			}
+			Operator::I8x16SubSatS => {
+				self.handle_v128_binary(V128BinaryOperator::SubtractSaturate {
+					r#type: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I8x16SubSatU => {
+				self.handle_v128_binary(V128BinaryOperator::SubtractSaturate {
+					r#type: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I8x16MinS => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMinimum {
+					r#type: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I8x16MinU => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMinimum {
+					r#type: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I8x16MaxS => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMaximum {
+					r#type: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I8x16MaxU => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMaximum {
+					r#type: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I8x16AvgrU => {
+				self.handle_v128_binary(V128BinaryOperator::Average(LaneType::I8));
+			}
+			Operator::I16x8ExtAddPairwiseI8x16S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendAddPairwise {
+					from: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I16x8ExtAddPairwiseI8x16U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendAddPairwise {
+					from: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I16x8Abs => {
+				self.handle_v128_unary(V128UnaryOperator::Absolute(LaneType::I16));
+			}
+			Operator::I16x8Neg => self.handle_v128_unary(V128UnaryOperator::Negate(LaneType::I16)),
+			Operator::I16x8Q15MulrSatS => {
+				self.handle_v128_binary(V128BinaryOperator::MultiplyRoundSaturate);
+			}
+			Operator::I16x8AllTrue => {
+				self.handle_v128_reduce(V128ReduceOperator::AllTrue(LaneType::I16));
+			}
+			Operator::I16x8Bitmask => {
+				self.handle_v128_reduce(V128ReduceOperator::BitMask(LaneType::I16));
+			}
+			Operator::I16x8NarrowI32x4S => {
+				self.handle_v128_binary(V128BinaryOperator::Narrow {
+					from: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I16x8NarrowI32x4U => {
+				self.handle_v128_binary(V128BinaryOperator::Narrow {
+					from: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::I16x8ExtendLowI8x16S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendLow {
+					from: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I16x8ExtendLowI8x16U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendLow {
+					from: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I16x8ExtendHighI8x16S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendHigh {
+					from: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I16x8ExtendHighI8x16U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendHigh {
+					from: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I16x8Shl => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftLeft(LaneType::I16));
+			}
+			Operator::I16x8ShrS => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I16x8ShrU => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I16x8Add => self.handle_v128_binary(V128BinaryOperator::Add(LaneType::I16)),
+			Operator::I16x8AddSatS => {
+				self.handle_v128_binary(V128BinaryOperator::AddSaturate {
+					r#type: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I16x8AddSatU => {
+				self.handle_v128_binary(V128BinaryOperator::AddSaturate {
+					r#type: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I16x8Sub => {
+				self.handle_v128_binary(V128BinaryOperator::Subtract(LaneType::I16));
+			}
+			Operator::I16x8SubSatS => {
+				self.handle_v128_binary(V128BinaryOperator::SubtractSaturate {
+					r#type: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I16x8SubSatU => {
+				self.handle_v128_binary(V128BinaryOperator::SubtractSaturate {
+					r#type: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I16x8Mul => {
+				self.handle_v128_binary(V128BinaryOperator::Multiply(LaneType::I16));
+			}
+			Operator::I16x8MinS => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMinimum {
+					r#type: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I16x8MinU => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMinimum {
+					r#type: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I16x8MaxS => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMaximum {
+					r#type: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I16x8MaxU => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMaximum {
+					r#type: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I16x8AvgrU => {
+				self.handle_v128_binary(V128BinaryOperator::Average(LaneType::I16));
+			}
+			Operator::I16x8ExtMulLowI8x16S => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyLow {
+					from: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I16x8ExtMulLowI8x16U => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyLow {
+					from: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I16x8ExtMulHighI8x16S => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyHigh {
+					from: LaneType::I8,
+					signed: true,
+				});
+			}
+			Operator::I16x8ExtMulHighI8x16U => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyHigh {
+					from: LaneType::I8,
+					signed: false,
+				});
+			}
+			Operator::I32x4ExtAddPairwiseI16x8S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendAddPairwise {
+					from: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I32x4ExtAddPairwiseI16x8U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendAddPairwise {
+					from: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I32x4Abs => {
+				self.handle_v128_unary(V128UnaryOperator::Absolute(LaneType::I32));
+			}
+			Operator::I32x4Neg => self.handle_v128_unary(V128UnaryOperator::Negate(LaneType::I32)),
+			Operator::I32x4AllTrue => {
+				self.handle_v128_reduce(V128ReduceOperator::AllTrue(LaneType::I32));
+			}
+			Operator::I32x4Bitmask => {
+				self.handle_v128_reduce(V128ReduceOperator::BitMask(LaneType::I32));
+			}
+			Operator::I32x4ExtendLowI16x8S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendLow {
+					from: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I32x4ExtendLowI16x8U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendLow {
+					from: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I32x4ExtendHighI16x8S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendHigh {
+					from: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I32x4ExtendHighI16x8U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendHigh {
+					from: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I32x4Shl => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftLeft(LaneType::I32));
+			}
+			Operator::I32x4ShrS => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I32x4ShrU => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::I32x4Add => self.handle_v128_binary(V128BinaryOperator::Add(LaneType::I32)),
+			Operator::I32x4Sub => {
+				self.handle_v128_binary(V128BinaryOperator::Subtract(LaneType::I32));
+			}
+			Operator::I32x4Mul => {
+				self.handle_v128_binary(V128BinaryOperator::Multiply(LaneType::I32));
+			}
+			Operator::I32x4MinS => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMinimum {
+					r#type: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I32x4MinU => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMinimum {
+					r#type: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::I32x4MaxS => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMaximum {
+					r#type: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I32x4MaxU => {
+				self.handle_v128_binary(V128BinaryOperator::IntegerMaximum {
+					r#type: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::I32x4DotI16x8S => self.handle_v128_binary(V128BinaryOperator::DotProduct),
+			Operator::I32x4ExtMulLowI16x8S => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyLow {
+					from: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I32x4ExtMulLowI16x8U => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyLow {
+					from: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I32x4ExtMulHighI16x8S => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyHigh {
+					from: LaneType::I16,
+					signed: true,
+				});
+			}
+			Operator::I32x4ExtMulHighI16x8U => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyHigh {
+					from: LaneType::I16,
+					signed: false,
+				});
+			}
+			Operator::I64x2Abs => {
+				self.handle_v128_unary(V128UnaryOperator::Absolute(LaneType::I64));
+			}
+			Operator::I64x2Neg => self.handle_v128_unary(V128UnaryOperator::Negate(LaneType::I64)),
+			Operator::I64x2AllTrue => {
+				self.handle_v128_reduce(V128ReduceOperator::AllTrue(LaneType::I64));
+			}
+			Operator::I64x2Bitmask => {
+				self.handle_v128_reduce(V128ReduceOperator::BitMask(LaneType::I64));
+			}
+			Operator::I64x2ExtendLowI32x4S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendLow {
+					from: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I64x2ExtendLowI32x4U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendLow {
+					from: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::I64x2ExtendHighI32x4S => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendHigh {
+					from: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I64x2ExtendHighI32x4U => {
+				self.handle_v128_unary(V128UnaryOperator::ExtendHigh {
+					from: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::I64x2Shl => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftLeft(LaneType::I64));
+			}
+			Operator::I64x2ShrS => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I64,
+					signed: true,
+				});
+			}
+			Operator::I64x2ShrU => {
+				self.handle_v128_binary(V128BinaryOperator::ShiftRight {
+					r#type: LaneType::I64,
+					signed: false,
+				});
+			}
+			Operator::I64x2Add => self.handle_v128_binary(V128BinaryOperator::Add(LaneType::I64)),
+			Operator::I64x2Sub => {
+				self.handle_v128_binary(V128BinaryOperator::Subtract(LaneType::I64));
+			}
+			Operator::I64x2Mul => {
+				self.handle_v128_binary(V128BinaryOperator::Multiply(LaneType::I64));
+			}
+			Operator::I64x2ExtMulLowI32x4S => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyLow {
+					from: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I64x2ExtMulLowI32x4U => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyLow {
+					from: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::I64x2ExtMulHighI32x4S => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyHigh {
+					from: LaneType::I32,
+					signed: true,
+				});
+			}
+			Operator::I64x2ExtMulHighI32x4U => {
+				self.handle_v128_binary(V128BinaryOperator::ExtendMultiplyHigh {
+					from: LaneType::I32,
+					signed: false,
+				});
+			}
+			Operator::F32x4Ceil => {
+				self.handle_v128_unary(V128UnaryOperator::RoundUp(LaneType::F32));
+			}
+			Operator::F32x4Floor => {
+				self.handle_v128_unary(V128UnaryOperator::RoundDown(LaneType::F32));
+			}
+			Operator::F32x4Trunc => {
+				self.handle_v128_unary(V128UnaryOperator::Truncate(LaneType::F32));
+			}
+			Operator::F32x4Nearest => {
+				self.handle_v128_unary(V128UnaryOperator::Nearest(LaneType::F32));
+			}
+			Operator::F32x4Abs => {
+				self.handle_v128_unary(V128UnaryOperator::Absolute(LaneType::F32));
+			}
+			Operator::F32x4Neg => self.handle_v128_unary(V128UnaryOperator::Negate(LaneType::F32)),
+			Operator::F32x4Sqrt => {
+				self.handle_v128_unary(V128UnaryOperator::SquareRoot(LaneType::F32));
+			}
+			Operator::F32x4Add => self.handle_v128_binary(V128BinaryOperator::Add(LaneType::F32)),
+			Operator::F32x4Sub => {
+				self.handle_v128_binary(V128BinaryOperator::Subtract(LaneType::F32));
+			}
+			Operator::F32x4Mul => {
+				self.handle_v128_binary(V128BinaryOperator::Multiply(LaneType::F32));
+			}
+			Operator::F32x4Div => {
+				self.handle_v128_binary(V128BinaryOperator::Divide(LaneType::F32));
+			}
+			Operator::F32x4Min => {
+				self.handle_v128_binary(V128BinaryOperator::NumberMinimum(LaneType::F32));
+			}
+			Operator::F32x4Max => {
+				self.handle_v128_binary(V128BinaryOperator::NumberMaximum(LaneType::F32));
+			}
+			Operator::F32x4PMin => {
+				self.handle_v128_binary(V128BinaryOperator::PseudoMinimum(LaneType::F32));
+			}
+			Operator::F32x4PMax => {
+				self.handle_v128_binary(V128BinaryOperator::PseudoMaximum(LaneType::F32));
+			}
+			Operator::F64x2Ceil => {
+				self.handle_v128_unary(V128UnaryOperator::RoundUp(LaneType::F64));
+			}
+			Operator::F64x2Floor => {
+				self.handle_v128_unary(V128UnaryOperator::RoundDown(LaneType::F64));
+			}
+			Operator::F64x2Trunc => {
+				self.handle_v128_unary(V128UnaryOperator::Truncate(LaneType::F64));
+			}
+			Operator::F64x2Nearest => {
+				self.handle_v128_unary(V128UnaryOperator::Nearest(LaneType::F64));
+			}
+			Operator::F64x2Abs => {
+				self.handle_v128_unary(V128UnaryOperator::Absolute(LaneType::F64));
+			}
+			Operator::F64x2Neg => self.handle_v128_unary(V128UnaryOperator::Negate(LaneType::F64)),
+			Operator::F64x2Sqrt => {
+				self.handle_v128_unary(V128UnaryOperator::SquareRoot(LaneType::F64));
+			}
+			Operator::F64x2Add => self.handle_v128_binary(V128BinaryOperator::Add(LaneType::F64)),
+			Operator::F64x2Sub => {
+				self.handle_v128_binary(V128BinaryOperator::Subtract(LaneType::F64));
+			}
+			Operator::F64x2Mul => {
+				self.handle_v128_binary(V128BinaryOperator::Multiply(LaneType::F64));
+			}
+			Operator::F64x2Div => {
+				self.handle_v128_binary(V128BinaryOperator::Divide(LaneType::F64));
+			}
+			Operator::F64x2Min => {
+				self.handle_v128_binary(V128BinaryOperator::NumberMinimum(LaneType::F64));
+			}
+			Operator::F64x2Max => {
+				self.handle_v128_binary(V128BinaryOperator::NumberMaximum(LaneType::F64));
+			}
+			Operator::F64x2PMin => {
+				self.handle_v128_binary(V128BinaryOperator::PseudoMinimum(LaneType::F64));
+			}
+			Operator::F64x2PMax => {
+				self.handle_v128_binary(V128BinaryOperator::PseudoMaximum(LaneType::F64));
+			}
+			Operator::I32x4TruncSatF32x4S => {
+				self.handle_v128_unary(V128UnaryOperator::Saturate {
+					from: LaneType::F32,
+					signed: true,
+				});
+			}
+			Operator::I32x4TruncSatF32x4U => {
+				self.handle_v128_unary(V128UnaryOperator::Saturate {
+					from: LaneType::F32,
+					signed: false,
+				});
+			}
+			Operator::F32x4ConvertI32x4S => {
+				self.handle_v128_unary(V128UnaryOperator::Convert {
+					to: LaneType::F32,
+					signed: true,
+				});
+			}
+			Operator::F32x4ConvertI32x4U => {
+				self.handle_v128_unary(V128UnaryOperator::Convert {
+					to: LaneType::F32,
+					signed: false,
+				});
+			}
+			Operator::I32x4TruncSatF64x2SZero => {
+				self.handle_v128_unary(V128UnaryOperator::Saturate {
+					from: LaneType::F64,
+					signed: true,
+				});
+			}
+			Operator::I32x4TruncSatF64x2UZero => {
+				self.handle_v128_unary(V128UnaryOperator::Saturate {
+					from: LaneType::F64,
+					signed: false,
+				});
+			}
+			Operator::F64x2ConvertLowI32x4S => {
+				self.handle_v128_unary(V128UnaryOperator::Convert {
+					to: LaneType::F64,
+					signed: true,
+				});
+			}
+			Operator::F64x2ConvertLowI32x4U => {
+				self.handle_v128_unary(V128UnaryOperator::Convert {
+					to: LaneType::F64,
+					signed: false,
+				});
+			}
+			Operator::F32x4DemoteF64x2Zero => self.handle_v128_unary(V128UnaryOperator::Narrow),
+			Operator::F64x2PromoteLowF32x4 => self.handle_v128_unary(V128UnaryOperator::Widen),
 
 			operator => unimplemented!("{operator:?}"),
 		}
@@ -974,10 +2854,15 @@ impl BasicBlockBuilder {
 		self.stack_builder
 			.set_function_data(types, function_type, locals);
 
+		self.memory_64_flags.clear();
+		self.memory_64_flags
+			.extend_from_slice(types.get_memory_64_flags());
+
 		self.code_builder.clear();
 		self.code_builder.add_basic_block(1);
 
-		for operator in operators.into_iter().map(Result::unwrap) {
+		for (operator, offset) in operators.into_iter_with_offsets().map(Result::unwrap) {
+			self.code_builder.add_position(offset.try_into().unwrap());
 			self.handle_operator(types, operator);
 		}
 
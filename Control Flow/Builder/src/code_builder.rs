@@ -2,16 +2,20 @@ use alloc::vec::Vec;
 use control_flow_graph::{
 	BasicBlock, ControlFlowGraph,
 	instruction::{
-		Call, DataDrop, ElementsDrop, ExtendType, F32Constant, F64Constant, GlobalGet, GlobalSet,
-		I32Constant, I64Constant, Instruction, IntegerBinaryOperation, IntegerBinaryOperator,
-		IntegerCompareOperation, IntegerCompareOperator, IntegerConvertToNumber, IntegerExtend,
-		IntegerNarrow, IntegerTransmuteToNumber, IntegerType, IntegerUnaryOperation,
-		IntegerUnaryOperator, IntegerWiden, LoadType, LocalBranch, LocalSet, Location, MemoryCopy,
-		MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemorySize, MemoryStore,
-		NumberBinaryOperation, NumberBinaryOperator, NumberCompareOperation, NumberCompareOperator,
-		NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger, NumberType,
-		NumberUnaryOperation, NumberUnaryOperator, NumberWiden, RefFunction, RefIsNull, RefNull,
-		StoreType, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize,
+		Call, DataDrop, ElementsDrop, ExceptionGet, ExceptionNew, ExceptionTest, ExtendType,
+		F32Constant, F64Constant, FunctionCheck, GlobalGet, GlobalSet, I32Constant, I64Constant,
+		Instruction, IntegerBinaryOperation, IntegerBinaryOperator, IntegerCompareOperation,
+		IntegerCompareOperator, IntegerConvertToNumber, IntegerExtend, IntegerNarrow,
+		IntegerTransmuteToNumber, IntegerType, IntegerUnaryOperation, IntegerUnaryOperator,
+		IntegerWiden, LaneType, LoadType, LocalBranch, LocalSet, Location, MemoryCopy, MemoryFill,
+		MemoryGrow, MemoryInit, MemoryLoad, MemorySize, MemoryStore, NumberBinaryOperation,
+		NumberBinaryOperator, NumberCompareOperation, NumberCompareOperator, NumberNarrow,
+		NumberTransmuteToInteger, NumberTruncateToInteger, NumberType, NumberUnaryOperation,
+		NumberUnaryOperator, NumberWiden, Position, RefFunction, RefIsNull, RefNull, StoreType,
+		TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize, Throw,
+		V128BinaryOperation, V128BinaryOperator, V128BitSelect, V128Constant, V128ExtractLane,
+		V128ReduceOperation, V128ReduceOperator, V128ReplaceLane, V128Shuffle, V128Splat,
+		V128UnaryOperation, V128UnaryOperator,
 	},
 };
 use list::resizable::Resizable;
@@ -86,6 +90,18 @@ impl CodeBuilder {
 		basic_blocks
 	}
 
+	pub fn add_position(&mut self, offset: u32) {
+		let position = Instruction::Position(Position { offset });
+		let start = usize::try_from(self.position).unwrap();
+
+		// Operators that emit nothing would leave a stale position behind.
+		if let Some(last @ Instruction::Position(_)) = self.instructions[start..].last_mut() {
+			*last = position;
+		} else {
+			self.instructions.push(position);
+		}
+	}
+
 	pub fn add_local_set(&mut self, destination: u16, source: u16) {
 		let local_set = Instruction::LocalSet(LocalSet {
 			destination,
@@ -157,11 +173,20 @@ impl CodeBuilder {
 		self.add_basic_block(1)
 	}
 
-	pub fn add_call(&mut self, destinations: (u16, u16), sources: (u16, u16), function: u16) {
+	pub fn add_call(
+		&mut self,
+		destinations: (u16, u16),
+		sources: (u16, u16),
+		function: u16,
+		tail: bool,
+		catch: bool,
+	) {
 		let call = Instruction::Call(Call {
 			destinations,
 			sources,
 			function,
+			tail,
+			catch,
 		});
 
 		self.instructions.push(call);
@@ -191,6 +216,16 @@ impl CodeBuilder {
 		self.instructions.push(ref_function);
 	}
 
+	pub fn add_function_check(&mut self, destination: u16, source: u16, r#type: u32) {
+		let function_check = Instruction::FunctionCheck(FunctionCheck {
+			destination,
+			source,
+			r#type,
+		});
+
+		self.instructions.push(function_check);
+	}
+
 	pub fn add_integer_unary_operation(
 		&mut self,
 		destination: u16,
@@ -424,6 +459,129 @@ impl CodeBuilder {
 		self.instructions.push(number_transmute_to_integer);
 	}
 
+	pub fn add_v128_constant(&mut self, destination: u16, data: u128) {
+		let v128 = Instruction::V128Constant(V128Constant { destination, data });
+
+		self.instructions.push(v128);
+	}
+
+	pub fn add_v128_unary_operation(
+		&mut self,
+		destination: u16,
+		source: u16,
+		operator: V128UnaryOperator,
+	) {
+		let v128_unary_operation = Instruction::V128UnaryOperation(V128UnaryOperation {
+			destination,
+			source,
+			operator,
+		});
+
+		self.instructions.push(v128_unary_operation);
+	}
+
+	pub fn add_v128_binary_operation(
+		&mut self,
+		destination: u16,
+		lhs: u16,
+		rhs: u16,
+		operator: V128BinaryOperator,
+	) {
+		let v128_binary_operation = Instruction::V128BinaryOperation(V128BinaryOperation {
+			destination,
+			lhs,
+			rhs,
+			operator,
+		});
+
+		self.instructions.push(v128_binary_operation);
+	}
+
+	pub fn add_v128_reduce_operation(
+		&mut self,
+		destination: u16,
+		source: u16,
+		operator: V128ReduceOperator,
+	) {
+		let v128_reduce_operation = Instruction::V128ReduceOperation(V128ReduceOperation {
+			destination,
+			source,
+			operator,
+		});
+
+		self.instructions.push(v128_reduce_operation);
+	}
+
+	pub fn add_v128_splat(&mut self, destination: u16, source: u16, r#type: LaneType) {
+		let v128_splat = Instruction::V128Splat(V128Splat {
+			destination,
+			source,
+			r#type,
+		});
+
+		self.instructions.push(v128_splat);
+	}
+
+	pub fn add_v128_extract_lane(
+		&mut self,
+		destination: u16,
+		source: u16,
+		r#type: LaneType,
+		lane: u8,
+		signed: bool,
+	) {
+		let v128_extract_lane = Instruction::V128ExtractLane(V128ExtractLane {
+			destination,
+			source,
+			r#type,
+			lane,
+			signed,
+		});
+
+		self.instructions.push(v128_extract_lane);
+	}
+
+	pub fn add_v128_replace_lane(
+		&mut self,
+		destination: u16,
+		lhs: u16,
+		rhs: u16,
+		r#type: LaneType,
+		lane: u8,
+	) {
+		let v128_replace_lane = Instruction::V128ReplaceLane(V128ReplaceLane {
+			destination,
+			lhs,
+			rhs,
+			r#type,
+			lane,
+		});
+
+		self.instructions.push(v128_replace_lane);
+	}
+
+	pub fn add_v128_shuffle(&mut self, destination: u16, lhs: u16, rhs: u16, lanes: [u8; 16]) {
+		let v128_shuffle = Instruction::V128Shuffle(V128Shuffle {
+			destination,
+			lhs,
+			rhs,
+			lanes,
+		});
+
+		self.instructions.push(v128_shuffle);
+	}
+
+	pub fn add_v128_bit_select(&mut self, destination: u16, lhs: u16, rhs: u16, condition: u16) {
+		let v128_bit_select = Instruction::V128BitSelect(V128BitSelect {
+			destination,
+			lhs,
+			rhs,
+			condition,
+		});
+
+		self.instructions.push(v128_bit_select);
+	}
+
 	pub fn add_global_get(&mut self, destination: u16, source: u16) {
 		let global_get = Instruction::GlobalGet(GlobalGet {
 			destination,
@@ -597,6 +755,44 @@ impl CodeBuilder {
 		self.instructions.push(data_drop);
 	}
 
+	pub fn add_throw(&mut self, source: u16) -> u16 {
+		let throw = Instruction::Throw(Throw { source });
+
+		self.instructions.push(throw);
+
+		self.add_basic_block(1)
+	}
+
+	pub fn add_exception_new(&mut self, destination: u16, sources: (u16, u16), tag: u16) {
+		let exception_new = Instruction::ExceptionNew(ExceptionNew {
+			destination,
+			sources,
+			tag,
+		});
+
+		self.instructions.push(exception_new);
+	}
+
+	pub fn add_exception_test(&mut self, destination: u16, source: u16, tag: u16) {
+		let exception_test = Instruction::ExceptionTest(ExceptionTest {
+			destination,
+			source,
+			tag,
+		});
+
+		self.instructions.push(exception_test);
+	}
+
+	pub fn add_exception_get(&mut self, destination: u16, source: u16, index: u16) {
+		let exception_get = Instruction::ExceptionGet(ExceptionGet {
+			destination,
+			source,
+			index,
+		});
+
+		self.instructions.push(exception_get);
+	}
+
 	pub fn set_jump_destination(&mut self, source: u16, branch: u16, destination: u16) {
 		let source = usize::from(source);
 		let branch = usize::from(branch);
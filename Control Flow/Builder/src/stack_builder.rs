@@ -78,6 +78,10 @@ impl StackBuilder {
 		level
 	}
 
+	pub const fn get_level_count(&self) -> usize {
+		self.levels.len()
+	}
+
 	pub fn peek_level_mut(&mut self) -> &mut Level {
 		self.levels.last_mut().unwrap()
 	}
@@ -1,9 +1,43 @@
-use alloc::vec::Vec;
-use wasmparser::{BlockType, FuncType, RecGroup, SectionLimited, SubType};
+use alloc::{string::String, sync::Arc, vec::Vec};
+use core::fmt::Write;
+use wasmparser::{
+	BlockType, CompositeInnerType, FuncType, MemoryType, RecGroup, SectionLimited, SubType,
+	TagType, ValType,
+};
+
+fn write_value_types(signature: &mut String, types: &[ValType]) {
+	for (index, value) in types.iter().enumerate() {
+		if index != 0 {
+			signature.push(' ');
+		}
+
+		write!(signature, "{value}").unwrap();
+	}
+}
+
+// Function types are identified by their structure, so that functions
+// coming from different modules can still be compared.
+fn load_signature(sub_type: &SubType) -> Arc<str> {
+	let CompositeInnerType::Func(r#type) = &sub_type.composite_type.inner else {
+		return Arc::default();
+	};
+
+	let mut signature = String::from("(");
+
+	write_value_types(&mut signature, r#type.params());
+	signature.push_str(") -> (");
+	write_value_types(&mut signature, r#type.results());
+	signature.push(')');
+
+	signature.into()
+}
 
 pub struct Types {
 	sub_types: Vec<SubType>,
+	signatures: Vec<Arc<str>>,
 	functions: Vec<u32>,
+	tags: Vec<u32>,
+	memories: Vec<bool>,
 }
 
 impl Types {
@@ -11,19 +45,31 @@ impl Types {
 	pub const fn new() -> Self {
 		Self {
 			sub_types: Vec::new(),
+			signatures: Vec::new(),
 			functions: Vec::new(),
+			tags: Vec::new(),
+			memories: Vec::new(),
 		}
 	}
 
 	pub fn clear(&mut self) {
 		self.sub_types.clear();
+		self.signatures.clear();
 		self.functions.clear();
+		self.tags.clear();
+		self.memories.clear();
 	}
 
 	pub fn add_sub_types(&mut self, section: SectionLimited<RecGroup>) {
 		for group in section.into_iter().map(Result::unwrap) {
 			self.sub_types.extend(group.into_types());
 		}
+
+		self.signatures.extend(
+			self.sub_types[self.signatures.len()..]
+				.iter()
+				.map(load_signature),
+		);
 	}
 
 	pub fn add_function(&mut self, function: u32) {
@@ -35,6 +81,32 @@ impl Types {
 			.extend(section.into_iter().map(Result::unwrap));
 	}
 
+	pub fn add_tag(&mut self, tag: TagType) {
+		self.tags.push(tag.func_type_idx);
+	}
+
+	pub fn add_tags(&mut self, section: SectionLimited<TagType>) {
+		self.tags.extend(
+			section
+				.into_iter()
+				.map(Result::unwrap)
+				.map(|tag| tag.func_type_idx),
+		);
+	}
+
+	pub fn add_memory(&mut self, memory: MemoryType) {
+		self.memories.push(memory.memory64);
+	}
+
+	pub fn add_memories(&mut self, section: SectionLimited<MemoryType>) {
+		self.memories.extend(
+			section
+				.into_iter()
+				.map(Result::unwrap)
+				.map(|memory| memory.memory64),
+		);
+	}
+
 	#[expect(clippy::missing_panics_doc)]
 	#[must_use]
 	pub fn get_function_index(&self, function: u32) -> u32 {
@@ -47,6 +119,12 @@ impl Types {
 		&self.sub_types[usize::try_from(r#type).unwrap()]
 	}
 
+	#[expect(clippy::missing_panics_doc)]
+	#[must_use]
+	pub fn get_signature(&self, r#type: u32) -> &Arc<str> {
+		&self.signatures[usize::try_from(r#type).unwrap()]
+	}
+
 	#[must_use]
 	pub fn get_function_type(&self, function: u32) -> &FuncType {
 		let function = self.get_function_index(function);
@@ -54,6 +132,19 @@ impl Types {
 		self.get_type(function).unwrap_func()
 	}
 
+	#[expect(clippy::missing_panics_doc)]
+	#[must_use]
+	pub fn get_tag_type(&self, tag: u32) -> &FuncType {
+		let tag = self.tags[usize::try_from(tag).unwrap()];
+
+		self.get_type(tag).unwrap_func()
+	}
+
+	#[must_use]
+	pub fn get_memory_64_flags(&self) -> &[bool] {
+		&self.memories
+	}
+
 	#[must_use]
 	pub fn get_parameter_count(&self, block_type: BlockType) -> usize {
 		match block_type {
@@ -53,6 +53,7 @@ fn fmt_instruction(instruction: Instruction, f: &mut Formatter) -> Result {
 	use core::fmt::Debug;
 
 	match instruction {
+		Instruction::Position(position) => Debug::fmt(&position, f),
 		Instruction::LocalSet(local_set) => Debug::fmt(&local_set, f),
 		Instruction::LocalBranch(local_branch) => Debug::fmt(&local_branch, f),
 		Instruction::I32Constant(i32_constant) => Debug::fmt(&i32_constant, f),
@@ -62,8 +63,10 @@ fn fmt_instruction(instruction: Instruction, f: &mut Formatter) -> Result {
 		Instruction::RefIsNull(ref_is_null) => Debug::fmt(&ref_is_null, f),
 		Instruction::RefNull(ref_null) => Debug::fmt(&ref_null, f),
 		Instruction::RefFunction(ref_function) => Debug::fmt(&ref_function, f),
+		Instruction::FunctionCheck(function_check) => Debug::fmt(&function_check, f),
 		Instruction::Call(call) => Debug::fmt(&call, f),
 		Instruction::Unreachable => write!(f, "Unreachable"),
+		Instruction::Throw(throw) => Debug::fmt(&throw, f),
 		Instruction::IntegerUnaryOperation(integer_unary_operation) => {
 			Debug::fmt(&integer_unary_operation, f)
 		}
@@ -99,6 +102,21 @@ fn fmt_instruction(instruction: Instruction, f: &mut Formatter) -> Result {
 		Instruction::NumberTransmuteToInteger(number_transmute_to_integer) => {
 			Debug::fmt(&number_transmute_to_integer, f)
 		}
+		Instruction::V128Constant(v128_constant) => Debug::fmt(&v128_constant, f),
+		Instruction::V128UnaryOperation(v128_unary_operation) => {
+			Debug::fmt(&v128_unary_operation, f)
+		}
+		Instruction::V128BinaryOperation(v128_binary_operation) => {
+			Debug::fmt(&v128_binary_operation, f)
+		}
+		Instruction::V128ReduceOperation(v128_reduce_operation) => {
+			Debug::fmt(&v128_reduce_operation, f)
+		}
+		Instruction::V128Splat(v128_splat) => Debug::fmt(&v128_splat, f),
+		Instruction::V128ExtractLane(v128_extract_lane) => Debug::fmt(&v128_extract_lane, f),
+		Instruction::V128ReplaceLane(v128_replace_lane) => Debug::fmt(&v128_replace_lane, f),
+		Instruction::V128Shuffle(v128_shuffle) => Debug::fmt(&v128_shuffle, f),
+		Instruction::V128BitSelect(v128_bit_select) => Debug::fmt(&v128_bit_select, f),
 		Instruction::GlobalGet(global_get) => Debug::fmt(&global_get, f),
 		Instruction::GlobalSet(global_set) => Debug::fmt(&global_set, f),
 		Instruction::TableGet(table_get) => Debug::fmt(&table_get, f),
@@ -117,6 +135,9 @@ fn fmt_instruction(instruction: Instruction, f: &mut Formatter) -> Result {
 		Instruction::MemoryCopy(memory_copy) => Debug::fmt(&memory_copy, f),
 		Instruction::MemoryInit(memory_init) => Debug::fmt(&memory_init, f),
 		Instruction::DataDrop(data_drop) => Debug::fmt(&data_drop, f),
+		Instruction::ExceptionNew(exception_new) => Debug::fmt(&exception_new, f),
+		Instruction::ExceptionTest(exception_test) => Debug::fmt(&exception_test, f),
+		Instruction::ExceptionGet(exception_get) => Debug::fmt(&exception_get, f),
 	}
 }
 
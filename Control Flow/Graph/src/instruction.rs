@@ -10,6 +10,11 @@ impl Name {
 	pub const COUNT: u16 = Self::D as u16 + 1;
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct Position {
+	pub offset: u32,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct LocalSet {
 	pub destination: u16,
@@ -50,6 +55,8 @@ pub struct Call {
 	pub destinations: (u16, u16),
 	pub sources: (u16, u16),
 	pub function: u16,
+	pub tail: bool,
+	pub catch: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -69,13 +76,20 @@ pub struct RefFunction {
 	pub function: u16,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug)]
+pub struct FunctionCheck {
+	pub destination: u16,
+	pub source: u16,
+	pub r#type: u32,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum IntegerType {
 	I32,
 	I64,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum IntegerUnaryOperator {
 	CountOnes,
 	LeadingZeroes,
@@ -91,7 +105,7 @@ pub struct IntegerUnaryOperation {
 	pub operator: IntegerUnaryOperator,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum IntegerBinaryOperator {
 	Add,
 	Subtract,
@@ -117,7 +131,7 @@ pub struct IntegerBinaryOperation {
 	pub operator: IntegerBinaryOperator,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum IntegerCompareOperator {
 	Equal,
 	NotEqual,
@@ -150,7 +164,7 @@ pub struct IntegerWiden {
 }
 
 #[expect(non_camel_case_types)]
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum ExtendType {
 	I32_S8,
 	I32_S16,
@@ -186,13 +200,13 @@ pub struct IntegerTransmuteToNumber {
 	pub from: IntegerType,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum NumberType {
 	F32,
 	F64,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum NumberUnaryOperator {
 	Absolute,
 	Negate,
@@ -212,7 +226,7 @@ pub struct NumberUnaryOperation {
 	pub operator: NumberUnaryOperator,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum NumberBinaryOperator {
 	Add,
 	Subtract,
@@ -233,7 +247,7 @@ pub struct NumberBinaryOperation {
 	pub operator: NumberBinaryOperator,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum NumberCompareOperator {
 	Equal,
 	NotEqual,
@@ -284,6 +298,150 @@ pub struct NumberWiden {
 	pub source: u16,
 }
 
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum LaneType {
+	I8,
+	I16,
+	I32,
+	I64,
+	F32,
+	F64,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128Constant {
+	pub destination: u16,
+	pub data: u128,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum V128UnaryOperator {
+	Not,
+	CountOnes,
+	Absolute(LaneType),
+	Negate(LaneType),
+	SquareRoot(LaneType),
+	RoundUp(LaneType),
+	RoundDown(LaneType),
+	Truncate(LaneType),
+	Nearest(LaneType),
+	ExtendLow { from: LaneType, signed: bool },
+	ExtendHigh { from: LaneType, signed: bool },
+	ExtendAddPairwise { from: LaneType, signed: bool },
+	Saturate { from: LaneType, signed: bool },
+	Convert { to: LaneType, signed: bool },
+	Narrow,
+	Widen,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128UnaryOperation {
+	pub destination: u16,
+	pub source: u16,
+
+	pub operator: V128UnaryOperator,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum V128BinaryOperator {
+	And,
+	AndNot,
+	Or,
+	ExclusiveOr,
+	Swizzle,
+	DotProduct,
+	MultiplyRoundSaturate,
+	Add(LaneType),
+	Subtract(LaneType),
+	Multiply(LaneType),
+	Divide(LaneType),
+	AddSaturate { r#type: LaneType, signed: bool },
+	SubtractSaturate { r#type: LaneType, signed: bool },
+	IntegerMinimum { r#type: LaneType, signed: bool },
+	IntegerMaximum { r#type: LaneType, signed: bool },
+	NumberMinimum(LaneType),
+	NumberMaximum(LaneType),
+	PseudoMinimum(LaneType),
+	PseudoMaximum(LaneType),
+	Average(LaneType),
+	ShiftLeft(LaneType),
+	ShiftRight { r#type: LaneType, signed: bool },
+	ExtendMultiplyLow { from: LaneType, signed: bool },
+	ExtendMultiplyHigh { from: LaneType, signed: bool },
+	Narrow { from: LaneType, signed: bool },
+	IntegerCompare(LaneType, IntegerCompareOperator),
+	NumberCompare(LaneType, NumberCompareOperator),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128BinaryOperation {
+	pub destination: u16,
+	pub lhs: u16,
+	pub rhs: u16,
+
+	pub operator: V128BinaryOperator,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum V128ReduceOperator {
+	AnyTrue,
+	AllTrue(LaneType),
+	BitMask(LaneType),
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128ReduceOperation {
+	pub destination: u16,
+	pub source: u16,
+
+	pub operator: V128ReduceOperator,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128Splat {
+	pub destination: u16,
+	pub source: u16,
+
+	pub r#type: LaneType,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128ExtractLane {
+	pub destination: u16,
+	pub source: u16,
+
+	pub r#type: LaneType,
+	pub lane: u8,
+	pub signed: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128ReplaceLane {
+	pub destination: u16,
+	pub lhs: u16,
+	pub rhs: u16,
+
+	pub r#type: LaneType,
+	pub lane: u8,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128Shuffle {
+	pub destination: u16,
+	pub lhs: u16,
+	pub rhs: u16,
+
+	pub lanes: [u8; 16],
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct V128BitSelect {
+	pub destination: u16,
+	pub lhs: u16,
+	pub rhs: u16,
+	pub condition: u16,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct GlobalGet {
 	pub destination: u16,
@@ -355,7 +513,7 @@ pub struct ElementsDrop {
 }
 
 #[expect(non_camel_case_types)]
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum LoadType {
 	I32_S8,
 	I32_U8,
@@ -373,6 +531,8 @@ pub enum LoadType {
 
 	F32,
 	F64,
+
+	V128,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -396,6 +556,8 @@ pub enum StoreType {
 
 	F32,
 	F64,
+
+	V128,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -444,8 +606,36 @@ pub struct DataDrop {
 	pub source: u16,
 }
 
+#[derive(Clone, Copy, Debug)]
+pub struct Throw {
+	pub source: u16,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExceptionNew {
+	pub destination: u16,
+	pub sources: (u16, u16),
+	pub tag: u16,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExceptionTest {
+	pub destination: u16,
+	pub source: u16,
+	pub tag: u16,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ExceptionGet {
+	pub destination: u16,
+	pub source: u16,
+	pub index: u16,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum Instruction {
+	Position(Position),
+
 	LocalSet(LocalSet),
 	LocalBranch(LocalBranch),
 
@@ -457,10 +647,12 @@ pub enum Instruction {
 	RefIsNull(RefIsNull),
 	RefNull(RefNull),
 	RefFunction(RefFunction),
+	FunctionCheck(FunctionCheck),
 
 	Call(Call),
 
 	Unreachable,
+	Throw(Throw),
 
 	IntegerUnaryOperation(IntegerUnaryOperation),
 	IntegerBinaryOperation(IntegerBinaryOperation),
@@ -479,6 +671,16 @@ pub enum Instruction {
 	NumberTruncateToInteger(NumberTruncateToInteger),
 	NumberTransmuteToInteger(NumberTransmuteToInteger),
 
+	V128Constant(V128Constant),
+	V128UnaryOperation(V128UnaryOperation),
+	V128BinaryOperation(V128BinaryOperation),
+	V128ReduceOperation(V128ReduceOperation),
+	V128Splat(V128Splat),
+	V128ExtractLane(V128ExtractLane),
+	V128ReplaceLane(V128ReplaceLane),
+	V128Shuffle(V128Shuffle),
+	V128BitSelect(V128BitSelect),
+
 	GlobalGet(GlobalGet),
 	GlobalSet(GlobalSet),
 
@@ -501,4 +703,8 @@ pub enum Instruction {
 	MemoryInit(MemoryInit),
 
 	DataDrop(DataDrop),
+
+	ExceptionNew(ExceptionNew),
+	ExceptionTest(ExceptionTest),
+	ExceptionGet(ExceptionGet),
 }
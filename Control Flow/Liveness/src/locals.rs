@@ -2,14 +2,16 @@ use alloc::vec::Vec;
 use control_flow_graph::{
 	ControlFlowGraph,
 	instruction::{
-		Call, F32Constant, F64Constant, GlobalGet, GlobalSet, I32Constant, I64Constant,
-		Instruction, IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber,
-		IntegerExtend, IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation,
-		IntegerWiden, LocalBranch, LocalSet, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit,
-		MemoryLoad, MemorySize, MemoryStore, Name, NumberBinaryOperation, NumberCompareOperation,
-		NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation,
-		NumberWiden, RefFunction, RefIsNull, RefNull, TableCopy, TableFill, TableGet, TableGrow,
-		TableInit, TableSet, TableSize,
+		Call, ExceptionGet, ExceptionNew, ExceptionTest, F32Constant, F64Constant, FunctionCheck,
+		GlobalGet, GlobalSet, I32Constant, I64Constant, Instruction, IntegerBinaryOperation,
+		IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend, IntegerNarrow,
+		IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, LocalBranch, LocalSet,
+		MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemorySize, MemoryStore, Name,
+		NumberBinaryOperation, NumberCompareOperation, NumberNarrow, NumberTransmuteToInteger,
+		NumberTruncateToInteger, NumberUnaryOperation, NumberWiden, RefFunction, RefIsNull,
+		RefNull, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize, Throw,
+		V128BinaryOperation, V128BitSelect, V128Constant, V128ExtractLane, V128ReduceOperation,
+		V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
 	},
 };
 use set::{Set, Slice};
@@ -215,11 +217,24 @@ impl LocalTracker {
 		self.write_local(destination);
 	}
 
+	fn handle_function_check(&mut self, function_check: FunctionCheck) {
+		let FunctionCheck {
+			destination,
+			source,
+			r#type: _,
+		} = function_check;
+
+		self.write_local(destination);
+		self.read_local(source);
+	}
+
 	fn handle_call(&mut self, call: Call) {
 		let Call {
 			destinations,
 			sources,
 			function,
+			tail: _,
+			catch: _,
 		} = call;
 
 		for destination in destinations.0..destinations.1 {
@@ -434,6 +449,115 @@ impl LocalTracker {
 		self.read_local(source);
 	}
 
+	fn handle_v128_constant(&mut self, v128_constant: V128Constant) {
+		let V128Constant {
+			destination,
+			data: _,
+		} = v128_constant;
+
+		self.write_local(destination);
+	}
+
+	fn handle_v128_unary_operation(&mut self, v128_unary_operation: V128UnaryOperation) {
+		let V128UnaryOperation {
+			destination,
+			source,
+			operator: _,
+		} = v128_unary_operation;
+
+		self.write_local(destination);
+		self.read_local(source);
+	}
+
+	fn handle_v128_binary_operation(&mut self, v128_binary_operation: V128BinaryOperation) {
+		let V128BinaryOperation {
+			destination,
+			lhs,
+			rhs,
+			operator: _,
+		} = v128_binary_operation;
+
+		self.write_local(destination);
+		self.read_local(lhs);
+		self.read_local(rhs);
+	}
+
+	fn handle_v128_reduce_operation(&mut self, v128_reduce_operation: V128ReduceOperation) {
+		let V128ReduceOperation {
+			destination,
+			source,
+			operator: _,
+		} = v128_reduce_operation;
+
+		self.write_local(destination);
+		self.read_local(source);
+	}
+
+	fn handle_v128_splat(&mut self, v128_splat: V128Splat) {
+		let V128Splat {
+			destination,
+			source,
+			r#type: _,
+		} = v128_splat;
+
+		self.write_local(destination);
+		self.read_local(source);
+	}
+
+	fn handle_v128_extract_lane(&mut self, v128_extract_lane: V128ExtractLane) {
+		let V128ExtractLane {
+			destination,
+			source,
+			r#type: _,
+			lane: _,
+			signed: _,
+		} = v128_extract_lane;
+
+		self.write_local(destination);
+		self.read_local(source);
+	}
+
+	fn handle_v128_replace_lane(&mut self, v128_replace_lane: V128ReplaceLane) {
+		let V128ReplaceLane {
+			destination,
+			lhs,
+			rhs,
+			r#type: _,
+			lane: _,
+		} = v128_replace_lane;
+
+		self.write_local(destination);
+		self.read_local(lhs);
+		self.read_local(rhs);
+	}
+
+	fn handle_v128_shuffle(&mut self, v128_shuffle: V128Shuffle) {
+		let V128Shuffle {
+			destination,
+			lhs,
+			rhs,
+			lanes: _,
+		} = v128_shuffle;
+
+		self.write_local(destination);
+		self.read_local(lhs);
+		self.read_local(rhs);
+	}
+
+	fn handle_v128_bit_select(&mut self, v128_bit_select: V128BitSelect) {
+		let V128BitSelect {
+			destination,
+			lhs,
+			rhs,
+			condition,
+		} = v128_bit_select;
+
+		self.write_local(destination);
+		self.read_local(lhs);
+		self.read_local(rhs);
+		self.read_local(condition);
+	}
+
 	fn handle_global_get(&mut self, global_get: GlobalGet) {
 		let GlobalGet {
 			destination,
@@ -608,9 +732,54 @@ impl LocalTracker {
 		self.read_local(size);
 	}
 
+	fn handle_throw(&mut self, throw: Throw) {
+		let Throw { source } = throw;
+
+		self.read_local(source);
+	}
+
+	fn handle_exception_new(&mut self, exception_new: ExceptionNew) {
+		let ExceptionNew {
+			destination,
+			sources,
+			tag: _,
+		} = exception_new;
+
+		self.write_local(destination);
+
+		for source in sources.0..sources.1 {
+			self.read_local(source);
+		}
+	}
+
+	fn handle_exception_test(&mut self, exception_test: ExceptionTest) {
+		let ExceptionTest {
+			destination,
+			source,
+			tag: _,
+		} = exception_test;
+
+		self.write_local(destination);
+		self.read_local(source);
+	}
+
+	fn handle_exception_get(&mut self, exception_get: ExceptionGet) {
+		let ExceptionGet {
+			destination,
+			source,
+			index: _,
+		} = exception_get;
+
+		self.write_local(destination);
+		self.read_local(source);
+	}
+
 	fn handle_instruction(&mut self, instruction: Instruction) {
 		match instruction {
-			Instruction::Unreachable | Instruction::ElementsDrop(_) | Instruction::DataDrop(_) => {}
+			Instruction::Position(_)
+			| Instruction::Unreachable
+			| Instruction::ElementsDrop(_)
+			| Instruction::DataDrop(_) => {}
 			Instruction::LocalSet(local_set) => self.handle_local_set(local_set),
 			Instruction::LocalBranch(local_branch) => self.handle_local_branch(local_branch),
 			Instruction::I32Constant(i32_constant) => self.handle_i32_constant(i32_constant),
@@ -620,6 +789,9 @@ impl LocalTracker {
 			Instruction::RefIsNull(ref_is_null) => self.handle_ref_is_null(ref_is_null),
 			Instruction::RefNull(ref_null) => self.handle_ref_null(ref_null),
 			Instruction::RefFunction(ref_function) => self.handle_ref_function(ref_function),
+			Instruction::FunctionCheck(function_check) => {
+				self.handle_function_check(function_check);
+			}
 			Instruction::Call(call) => self.handle_call(call),
 			Instruction::IntegerUnaryOperation(integer_unary_operation) => {
 				self.handle_integer_unary_operation(integer_unary_operation);
@@ -660,6 +832,27 @@ impl LocalTracker {
 			Instruction::NumberTransmuteToInteger(number_transmute_to_integer) => {
 				self.handle_number_transmute_to_integer(number_transmute_to_integer);
 			}
+			Instruction::V128Constant(v128_constant) => self.handle_v128_constant(v128_constant),
+			Instruction::V128UnaryOperation(v128_unary_operation) => {
+				self.handle_v128_unary_operation(v128_unary_operation);
+			}
+			Instruction::V128BinaryOperation(v128_binary_operation) => {
+				self.handle_v128_binary_operation(v128_binary_operation);
+			}
+			Instruction::V128ReduceOperation(v128_reduce_operation) => {
+				self.handle_v128_reduce_operation(v128_reduce_operation);
+			}
+			Instruction::V128Splat(v128_splat) => self.handle_v128_splat(v128_splat),
+			Instruction::V128ExtractLane(v128_extract_lane) => {
+				self.handle_v128_extract_lane(v128_extract_lane);
+			}
+			Instruction::V128ReplaceLane(v128_replace_lane) => {
+				self.handle_v128_replace_lane(v128_replace_lane);
+			}
+			Instruction::V128Shuffle(v128_shuffle) => self.handle_v128_shuffle(v128_shuffle),
+			Instruction::V128BitSelect(v128_bit_select) => {
+				self.handle_v128_bit_select(v128_bit_select);
+			}
 			Instruction::GlobalGet(global_get) => self.handle_global_get(global_get),
 			Instruction::GlobalSet(global_set) => self.handle_global_set(global_set),
 			Instruction::TableGet(table_get) => self.handle_table_get(table_get),
@@ -676,6 +869,12 @@ impl LocalTracker {
 			Instruction::MemoryFill(memory_fill) => self.handle_memory_fill(memory_fill),
 			Instruction::MemoryCopy(memory_copy) => self.handle_memory_copy(memory_copy),
 			Instruction::MemoryInit(memory_init) => self.handle_memory_init(memory_init),
+			Instruction::Throw(throw) => self.handle_throw(throw),
+			Instruction::ExceptionNew(exception_new) => self.handle_exception_new(exception_new),
+			Instruction::ExceptionTest(exception_test) => {
+				self.handle_exception_test(exception_test);
+			}
+			Instruction::ExceptionGet(exception_get) => self.handle_exception_get(exception_get),
 		}
 	}
 
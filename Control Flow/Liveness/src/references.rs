@@ -1,8 +1,8 @@
 use alloc::vec::Vec;
 use control_flow_graph::instruction::{
-	DataDrop, ElementsDrop, GlobalGet, GlobalSet, Instruction, MemoryCopy, MemoryFill, MemoryGrow,
-	MemoryInit, MemoryLoad, MemorySize, MemoryStore, RefFunction, TableCopy, TableFill, TableGet,
-	TableGrow, TableInit, TableSet, TableSize,
+	DataDrop, ElementsDrop, ExceptionNew, ExceptionTest, GlobalGet, GlobalSet, Instruction,
+	MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemorySize, MemoryStore,
+	RefFunction, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize,
 };
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
@@ -14,6 +14,7 @@ pub enum ReferenceType {
 	Elements,
 	Memory,
 	Data,
+	Tag,
 }
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -84,6 +85,13 @@ fn write_data(references: &mut Vec<Reference>, data: u16) {
 	read_data(references, data);
 }
 
+fn read_tag(references: &mut Vec<Reference>, tag: u16) {
+	references.push(Reference {
+		r#type: ReferenceType::Tag,
+		id: tag,
+	});
+}
+
 fn handle_ref_function(references: &mut Vec<Reference>, ref_function: RefFunction) {
 	let RefFunction {
 		destination: _,
@@ -264,9 +272,30 @@ fn handle_data_drop(references: &mut Vec<Reference>, data_drop: DataDrop) {
 	write_data(references, source);
 }
 
+fn handle_exception_new(references: &mut Vec<Reference>, exception_new: ExceptionNew) {
+	let ExceptionNew {
+		destination: _,
+		sources: _,
+		tag,
+	} = exception_new;
+
+	read_tag(references, tag);
+}
+
+fn handle_exception_test(references: &mut Vec<Reference>, exception_test: ExceptionTest) {
+	let ExceptionTest {
+		destination: _,
+		source: _,
+		tag,
+	} = exception_test;
+
+	read_tag(references, tag);
+}
+
 fn handle_instruction(references: &mut Vec<Reference>, instruction: Instruction) {
 	match instruction {
-		Instruction::LocalSet(_)
+		Instruction::Position(_)
+		| Instruction::LocalSet(_)
 		| Instruction::LocalBranch(_)
 		| Instruction::I32Constant(_)
 		| Instruction::I64Constant(_)
@@ -274,8 +303,10 @@ fn handle_instruction(references: &mut Vec<Reference>, instruction: Instruction)
 		| Instruction::F64Constant(_)
 		| Instruction::RefIsNull(_)
 		| Instruction::RefNull(_)
+		| Instruction::FunctionCheck(_)
 		| Instruction::Call(_)
 		| Instruction::Unreachable
+		| Instruction::Throw(_)
 		| Instruction::IntegerUnaryOperation(_)
 		| Instruction::IntegerBinaryOperation(_)
 		| Instruction::IntegerCompareOperation(_)
@@ -290,7 +321,17 @@ fn handle_instruction(references: &mut Vec<Reference>, instruction: Instruction)
 		| Instruction::NumberNarrow(_)
 		| Instruction::NumberWiden(_)
 		| Instruction::NumberTruncateToInteger(_)
-		| Instruction::NumberTransmuteToInteger(_) => {}
+		| Instruction::NumberTransmuteToInteger(_)
+		| Instruction::V128Constant(_)
+		| Instruction::V128UnaryOperation(_)
+		| Instruction::V128BinaryOperation(_)
+		| Instruction::V128ReduceOperation(_)
+		| Instruction::V128Splat(_)
+		| Instruction::V128ExtractLane(_)
+		| Instruction::V128ReplaceLane(_)
+		| Instruction::V128Shuffle(_)
+		| Instruction::V128BitSelect(_)
+		| Instruction::ExceptionGet(_) => {}
 
 		Instruction::RefFunction(ref_function) => handle_ref_function(references, ref_function),
 		Instruction::GlobalGet(global_get) => handle_global_get(references, global_get),
@@ -311,6 +352,10 @@ fn handle_instruction(references: &mut Vec<Reference>, instruction: Instruction)
 		Instruction::MemoryCopy(memory_copy) => handle_memory_copy(references, memory_copy),
 		Instruction::MemoryInit(memory_init) => handle_memory_init(references, memory_init),
 		Instruction::DataDrop(data_drop) => handle_data_drop(references, data_drop),
+		Instruction::ExceptionNew(exception_new) => handle_exception_new(references, exception_new),
+		Instruction::ExceptionTest(exception_test) => {
+			handle_exception_test(references, exception_test);
+		}
 	}
 }
 
@@ -1,13 +1,16 @@
 use alloc::vec::Vec;
+use control_flow_builder::Types;
 use control_flow_graph::instruction::{
-	Call, DataDrop, ElementsDrop, F32Constant, F64Constant, GlobalGet, GlobalSet, I32Constant,
-	I64Constant, Instruction, IntegerBinaryOperation, IntegerCompareOperation,
-	IntegerConvertToNumber, IntegerExtend, IntegerNarrow, IntegerTransmuteToNumber,
-	IntegerUnaryOperation, IntegerWiden, LocalBranch, LocalSet, MemoryCopy, MemoryFill, MemoryGrow,
-	MemoryInit, MemoryLoad, MemorySize, MemoryStore, Name, NumberBinaryOperation,
-	NumberCompareOperation, NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger,
-	NumberUnaryOperation, NumberWiden, RefFunction, RefIsNull, RefNull, TableCopy, TableFill,
-	TableGet, TableGrow, TableInit, TableSet, TableSize,
+	Call, DataDrop, ElementsDrop, ExceptionGet, ExceptionNew, ExceptionTest, F32Constant,
+	F64Constant, FunctionCheck, GlobalGet, GlobalSet, I32Constant, I64Constant, Instruction,
+	IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
+	IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, LocalBranch,
+	LocalSet, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemorySize, MemoryStore,
+	Name, NumberBinaryOperation, NumberCompareOperation, NumberNarrow, NumberTransmuteToInteger,
+	NumberTruncateToInteger, NumberUnaryOperation, NumberWiden, Position, RefFunction, RefIsNull,
+	RefNull, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet, TableSize, Throw,
+	V128BinaryOperation, V128BitSelect, V128Constant, V128ExtractLane, V128ReduceOperation,
+	V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
 };
 use control_flow_liveness::references::{Reference, ReferenceType};
 use data_flow_graph::{DataFlowGraph, Link, mvp::Location, nested::ValueType};
@@ -75,6 +78,7 @@ impl BasicBlockConverter {
 			ValueType::I64 => graph.add_i64(0),
 			ValueType::F32 => graph.add_f32(0.0),
 			ValueType::F64 => graph.add_f64(0.0),
+			ValueType::V128 => graph.add_v128(0),
 			ValueType::Reference => graph.add_null(),
 		}));
 	}
@@ -119,6 +123,12 @@ impl BasicBlockConverter {
 		self.trap = producer.next().unwrap();
 	}
 
+	fn handle_position(graph: &mut DataFlowGraph, position: Position) {
+		let Position { offset } = position;
+
+		graph.set_position(Some(offset));
+	}
+
 	fn handle_local_set(&mut self, local_set: LocalSet) {
 		let LocalSet {
 			destination,
@@ -185,10 +195,40 @@ impl BasicBlockConverter {
 		self.locals[usize::from(destination)] = graph.add_global_get(state);
 	}
 
+	fn handle_function_check(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		function_check: FunctionCheck,
+		types: &Types,
+	) {
+		let FunctionCheck {
+			destination,
+			source,
+			r#type,
+		} = function_check;
+
+		let signature = types.get_signature(r#type).clone();
+
+		self.locals[usize::from(destination)] =
+			graph.add_function_check(self.locals[usize::from(source)], signature);
+	}
+
 	fn handle_unreachable(&mut self, graph: &mut DataFlowGraph) {
 		self.trap = graph.add_trap();
 	}
 
+	fn handle_throw(&mut self, graph: &mut DataFlowGraph, throw: Throw) {
+		let Throw { source } = throw;
+
+		let mut states = alloc::vec![self.trap];
+
+		self.dependencies.extend_into(&mut states);
+
+		let throw = graph.add_throw(self.locals[usize::from(source)], states);
+
+		self.handle_post_call(throw, 0..0);
+	}
+
 	fn handle_pre_call(&self, sources: core::ops::Range<usize>) -> (Vec<Link>, usize) {
 		let mut arguments = self.locals[sources.clone()].to_vec();
 
@@ -218,6 +258,8 @@ impl BasicBlockConverter {
 			destinations,
 			sources,
 			function,
+			tail,
+			catch,
 		} = call;
 
 		let destinations = usize::from(destinations.0)..usize::from(destinations.1);
@@ -232,6 +274,8 @@ impl BasicBlockConverter {
 				arguments,
 				results.try_into().unwrap(),
 				states.try_into().unwrap(),
+				tail,
+				catch,
 			),
 			destinations,
 		);
@@ -474,6 +518,144 @@ impl BasicBlockConverter {
 			graph.add_number_transmute_to_integer(self.locals[usize::from(source)], from);
 	}
 
+	fn handle_v128_constant(&mut self, graph: &mut DataFlowGraph, v128_constant: V128Constant) {
+		let V128Constant { destination, data } = v128_constant;
+
+		self.locals[usize::from(destination)] = graph.add_v128(data);
+	}
+
+	fn handle_v128_unary_operation(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		v128_unary_operation: V128UnaryOperation,
+	) {
+		let V128UnaryOperation {
+			destination,
+			source,
+			operator,
+		} = v128_unary_operation;
+
+		self.locals[usize::from(destination)] =
+			graph.add_v128_unary_operation(self.locals[usize::from(source)], operator);
+	}
+
+	fn handle_v128_binary_operation(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		v128_binary_operation: V128BinaryOperation,
+	) {
+		let V128BinaryOperation {
+			destination,
+			lhs,
+			rhs,
+			operator,
+		} = v128_binary_operation;
+
+		self.locals[usize::from(destination)] = graph.add_v128_binary_operation(
+			self.locals[usize::from(lhs)],
+			self.locals[usize::from(rhs)],
+			operator,
+		);
+	}
+
+	fn handle_v128_reduce_operation(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		v128_reduce_operation: V128ReduceOperation,
+	) {
+		let V128ReduceOperation {
+			destination,
+			source,
+			operator,
+		} = v128_reduce_operation;
+
+		self.locals[usize::from(destination)] =
+			graph.add_v128_reduce_operation(self.locals[usize::from(source)], operator);
+	}
+
+	fn handle_v128_splat(&mut self, graph: &mut DataFlowGraph, v128_splat: V128Splat) {
+		let V128Splat {
+			destination,
+			source,
+			r#type,
+		} = v128_splat;
+
+		self.locals[usize::from(destination)] =
+			graph.add_v128_splat(self.locals[usize::from(source)], r#type);
+	}
+
+	fn handle_v128_extract_lane(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		v128_extract_lane: V128ExtractLane,
+	) {
+		let V128ExtractLane {
+			destination,
+			source,
+			r#type,
+			lane,
+			signed,
+		} = v128_extract_lane;
+
+		self.locals[usize::from(destination)] =
+			graph.add_v128_extract_lane(self.locals[usize::from(source)], r#type, lane, signed);
+	}
+
+	fn handle_v128_replace_lane(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		v128_replace_lane: V128ReplaceLane,
+	) {
+		let V128ReplaceLane {
+			destination,
+			lhs,
+			rhs,
+			r#type,
+			lane,
+		} = v128_replace_lane;
+
+		self.locals[usize::from(destination)] = graph.add_v128_replace_lane(
+			self.locals[usize::from(lhs)],
+			self.locals[usize::from(rhs)],
+			r#type,
+			lane,
+		);
+	}
+
+	fn handle_v128_shuffle(&mut self, graph: &mut DataFlowGraph, v128_shuffle: V128Shuffle) {
+		let V128Shuffle {
+			destination,
+			lhs,
+			rhs,
+			lanes,
+		} = v128_shuffle;
+
+		self.locals[usize::from(destination)] = graph.add_v128_shuffle(
+			self.locals[usize::from(lhs)],
+			self.locals[usize::from(rhs)],
+			lanes,
+		);
+	}
+
+	fn handle_v128_bit_select(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		v128_bit_select: V128BitSelect,
+	) {
+		let V128BitSelect {
+			destination,
+			lhs,
+			rhs,
+			condition,
+		} = v128_bit_select;
+
+		self.locals[usize::from(destination)] = graph.add_v128_bit_select(
+			self.locals[usize::from(lhs)],
+			self.locals[usize::from(rhs)],
+			self.locals[usize::from(condition)],
+		);
+	}
+
 	fn handle_global_get(&mut self, graph: &mut DataFlowGraph, global_get: GlobalGet) {
 		let GlobalGet {
 			destination,
@@ -753,8 +935,51 @@ impl BasicBlockConverter {
 		self.dependencies.set(ReferenceType::Data, source, state);
 	}
 
-	fn handle_instruction(&mut self, graph: &mut DataFlowGraph, instruction: Instruction) {
+	fn handle_exception_new(&mut self, graph: &mut DataFlowGraph, exception_new: ExceptionNew) {
+		let ExceptionNew {
+			destination,
+			sources,
+			tag,
+		} = exception_new;
+
+		let tag = self.dependencies.get(ReferenceType::Tag, tag);
+		let arguments = self.locals[usize::from(sources.0)..usize::from(sources.1)].to_vec();
+
+		self.locals[usize::from(destination)] = graph.add_exception_new(tag, arguments);
+	}
+
+	fn handle_exception_test(&mut self, graph: &mut DataFlowGraph, exception_test: ExceptionTest) {
+		let ExceptionTest {
+			destination,
+			source,
+			tag,
+		} = exception_test;
+
+		let tag = self.dependencies.get(ReferenceType::Tag, tag);
+
+		self.locals[usize::from(destination)] =
+			graph.add_exception_test(self.locals[usize::from(source)], tag);
+	}
+
+	fn handle_exception_get(&mut self, graph: &mut DataFlowGraph, exception_get: ExceptionGet) {
+		let ExceptionGet {
+			destination,
+			source,
+			index,
+		} = exception_get;
+
+		self.locals[usize::from(destination)] =
+			graph.add_exception_get(self.locals[usize::from(source)], index);
+	}
+
+	fn handle_instruction(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		instruction: Instruction,
+		types: &Types,
+	) {
 		match instruction {
+			Instruction::Position(position) => Self::handle_position(graph, position),
 			Instruction::LocalSet(local_set) => self.handle_local_set(local_set),
 			Instruction::LocalBranch(local_branch) => self.handle_local_branch(local_branch),
 			Instruction::I32Constant(i32_constant) => self.handle_i32_constant(graph, i32_constant),
@@ -764,8 +989,12 @@ impl BasicBlockConverter {
 			Instruction::RefIsNull(ref_is_null) => self.handle_ref_is_null(graph, ref_is_null),
 			Instruction::RefNull(ref_null) => self.handle_ref_null(graph, ref_null),
 			Instruction::RefFunction(ref_function) => self.handle_ref_function(graph, ref_function),
+			Instruction::FunctionCheck(function_check) => {
+				self.handle_function_check(graph, function_check, types);
+			}
 			Instruction::Call(call) => self.handle_call(graph, call),
 			Instruction::Unreachable => self.handle_unreachable(graph),
+			Instruction::Throw(throw) => self.handle_throw(graph, throw),
 			Instruction::IntegerUnaryOperation(integer_unary_operation) => {
 				self.handle_integer_unary_operation(graph, integer_unary_operation);
 			}
@@ -809,6 +1038,29 @@ impl BasicBlockConverter {
 			Instruction::NumberTransmuteToInteger(number_transmute_to_integer) => {
 				self.handle_number_transmute_to_integer(graph, number_transmute_to_integer);
 			}
+			Instruction::V128Constant(v128_constant) => {
+				self.handle_v128_constant(graph, v128_constant);
+			}
+			Instruction::V128UnaryOperation(v128_unary_operation) => {
+				self.handle_v128_unary_operation(graph, v128_unary_operation);
+			}
+			Instruction::V128BinaryOperation(v128_binary_operation) => {
+				self.handle_v128_binary_operation(graph, v128_binary_operation);
+			}
+			Instruction::V128ReduceOperation(v128_reduce_operation) => {
+				self.handle_v128_reduce_operation(graph, v128_reduce_operation);
+			}
+			Instruction::V128Splat(v128_splat) => self.handle_v128_splat(graph, v128_splat),
+			Instruction::V128ExtractLane(v128_extract_lane) => {
+				self.handle_v128_extract_lane(graph, v128_extract_lane);
+			}
+			Instruction::V128ReplaceLane(v128_replace_lane) => {
+				self.handle_v128_replace_lane(graph, v128_replace_lane);
+			}
+			Instruction::V128Shuffle(v128_shuffle) => self.handle_v128_shuffle(graph, v128_shuffle),
+			Instruction::V128BitSelect(v128_bit_select) => {
+				self.handle_v128_bit_select(graph, v128_bit_select);
+			}
 			Instruction::GlobalGet(global_get) => self.handle_global_get(graph, global_get),
 			Instruction::GlobalSet(global_set) => self.handle_global_set(graph, global_set),
 			Instruction::TableGet(table_get) => self.handle_table_get(graph, table_get),
@@ -829,12 +1081,21 @@ impl BasicBlockConverter {
 			Instruction::MemoryCopy(memory_copy) => self.handle_memory_copy(graph, memory_copy),
 			Instruction::MemoryInit(memory_init) => self.handle_memory_init(graph, memory_init),
 			Instruction::DataDrop(data_drop) => self.handle_data_drop(graph, data_drop),
+			Instruction::ExceptionNew(exception_new) => {
+				self.handle_exception_new(graph, exception_new);
+			}
+			Instruction::ExceptionTest(exception_test) => {
+				self.handle_exception_test(graph, exception_test);
+			}
+			Instruction::ExceptionGet(exception_get) => {
+				self.handle_exception_get(graph, exception_get);
+			}
 		}
 	}
 
-	pub fn run(&mut self, graph: &mut DataFlowGraph, instructions: &[Instruction]) {
+	pub fn run(&mut self, graph: &mut DataFlowGraph, instructions: &[Instruction], types: &Types) {
 		for &instruction in instructions {
-			self.handle_instruction(graph, instruction);
+			self.handle_instruction(graph, instruction, types);
 		}
 	}
 }
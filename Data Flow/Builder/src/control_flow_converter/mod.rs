@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use control_flow_builder::Types;
 use control_flow_graph::ControlFlowGraph;
 use control_flow_liveness::{locals::Locals, references::Reference};
 use data_flow_graph::{
@@ -97,6 +98,7 @@ impl ControlFlowConverter {
 		control_flow_graph: &ControlFlowGraph,
 		id: u16,
 		locals: &Locals,
+		types: &Types,
 	) {
 		// We just started down the paths in a branch.
 		if let Some(start) = control_flow_graph.find_branch_start(id) {
@@ -115,7 +117,7 @@ impl ControlFlowConverter {
 		}
 
 		self.basic_block_converter
-			.run(data_flow_graph, control_flow_graph.instructions(id));
+			.run(data_flow_graph, control_flow_graph.instructions(id), types);
 
 		// We just started a branch region.
 		if control_flow_graph.is_branch_start(id) {
@@ -168,13 +170,14 @@ impl ControlFlowConverter {
 		control_flow_graph: &ControlFlowGraph,
 		lambda_in: u32,
 		locals: &Locals,
+		types: &Types,
 	) -> Vec<Link> {
 		let LambdaIn { r#type, .. } = data_flow_graph.get(lambda_in).as_lambda_in().unwrap();
 
 		let results = r#type.results.len();
 
 		for id in control_flow_graph.block_ids() {
-			self.handle_basic_block(data_flow_graph, control_flow_graph, id, locals);
+			self.handle_basic_block(data_flow_graph, control_flow_graph, id, locals, types);
 		}
 
 		self.basic_block_converter.get_function_outputs(results)
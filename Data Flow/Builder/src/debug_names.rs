@@ -0,0 +1,109 @@
+use alloc::{collections::btree_map::BTreeMap, sync::Arc};
+use control_flow_liveness::references::{Reference, ReferenceType};
+use wasmparser::{IndirectNameMap, Name, NameMap, NameSectionReader, Naming};
+
+pub struct DebugNames {
+	functions: BTreeMap<u32, Arc<str>>,
+	locals: BTreeMap<(u32, u32), Arc<str>>,
+	tables: BTreeMap<u32, Arc<str>>,
+	memories: BTreeMap<u32, Arc<str>>,
+	globals: BTreeMap<u32, Arc<str>>,
+	tags: BTreeMap<u32, Arc<str>>,
+
+	elements: BTreeMap<u32, Arc<str>>,
+	datas: BTreeMap<u32, Arc<str>>,
+}
+
+impl DebugNames {
+	pub const fn new() -> Self {
+		Self {
+			functions: BTreeMap::new(),
+			locals: BTreeMap::new(),
+			tables: BTreeMap::new(),
+			memories: BTreeMap::new(),
+			globals: BTreeMap::new(),
+			tags: BTreeMap::new(),
+
+			elements: BTreeMap::new(),
+			datas: BTreeMap::new(),
+		}
+	}
+
+	pub fn clear(&mut self) {
+		self.functions.clear();
+		self.locals.clear();
+		self.tables.clear();
+		self.memories.clear();
+		self.globals.clear();
+		self.tags.clear();
+
+		self.elements.clear();
+		self.datas.clear();
+	}
+
+	fn load_name_map(names: &mut BTreeMap<u32, Arc<str>>, map: NameMap) {
+		let iter = map
+			.into_iter()
+			.map_while(Result::ok)
+			.map(|Naming { index, name }| (index, name.into()));
+
+		names.extend(iter);
+	}
+
+	fn load_indirect_name_map(names: &mut BTreeMap<(u32, u32), Arc<str>>, map: IndirectNameMap) {
+		for indirect in map.into_iter().map_while(Result::ok) {
+			let iter = indirect
+				.names
+				.into_iter()
+				.map_while(Result::ok)
+				.map(|Naming { index, name }| ((indirect.index, index), name.into()));
+
+			names.extend(iter);
+		}
+	}
+
+	// Malformed custom sections must not fail the module, so we
+	// stop reading at the first error instead.
+	pub fn load(&mut self, reader: NameSectionReader) {
+		for name in reader.into_iter().map_while(Result::ok) {
+			match name {
+				Name::Function(map) => Self::load_name_map(&mut self.functions, map),
+				Name::Local(map) => Self::load_indirect_name_map(&mut self.locals, map),
+				Name::Table(map) => Self::load_name_map(&mut self.tables, map),
+				Name::Memory(map) => Self::load_name_map(&mut self.memories, map),
+				Name::Global(map) => Self::load_name_map(&mut self.globals, map),
+				Name::Tag(map) => Self::load_name_map(&mut self.tags, map),
+				Name::Element(map) => Self::load_name_map(&mut self.elements, map),
+				Name::Data(map) => Self::load_name_map(&mut self.datas, map),
+
+				_ => {}
+			}
+		}
+	}
+
+	pub fn get_function(&self, function: u32) -> Option<Arc<str>> {
+		self.functions.get(&function).cloned()
+	}
+
+	pub fn get_local(&self, function: u32, local: u32) -> Option<Arc<str>> {
+		self.locals.get(&(function, local)).cloned()
+	}
+
+	pub fn get_global(&self, global: u32) -> Option<Arc<str>> {
+		self.globals.get(&global).cloned()
+	}
+
+	pub fn get_reference(&self, reference: Reference) -> Option<Arc<str>> {
+		let names = match reference.r#type {
+			ReferenceType::Function => &self.functions,
+			ReferenceType::Global => &self.globals,
+			ReferenceType::Table => &self.tables,
+			ReferenceType::Elements => &self.elements,
+			ReferenceType::Memory => &self.memories,
+			ReferenceType::Data => &self.datas,
+			ReferenceType::Tag => &self.tags,
+		};
+
+		names.get(&u32::from(reference.id)).cloned()
+	}
+}
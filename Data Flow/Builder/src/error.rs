@@ -0,0 +1,70 @@
+use core::fmt::{Display, Formatter, Result};
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use wasmparser::BinaryReaderError;
+
+/// An error that stops a module from being compiled.
+#[derive(Debug)]
+pub enum CompileError {
+	/// The module is malformed or fails validation.
+	Invalid { message: String, offset: usize },
+
+	/// The module relies on a proposal that is not implemented.
+	Unsupported {
+		proposal: &'static str,
+		offset: usize,
+	},
+
+	/// The module exceeds a limit of the implementation.
+	Limit { limit: &'static str, offset: usize },
+
+	/// An import names an instance being linked but does not match any of
+	/// its exports.
+	Unlinkable { message: String, offset: usize },
+
+	/// Exports were asked to be kept by names that none of them have.
+	UnknownExports { names: Vec<String> },
+
+	/// A module being linked failed to compile.
+	Instance {
+		name: String,
+		error: Box<CompileError>,
+	},
+}
+
+impl Display for CompileError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		match self {
+			Self::Invalid { message, offset } => {
+				write!(f, "invalid module at offset {offset:#x}: {message}")
+			}
+			Self::Unsupported { proposal, offset } => {
+				write!(f, "unsupported `{proposal}` proposal at offset {offset:#x}")
+			}
+			Self::Limit { limit, offset } => {
+				write!(
+					f,
+					"{limit} exceeds the implementation limit at offset {offset:#x}"
+				)
+			}
+			Self::Unlinkable { message, offset } => {
+				write!(f, "unlinkable module at offset {offset:#x}: {message}")
+			}
+			Self::UnknownExports { names } => {
+				write!(f, "no exports are named `{}`", names.join("`, `"))
+			}
+			Self::Instance { name, error } => write!(f, "in instance `{name}`, {error}"),
+		}
+	}
+}
+
+impl core::error::Error for CompileError {}
+
+impl From<BinaryReaderError> for CompileError {
+	fn from(error: BinaryReaderError) -> Self {
+		Self::Invalid {
+			message: error.message().into(),
+			offset: error.offset(),
+		}
+	}
+}
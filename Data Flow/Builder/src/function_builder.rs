@@ -1,4 +1,4 @@
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 use control_flow_builder::{ControlFlowBuilder, Types};
 use control_flow_graph::ControlFlowGraph;
 use control_flow_liveness::{
@@ -12,7 +12,10 @@ use data_flow_graph::{
 use list::resizable::Resizable;
 use wasmparser::{BlockType, FunctionBody, LocalsReader, OperatorsReader, ValType};
 
-use crate::{control_flow_converter::ControlFlowConverter, global_state::GlobalState};
+use crate::{
+	control_flow_converter::ControlFlowConverter, debug_names::DebugNames,
+	global_state::GlobalState,
+};
 
 fn web_type_to_data_type(r#type: ValType) -> ValueType {
 	match r#type {
@@ -20,9 +23,8 @@ fn web_type_to_data_type(r#type: ValType) -> ValueType {
 		ValType::I64 => ValueType::I64,
 		ValType::F32 => ValueType::F32,
 		ValType::F64 => ValueType::F64,
+		ValType::V128 => ValueType::V128,
 		ValType::Ref(_) => ValueType::Reference,
-
-		ValType::V128 => unimplemented!("`V128` types"),
 	}
 }
 
@@ -66,6 +68,20 @@ fn read_local_types_into(local_types: &mut Vec<ValueType>, reader: LocalsReader)
 	}
 }
 
+fn read_argument_names_into(
+	argument_names: &mut Vec<Option<Arc<str>>>,
+	names: &DebugNames,
+	function: u32,
+	arguments: usize,
+) {
+	argument_names.clear();
+	argument_names.extend(
+		(0..)
+			.take(arguments)
+			.map(|local| names.get_local(function, local)),
+	);
+}
+
 pub struct FunctionBuilder {
 	converter: ControlFlowConverter,
 	builder: ControlFlowBuilder,
@@ -74,6 +90,7 @@ pub struct FunctionBuilder {
 	graph: ControlFlowGraph,
 
 	local_types: Vec<ValueType>,
+	argument_names: Vec<Option<Arc<str>>>,
 	locals: Locals,
 	dependencies: Vec<Reference>,
 }
@@ -90,13 +107,20 @@ impl FunctionBuilder {
 			dependencies: Vec::new(),
 			locals: Locals::new(),
 			local_types: Vec::new(),
+			argument_names: Vec::new(),
 		}
 	}
 
+	pub const fn take_control_flow_graph(&mut self) -> ControlFlowGraph {
+		core::mem::replace(&mut self.graph, ControlFlowGraph::new())
+	}
+
 	pub fn build_data_flow(
 		&mut self,
 		graph: &mut DataFlowGraph,
 		r#type: FunctionType,
+		types: &Types,
+		names: &DebugNames,
 		global_state: &GlobalState,
 	) -> u32 {
 		references::track(&mut self.dependencies, &self.graph.instructions);
@@ -108,7 +132,14 @@ impl FunctionBuilder {
 			r#type.results.len().try_into().unwrap(),
 		);
 
-		let lambda_in = graph.add_lambda_in(r#type.into(), dependencies);
+		let names = self
+			.dependencies
+			.iter()
+			.map(|&dependency| names.get_reference(dependency))
+			.chain(self.argument_names.iter().cloned())
+			.collect();
+
+		let lambda_in = graph.add_lambda_in(r#type.into(), dependencies, names);
 
 		self.converter.set_function_data(
 			graph,
@@ -120,7 +151,9 @@ impl FunctionBuilder {
 
 		let results = self
 			.converter
-			.run(graph, &self.graph, lambda_in, &self.locals);
+			.run(graph, &self.graph, lambda_in, &self.locals, types);
+
+		graph.set_position(None);
 
 		graph.add_lambda_out(lambda_in, results)
 	}
@@ -129,11 +162,12 @@ impl FunctionBuilder {
 		&mut self,
 		graph: &mut DataFlowGraph,
 		body: &FunctionBody,
-		function: u32,
+		index: u32,
 		types: &Types,
+		names: &DebugNames,
 		global_state: &GlobalState,
 	) -> u32 {
-		let function = types.get_function_index(function);
+		let function = types.get_function_index(index);
 
 		read_local_types_into(&mut self.local_types, body.get_locals_reader().unwrap());
 
@@ -147,7 +181,14 @@ impl FunctionBuilder {
 
 		let function_type = load_type_from_function(function, types);
 
-		self.build_data_flow(graph, function_type, global_state)
+		read_argument_names_into(
+			&mut self.argument_names,
+			names,
+			index,
+			function_type.arguments.len(),
+		);
+
+		self.build_data_flow(graph, function_type, types, names, global_state)
 	}
 
 	pub fn build_expression(
@@ -156,6 +197,7 @@ impl FunctionBuilder {
 		operators: OperatorsReader,
 		result: ValType,
 		types: &Types,
+		names: &DebugNames,
 		global_state: &GlobalState,
 	) -> Link {
 		self.builder.run(
@@ -167,10 +209,11 @@ impl FunctionBuilder {
 		);
 
 		self.local_types.clear();
+		self.argument_names.clear();
 
 		let function_type = load_type_from_result(result);
-		let function = self.build_data_flow(graph, function_type, global_state);
-		let call = graph.add_call(Link(function, 0), Vec::new(), 1, 0);
+		let function = self.build_data_flow(graph, function_type, types, names, global_state);
+		let call = graph.add_call(Link(function, 0), Vec::new(), 1, 0, false, false);
 
 		Link(call, 0)
 	}
@@ -8,6 +8,7 @@ pub struct GlobalState {
 	pub tables: Vec<Link>,
 	pub memories: Vec<Link>,
 	pub globals: Vec<Link>,
+	pub tags: Vec<Link>,
 
 	pub elements: Vec<Link>,
 	pub datas: Vec<Link>,
@@ -21,6 +22,7 @@ impl GlobalState {
 			tables: Vec::new(),
 			memories: Vec::new(),
 			globals: Vec::new(),
+			tags: Vec::new(),
 
 			elements: Vec::new(),
 			datas: Vec::new(),
@@ -32,6 +34,7 @@ impl GlobalState {
 		self.tables.clear();
 		self.memories.clear();
 		self.globals.clear();
+		self.tags.clear();
 
 		self.elements.clear();
 		self.datas.clear();
@@ -54,6 +57,7 @@ impl GlobalState {
 			ReferenceType::Elements => &self.elements,
 			ReferenceType::Memory => &self.memories,
 			ReferenceType::Data => &self.datas,
+			ReferenceType::Tag => &self.tags,
 		};
 
 		list[usize::from(reference.id)]
@@ -72,7 +76,7 @@ impl GlobalState {
 			ExternalKind::Table => &self.tables,
 			ExternalKind::Memory => &self.memories,
 			ExternalKind::Global => &self.globals,
-			ExternalKind::Tag => unimplemented!("`Tag`"),
+			ExternalKind::Tag => &self.tags,
 		}
 	}
 
@@ -82,7 +86,7 @@ impl GlobalState {
 			TypeRef::Table(_) => &mut self.tables,
 			TypeRef::Memory(_) => &mut self.memories,
 			TypeRef::Global(_) => &mut self.globals,
-			TypeRef::Tag(_) => unimplemented!("`Tag`"),
+			TypeRef::Tag(_) => &mut self.tags,
 		}
 	}
 }
@@ -2,26 +2,49 @@
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
 use control_flow_builder::Types;
+use control_flow_graph::ControlFlowGraph;
 use data_flow_graph::{
 	DataFlowGraph, Link,
-	mvp::Location,
+	mvp::{IntegerType, Location, NumberType},
 	nested::{Export, OmegaIn},
 };
-use wasmparser::{ConstExpr, ElementItems, FunctionBody, RecGroup, SectionLimited, ValType};
+use wasmparser::{
+	ConstExpr, ElementItems, FunctionBody, RecGroup, SectionLimited, ValType, Validator,
+};
+
+use self::{
+	debug_names::DebugNames, function_builder::FunctionBuilder, global_state::GlobalState,
+	linker::Linker, sections::Sections,
+};
 
-use self::{function_builder::FunctionBuilder, global_state::GlobalState, sections::Sections};
+pub use self::error::CompileError;
 
 mod control_flow_converter;
+mod debug_names;
+mod error;
 mod function_builder;
 mod global_state;
+mod linker;
 mod sections;
+mod support;
+
+/// A module to be linked with others, named by its instance.
+pub struct Instance<'data> {
+	pub name: &'data str,
+	pub data: &'data [u8],
+}
 
 pub struct DataFlowBuilder {
 	function_builder: FunctionBuilder,
 	global_state: GlobalState,
 	types: Types,
+	names: DebugNames,
+	linker: Linker,
+
+	control_flow_graphs: Vec<ControlFlowGraph>,
+	keep_control_flow: bool,
 }
 
 impl DataFlowBuilder {
@@ -31,47 +54,99 @@ impl DataFlowBuilder {
 			function_builder: FunctionBuilder::new(),
 			global_state: GlobalState::new(),
 			types: Types::new(),
+			names: DebugNames::new(),
+			linker: Linker::new(),
+
+			control_flow_graphs: Vec::new(),
+			keep_control_flow: false,
 		}
 	}
 
+	/// Sets whether the control flow graph of each function body is kept
+	/// after building, in order of definition.
+	pub const fn set_keep_control_flow(&mut self, keep: bool) {
+		self.keep_control_flow = keep;
+	}
+
+	pub fn take_control_flow_graphs(&mut self) -> Vec<ControlFlowGraph> {
+		core::mem::take(&mut self.control_flow_graphs)
+	}
+
 	fn handle_type_section(&mut self, section: SectionLimited<RecGroup>) {
 		self.types.add_sub_types(section);
 	}
 
+	fn load_import(
+		&self,
+		graph: &mut DataFlowGraph,
+		environment: Link,
+		import: wasmparser::Import,
+	) -> Link {
+		let wasmparser::Import { module, name, ty } = import;
+		let mut link = graph.add_import(environment, module.into(), name.into());
+
+		if let wasmparser::TypeRef::Func(function) = ty {
+			let signature = self.types.get_signature(function).clone();
+			let index = self.global_state.functions.len().try_into().unwrap();
+			let name = self.names.get_function(index);
+
+			link = graph.add_function_new(link, signature);
+			link = graph.add_global_new(link, name);
+		}
+
+		link
+	}
+
 	fn handle_import_section(
 		&mut self,
 		graph: &mut DataFlowGraph,
 		omega_in: u32,
 		section: SectionLimited<wasmparser::Import>,
-	) {
+	) -> Result<(), CompileError> {
 		let environment = Link(omega_in, OmegaIn::ENVIRONMENT_PORT);
 
-		for wasmparser::Import { module, name, ty } in section.into_iter().map(Result::unwrap) {
-			let mut link = graph.add_import(environment, module.into(), name.into());
-
-			if let wasmparser::TypeRef::Func(function) = ty {
-				self.types.add_function(function);
-
-				link = graph.add_global_new(link);
+		for (offset, import) in section.into_iter_with_offsets().map(Result::unwrap) {
+			let signature = match import.ty {
+				wasmparser::TypeRef::Func(function) => Some(self.types.get_signature(function)),
+				_ => None,
+			};
+
+			let link = match self.linker.resolve(&import, signature) {
+				Ok(Some(link)) => link,
+				Ok(None) => self.load_import(graph, environment, import),
+				Err(message) => return Err(CompileError::Unlinkable { message, offset }),
+			};
+
+			match import.ty {
+				wasmparser::TypeRef::Func(function) => self.types.add_function(function),
+				wasmparser::TypeRef::Table(table) => self.linker.add_table(table),
+				wasmparser::TypeRef::Memory(memory) => {
+					self.types.add_memory(memory);
+					self.linker.add_memory(memory);
+				}
+				wasmparser::TypeRef::Global(global) => self.linker.add_global(global),
+				wasmparser::TypeRef::Tag(tag) => self.types.add_tag(tag),
 			}
 
-			self.global_state.get_mut_type_ref(ty).push(link);
+			self.global_state.get_mut_type_ref(import.ty).push(link);
 		}
+
+		Ok(())
 	}
 
 	fn handle_function_section(&mut self, graph: &mut DataFlowGraph, section: SectionLimited<u32>) {
-		let len = section.count().try_into().unwrap();
+		let start: u32 = self.global_state.functions.len().try_into().unwrap();
+		let end = start + section.count();
 
 		self.types.add_functions(section);
 
-		self.global_state.functions.extend(
-			core::iter::repeat_with(|| {
+		self.global_state
+			.functions
+			.extend((start..end).map(|function| {
 				let null = graph.add_null();
 
-				graph.add_global_new(null)
-			})
-			.take(len),
-		);
+				graph.add_global_new(null, self.names.get_function(function))
+			}));
 	}
 
 	fn build_expression(
@@ -82,8 +157,14 @@ impl DataFlowBuilder {
 	) -> Link {
 		let code = code.get_operators_reader();
 
-		self.function_builder
-			.build_expression(graph, code, result, &self.types, &self.global_state)
+		self.function_builder.build_expression(
+			graph,
+			code,
+			result,
+			&self.types,
+			&self.names,
+			&self.global_state,
+		)
 	}
 
 	fn load_table_node(graph: &mut DataFlowGraph, table_type: wasmparser::TableType) -> Link {
@@ -101,12 +182,12 @@ impl DataFlowBuilder {
 		graph: &mut DataFlowGraph,
 		section: SectionLimited<wasmparser::Table>,
 	) {
-		self.global_state.tables.extend(
-			section
-				.into_iter()
-				.map(Result::unwrap)
-				.map(|wasmparser::Table { ty, .. }| Self::load_table_node(graph, ty)),
-		);
+		for wasmparser::Table { ty, .. } in section.into_iter().map(Result::unwrap) {
+			self.linker.add_table(ty);
+			self.global_state
+				.tables
+				.push(Self::load_table_node(graph, ty));
+		}
 	}
 
 	fn load_table_fill(
@@ -259,7 +340,7 @@ impl DataFlowBuilder {
 			core::iter::repeat_with(|| {
 				let null = graph.add_null();
 
-				graph.add_global_new(null)
+				graph.add_global_new(null, None)
 			})
 			.take(len),
 		);
@@ -293,12 +374,14 @@ impl DataFlowBuilder {
 		graph: &mut DataFlowGraph,
 		section: SectionLimited<wasmparser::MemoryType>,
 	) {
-		self.global_state.memories.extend(
-			section
-				.into_iter()
-				.map(Result::unwrap)
-				.map(|memory_type| Self::load_memory_node(graph, memory_type)),
-		);
+		self.types.add_memories(section.clone());
+
+		for memory_type in section.into_iter().map(Result::unwrap) {
+			self.linker.add_memory(memory_type);
+			self.global_state
+				.memories
+				.push(Self::load_memory_node(graph, memory_type));
+		}
 	}
 
 	fn load_memory_init(
@@ -308,12 +391,18 @@ impl DataFlowBuilder {
 		offset: &ConstExpr,
 		data: Link,
 		size: i32,
+		memory_64: bool,
 	) -> Link {
-		let destination = Location {
-			reference,
-			offset: self.build_expression(graph, offset, ValType::I32),
+		let offset = if memory_64 {
+			let offset = self.build_expression(graph, offset, ValType::I64);
+
+			graph.add_integer_convert_to_number(offset, false, NumberType::F64, IntegerType::I64)
+		} else {
+			self.build_expression(graph, offset, ValType::I32)
 		};
 
+		let destination = Location { reference, offset };
+
 		let source = Location {
 			reference: data,
 			offset: graph.add_i32(0),
@@ -339,9 +428,10 @@ impl DataFlowBuilder {
 			} => {
 				let index = usize::try_from(*memory_index).unwrap();
 				let reference = self.global_state.memories[index];
+				let memory_64 = self.types.get_memory_64_flags()[index];
 
 				self.global_state.memories[index] =
-					self.load_memory_init(graph, reference, offset_expr, data, size);
+					self.load_memory_init(graph, reference, offset_expr, data, size, memory_64);
 
 				graph.add_data_drop(data)
 			}
@@ -380,16 +470,18 @@ impl DataFlowBuilder {
 		graph: &mut DataFlowGraph,
 		section: &SectionLimited<wasmparser::Global>,
 	) {
-		let len = section.count().try_into().unwrap();
+		let start: u32 = self.global_state.globals.len().try_into().unwrap();
+		let end = start + section.count();
 
-		self.global_state.globals.extend(
-			core::iter::repeat_with(|| {
-				let null = graph.add_null();
+		self.global_state.globals.extend((start..end).map(|global| {
+			let null = graph.add_null();
 
-				graph.add_global_new(null)
-			})
-			.take(len),
-		);
+			graph.add_global_new(null, self.names.get_global(global))
+		}));
+
+		for global in section.clone().into_iter().map(Result::unwrap) {
+			self.linker.add_global(global.ty);
+		}
 	}
 
 	fn do_global_set(
@@ -418,9 +510,16 @@ impl DataFlowBuilder {
 
 	fn handle_tag_section(
 		&mut self,
-		_graph: &mut DataFlowGraph,
-		_section: SectionLimited<wasmparser::TagType>,
+		graph: &mut DataFlowGraph,
+		section: SectionLimited<wasmparser::TagType>,
 	) {
+		let len = section.count().try_into().unwrap();
+
+		self.types.add_tags(section);
+
+		self.global_state
+			.tags
+			.extend(core::iter::repeat_with(|| graph.add_tag_new()).take(len));
 	}
 
 	fn build_function(
@@ -429,13 +528,22 @@ impl DataFlowBuilder {
 		body: &FunctionBody,
 		index: usize,
 	) -> u32 {
-		self.function_builder.build_function(
+		let lambda_out = self.function_builder.build_function(
 			graph,
 			body,
 			index.try_into().unwrap(),
 			&self.types,
+			&self.names,
 			&self.global_state,
-		)
+		);
+
+		if self.keep_control_flow {
+			let control_flow_graph = self.function_builder.take_control_flow_graph();
+
+			self.control_flow_graphs.push(control_flow_graph);
+		}
+
+		lambda_out
 	}
 
 	fn handle_code_section(
@@ -446,90 +554,127 @@ impl DataFlowBuilder {
 	) {
 		for body in section {
 			let lambda_out = self.build_function(graph, body, imports);
+			let function = self.types.get_function_index(imports.try_into().unwrap());
+			let signature = self.types.get_signature(function).clone();
+			let source = graph.add_function_new(Link(lambda_out, 0), signature);
 			let functions = &mut self.global_state.functions;
 
-			functions[imports] = graph.add_global_set(functions[imports], Link(lambda_out, 0));
+			functions[imports] = graph.add_global_set(functions[imports], source);
 
 			imports += 1;
 		}
 	}
 
+	fn link_export(&mut self, instance: &str, export: wasmparser::Export, link: Link) {
+		let signature = (export.kind == wasmparser::ExternalKind::Func).then(|| {
+			let function = self.types.get_function_index(export.index);
+
+			self.types.get_signature(function).clone()
+		});
+
+		self.linker.add_export(
+			instance,
+			export.name.into(),
+			export.kind,
+			export.index,
+			link,
+			signature,
+		);
+	}
+
 	fn load_export_information(
-		&self,
+		&mut self,
 		graph: &mut DataFlowGraph,
 		export: wasmparser::Export,
+		instance: Option<&Arc<str>>,
 	) -> Export {
 		let index = usize::try_from(export.index).unwrap();
 		let mut reference = self.global_state.get_external_kind(export.kind)[index];
 
+		if let Some(instance) = instance {
+			self.link_export(instance, export, reference);
+		}
+
 		if export.kind == wasmparser::ExternalKind::Func {
 			reference = graph.add_global_get(reference);
 		}
 
 		Export {
+			instance: instance.cloned(),
 			identifier: export.name.into(),
 			reference,
 		}
 	}
 
 	fn handle_export_section(
-		&self,
+		&mut self,
 		graph: &mut DataFlowGraph,
 		section: SectionLimited<wasmparser::Export>,
+		instance: Option<&Arc<str>>,
 	) -> Vec<Export> {
 		section
 			.into_iter()
 			.map(Result::unwrap)
-			.map(|export| self.load_export_information(graph, export))
+			.map(|export| self.load_export_information(graph, export, instance))
 			.collect()
 	}
 
 	fn handle_start_section(
 		&self,
 		graph: &mut DataFlowGraph,
-		omega_in: u32,
+		state: Link,
 		start: Option<u32>,
 	) -> Link {
-		let state = Link(omega_in, OmegaIn::STATE_PORT);
-
 		start.map_or(state, |start| {
 			let function = self.global_state.functions[usize::try_from(start).unwrap()];
 			let function = graph.add_global_get(function);
-			let call = graph.add_call(function, alloc::vec![state], 0, 1);
+			let call = graph.add_call(function, alloc::vec![state], 0, 1, false, false);
 
 			Link(call, 0)
 		})
 	}
 
-	fn handle_module(
-		&self,
-		graph: &mut DataFlowGraph,
-		omega_in: u32,
-		start: Link,
-		exports: Vec<Export>,
-	) -> u32 {
+	fn handle_module(&self, graph: &mut DataFlowGraph, start: Link) -> Link {
 		let mut states = Vec::new();
 
 		self.global_state.retrieve_all_mutable(&mut states);
 		states.push(start);
 
-		let start = graph.add_merge(states);
-
-		graph.add_omega_out(omega_in, start, exports)
+		graph.add_merge(states)
 	}
 
-	pub fn run(&mut self, graph: &mut DataFlowGraph, data: &[u8]) -> u32 {
-		let sections = Sections::load(data);
+	fn load_sections(data: &[u8]) -> Result<Sections<'_>, CompileError> {
+		// Validating first means the readers used past this point cannot fail.
+		Validator::new().validate_all(data)?;
 
-		graph.inner_mut().clear();
+		let sections = Sections::load(data)?;
+
+		support::check(&sections)?;
+
+		Ok(sections)
+	}
+
+	// Builds one module on top of the `state` left by the ones before it,
+	// returning the state it leaves behind along with its exports.
+	fn build_module(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		omega_in: u32,
+		state: Link,
+		sections: Sections,
+		instance: Option<&Arc<str>>,
+	) -> Result<(Link, Vec<Export>), CompileError> {
 		self.global_state.clear();
 		self.types.clear();
+		self.names.clear();
+		self.linker.clear_types();
 
-		self.handle_type_section(sections.types);
-
-		let omega_in = graph.add_omega_in();
+		if let Some(names) = sections.names {
+			self.names.load(names);
+		}
 
-		self.handle_import_section(graph, omega_in, sections.imports);
+		self.handle_type_section(sections.types);
+		self.handle_import_section(graph, omega_in, sections.imports)?;
 
 		let function_imports = self.global_state.functions.len();
 
@@ -548,10 +693,104 @@ impl DataFlowBuilder {
 		self.handle_data_initialization(graph, sections.datas);
 		self.handle_global_initialization(graph, sections.globals);
 
-		let start = self.handle_start_section(graph, omega_in, sections.start);
-		let exports = self.handle_export_section(graph, sections.exports);
+		let start = self.handle_start_section(graph, state, sections.start);
+		let exports = self.handle_export_section(graph, sections.exports, instance);
+
+		Ok((self.handle_module(graph, start), exports))
+	}
+
+	/// Builds the module in `data` into the `graph`, returning the id of its
+	/// `OmegaOut` node.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the module is invalid, relies on an unsupported
+	/// proposal, or exceeds an implementation limit. Nothing is built then.
+	pub fn run(&mut self, graph: &mut DataFlowGraph, data: &[u8]) -> Result<u32, CompileError> {
+		let sections = Self::load_sections(data)?;
+
+		graph.clear();
+		self.control_flow_graphs.clear();
+		self.linker.clear();
+
+		let omega_in = graph.add_omega_in();
+		let state = Link(omega_in, OmegaIn::STATE_PORT);
+		let (state, exports) = self.build_module(graph, omega_in, state, sections, None)?;
+
+		Ok(graph.add_omega_out(omega_in, state, exports))
+	}
+
+	/// Builds the `instances` into the `graph` in order, sharing a single
+	/// `OmegaIn` and `OmegaOut` node, and returns the id of the latter.
+	///
+	/// Imports naming an earlier instance are linked directly to its
+	/// exports, while any others are still read from the environment.
+	/// Exports are grouped by the instance they came from.
+	///
+	/// # Errors
+	///
+	/// Returns an error if any module cannot be built on its own, if an
+	/// import does not match the export it names, or if two instances share
+	/// a name. Nothing is built then.
+	pub fn run_linked(
+		&mut self,
+		graph: &mut DataFlowGraph,
+		instances: &[Instance],
+	) -> Result<u32, CompileError> {
+		let in_instance = |name: &str, error| CompileError::Instance {
+			name: name.into(),
+			error: Box::new(error),
+		};
+
+		let mut list = Vec::with_capacity(instances.len());
+
+		for (index, instance) in instances.iter().enumerate() {
+			if instances[..index]
+				.iter()
+				.any(|other| other.name == instance.name)
+			{
+				let message = String::from("instance name is already in use");
+
+				return Err(in_instance(
+					instance.name,
+					CompileError::Unlinkable { message, offset: 0 },
+				));
+			}
+
+			let sections = Self::load_sections(instance.data)
+				.map_err(|error| in_instance(instance.name, error))?;
+
+			list.push(sections);
+		}
+
+		graph.clear();
+		self.control_flow_graphs.clear();
+		self.linker.clear();
+
+		let omega_in = graph.add_omega_in();
+		let mut state = Link(omega_in, OmegaIn::STATE_PORT);
+		let mut exports = Vec::new();
+
+		for (instance, sections) in instances.iter().zip(list) {
+			let name: Arc<str> = instance.name.into();
+
+			self.linker.add_instance(name.clone());
+
+			match self.build_module(graph, omega_in, state, sections, Some(&name)) {
+				Ok((next, list)) => {
+					state = next;
+					exports.extend(list);
+				}
+				Err(error) => {
+					graph.clear();
+					self.control_flow_graphs.clear();
+
+					return Err(in_instance(instance.name, error));
+				}
+			}
+		}
 
-		self.handle_module(graph, omega_in, start, exports)
+		Ok(graph.add_omega_out(omega_in, state, exports))
 	}
 }
 
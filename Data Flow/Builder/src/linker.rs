@@ -0,0 +1,180 @@
+use alloc::{collections::BTreeMap, format, string::String, sync::Arc, vec::Vec};
+use data_flow_graph::Link;
+use wasmparser::{ExternalKind, GlobalType, Import, MemoryType, TableType, TypeRef};
+
+// The type that an item was declared with, where functions are known by
+// their signature so that those of different modules can be compared.
+enum ItemType {
+	Function(Arc<str>),
+	Table(TableType),
+	Memory(MemoryType),
+	Global(GlobalType),
+	Tag,
+}
+
+impl ItemType {
+	// Items can grow past their minimum but not past their maximum, so an
+	// import accepts any limits that fit within its own.
+	fn is_within_limits(export: (u64, Option<u64>), import: (u64, Option<u64>)) -> bool {
+		export.0 >= import.0
+			&& import
+				.1
+				.is_none_or(|import| export.1.is_some_and(|export| export <= import))
+	}
+
+	fn is_subtype_of(&self, import: &Self) -> bool {
+		match (self, import) {
+			(Self::Function(export), Self::Function(import)) => export == import,
+			(Self::Table(export), Self::Table(import)) => {
+				export.element_type == import.element_type
+					&& export.table64 == import.table64
+					&& export.shared == import.shared
+					&& Self::is_within_limits(
+						(export.initial, export.maximum),
+						(import.initial, import.maximum),
+					)
+			}
+			(Self::Memory(export), Self::Memory(import)) => {
+				export.memory64 == import.memory64
+					&& export.shared == import.shared
+					&& export.page_size_log2 == import.page_size_log2
+					&& Self::is_within_limits(
+						(export.initial, export.maximum),
+						(import.initial, import.maximum),
+					)
+			}
+			(Self::Global(export), Self::Global(import)) => export == import,
+			(Self::Tag, Self::Tag) => true,
+			_ => false,
+		}
+	}
+}
+
+struct Item {
+	link: Link,
+	r#type: ItemType,
+}
+
+// Exports of the instances built so far, which later imports are
+// resolved against instead of going through the environment. The types
+// of the tables, memories, and globals of the module being built are
+// kept to check its own exports against later imports.
+pub struct Linker {
+	instances: BTreeMap<Arc<str>, BTreeMap<Arc<str>, Item>>,
+
+	tables: Vec<TableType>,
+	memories: Vec<MemoryType>,
+	globals: Vec<GlobalType>,
+}
+
+impl Linker {
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			instances: BTreeMap::new(),
+
+			tables: Vec::new(),
+			memories: Vec::new(),
+			globals: Vec::new(),
+		}
+	}
+
+	pub fn clear(&mut self) {
+		self.instances.clear();
+		self.clear_types();
+	}
+
+	pub fn clear_types(&mut self) {
+		self.tables.clear();
+		self.memories.clear();
+		self.globals.clear();
+	}
+
+	pub fn add_table(&mut self, table: TableType) {
+		self.tables.push(table);
+	}
+
+	pub fn add_memory(&mut self, memory: MemoryType) {
+		self.memories.push(memory);
+	}
+
+	pub fn add_global(&mut self, global: GlobalType) {
+		self.globals.push(global);
+	}
+
+	pub fn add_instance(&mut self, name: Arc<str>) {
+		self.instances.insert(name, BTreeMap::new());
+	}
+
+	pub fn add_export(
+		&mut self,
+		instance: &str,
+		identifier: Arc<str>,
+		kind: ExternalKind,
+		index: u32,
+		link: Link,
+		signature: Option<Arc<str>>,
+	) {
+		let index = usize::try_from(index).unwrap();
+		let r#type = match kind {
+			ExternalKind::Func => {
+				ItemType::Function(signature.expect("functions should have a signature"))
+			}
+			ExternalKind::Table => ItemType::Table(self.tables[index]),
+			ExternalKind::Memory => ItemType::Memory(self.memories[index]),
+			ExternalKind::Global => ItemType::Global(self.globals[index]),
+			ExternalKind::Tag => ItemType::Tag,
+		};
+
+		let item = Item { link, r#type };
+
+		self.instances
+			.get_mut(instance)
+			.expect("instance should be added")
+			.insert(identifier, item);
+	}
+
+	fn load_import_type(type_ref: TypeRef, signature: Option<&Arc<str>>) -> ItemType {
+		match type_ref {
+			TypeRef::Func(_) => ItemType::Function(
+				signature
+					.expect("functions should have a signature")
+					.clone(),
+			),
+			TypeRef::Table(table) => ItemType::Table(table),
+			TypeRef::Memory(memory) => ItemType::Memory(memory),
+			TypeRef::Global(global) => ItemType::Global(global),
+			TypeRef::Tag(_) => ItemType::Tag,
+		}
+	}
+
+	/// Returns the link that satisfies `import`, or `None` if it should be
+	/// read from the environment. Exports are checked against the type that
+	/// their item was declared with, so tables and memories that grew since
+	/// are still judged by their declared limits. Tags are only checked by
+	/// their kind.
+	pub fn resolve(
+		&self,
+		import: &Import,
+		signature: Option<&Arc<str>>,
+	) -> Result<Option<Link>, String> {
+		let Import { module, name, ty } = *import;
+
+		let Some(exports) = self.instances.get(module) else {
+			return Ok(None);
+		};
+
+		let Some(item) = exports.get(name) else {
+			return Err(format!("unknown import `{module}.{name}`"));
+		};
+
+		if !item
+			.r#type
+			.is_subtype_of(&Self::load_import_type(ty, signature))
+		{
+			return Err(format!("incompatible import type for `{module}.{name}`"));
+		}
+
+		Ok(Some(item.link))
+	}
+}
@@ -1,9 +1,11 @@
 use alloc::vec::Vec;
 use wasmparser::{
-	BinaryReader, Data, Element, Export, FunctionBody, Global, Import, MemoryType, Parser, Payload,
-	RecGroup, Result, SectionLimited, Table, TagType,
+	BinaryReader, Data, Element, Export, FunctionBody, Global, Import, KnownCustom, MemoryType,
+	NameSectionReader, Parser, Payload, RecGroup, SectionLimited, Table, TagType,
 };
 
+use crate::error::CompileError;
+
 pub struct Sections<'data> {
 	pub types: SectionLimited<'data, RecGroup>,
 	pub imports: SectionLimited<'data, Import<'data>>,
@@ -20,6 +22,7 @@ pub struct Sections<'data> {
 
 	pub start: Option<u32>,
 	pub exports: SectionLimited<'data, Export<'data>>,
+	pub names: Option<NameSectionReader<'data>>,
 }
 
 impl<'data> Sections<'data> {
@@ -29,7 +32,7 @@ impl<'data> Sections<'data> {
 		SectionLimited::new(reader).unwrap()
 	}
 
-	pub fn load(data: &'data [u8]) -> Self {
+	pub fn load(data: &'data [u8]) -> Result<Self, CompileError> {
 		let mut types = Self::reader_with_empty();
 		let mut imports = Self::reader_with_empty();
 		let mut functions = Self::reader_with_empty();
@@ -42,13 +45,11 @@ impl<'data> Sections<'data> {
 		let mut elements = Self::reader_with_empty();
 		let mut datas = Self::reader_with_empty();
 		let mut code = Vec::new();
+		let mut names = None;
 
-		for payload in Parser::new(0).parse_all(data).map(Result::unwrap) {
-			match payload {
-				Payload::Version { .. }
-				| Payload::CustomSection(_)
-				| Payload::End(_)
-				| Payload::DataCountSection { .. } => {}
+		for payload in Parser::new(0).parse_all(data) {
+			match payload? {
+				Payload::Version { .. } | Payload::End(_) | Payload::DataCountSection { .. } => {}
 
 				Payload::TypeSection(section) => types = section,
 				Payload::ImportSection(section) => imports = section,
@@ -69,11 +70,25 @@ impl<'data> Sections<'data> {
 				}
 				Payload::CodeSectionEntry(function_body) => code.push(function_body),
 
-				payload => unimplemented!("{payload:?}"),
+				Payload::CustomSection(section) => {
+					if let KnownCustom::Name(reader) = section.as_known() {
+						names = Some(reader);
+					}
+				}
+
+				// Anything else belongs to components rather than core modules.
+				payload => {
+					let offset = payload.as_section().map_or(0, |(_, range)| range.start);
+
+					return Err(CompileError::Unsupported {
+						proposal: "component-model",
+						offset,
+					});
+				}
 			}
 		}
 
-		Self {
+		Ok(Self {
 			types,
 			imports,
 			tables,
@@ -86,6 +101,7 @@ impl<'data> Sections<'data> {
 			code,
 			start,
 			exports,
-		}
+			names,
+		})
 	}
 }
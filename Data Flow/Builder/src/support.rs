@@ -0,0 +1,233 @@
+use alloc::vec::Vec;
+use wasmparser::{
+	CompositeInnerType, FunctionBody, MemoryType, Operator, RecGroup, SectionLimited, Table,
+	TypeRef,
+};
+
+use crate::{error::CompileError, sections::Sections};
+
+// Functions and globals are numbered with `u16` values in the control flow
+// graph, as are the registers that hold the locals and the operand stack
+// of each function.
+const MAX_ITEMS: usize = 1 << 16;
+
+// Half of the registers of a function are left for its operand stack.
+const MAX_LOCALS: usize = 1 << 15;
+
+macro_rules! define_find_group {
+	($(@$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident ($($ann:tt)*))*) => {
+		// Names the proposal that an operator was introduced by, as it is
+		// grouped by the parser.
+		fn find_group(operator: &Operator) -> &'static str {
+			match operator {
+				$(Operator::$op { .. } => stringify!($proposal),)*
+				_ => "unknown",
+			}
+		}
+	};
+}
+
+wasmparser::for_each_operator!(define_find_group);
+
+// Only operators of proposals that are lowered are accepted, as the
+// validator may enable others by default.
+fn find_proposal(operator: &Operator) -> Option<&'static str> {
+	// Typed selects of several results are parsed but have no lowering.
+	if let Operator::TypedSelectMulti { .. } = operator {
+		return Some("multi-value");
+	}
+
+	let proposal = match find_group(operator) {
+		"mvp"
+		| "sign_extension"
+		| "saturating_float_to_int"
+		| "bulk_memory"
+		| "reference_types"
+		| "tail_call"
+		| "threads"
+		| "simd"
+		| "exceptions" => return None,
+
+		"legacy_exceptions" => "legacy-exceptions",
+		"function_references" => "function-references",
+		"relaxed_simd" => "relaxed-simd",
+		"wide_arithmetic" => "wide-arithmetic",
+		"memory_control" => "memory-control",
+		"shared_everything_threads" => "shared-everything-threads",
+		"stack_switching" => "stack-switching",
+
+		group => group,
+	};
+
+	Some(proposal)
+}
+
+fn check_types(section: SectionLimited<RecGroup>) -> Result<(), CompileError> {
+	for group in section.into_iter_with_offsets() {
+		let (offset, group) = group?;
+
+		let proposal = group
+			.types()
+			.find_map(|sub_type| match sub_type.composite_type.inner {
+				CompositeInnerType::Func(_) => None,
+				CompositeInnerType::Cont(_) => Some("stack-switching"),
+				_ => Some("gc"),
+			});
+
+		if let Some(proposal) = proposal {
+			return Err(CompileError::Unsupported { proposal, offset });
+		}
+	}
+
+	Ok(())
+}
+
+// Table sizes are handled as `i32` values when the tables are filled,
+// and their limits as `u32` values by the runtime.
+fn check_tables(section: SectionLimited<Table>) -> Result<(), CompileError> {
+	for table in section.into_iter_with_offsets() {
+		let (offset, Table { ty, .. }) = table?;
+
+		if i32::try_from(ty.initial).is_err()
+			|| ty
+				.maximum
+				.is_some_and(|maximum| u32::try_from(maximum).is_err())
+		{
+			return Err(CompileError::Limit {
+				limit: "table size",
+				offset,
+			});
+		}
+	}
+
+	Ok(())
+}
+
+// 64-bit memories may declare limits in pages well past anything that
+// the `u32` limits of the runtime can hold.
+fn check_memories(section: SectionLimited<MemoryType>) -> Result<(), CompileError> {
+	for memory in section.into_iter_with_offsets() {
+		let (offset, memory) = memory?;
+
+		if u32::try_from(memory.initial).is_err()
+			|| memory
+				.maximum
+				.is_some_and(|maximum| u32::try_from(maximum).is_err())
+		{
+			return Err(CompileError::Limit {
+				limit: "memory size",
+				offset,
+			});
+		}
+	}
+
+	Ok(())
+}
+
+fn check_items(sections: &Sections) -> Result<(), CompileError> {
+	let (mut functions, mut globals) = (0, 0);
+
+	for import in sections.imports.clone() {
+		match import?.ty {
+			TypeRef::Func(_) => functions += 1,
+			TypeRef::Global(_) => globals += 1,
+			_ => {}
+		}
+	}
+
+	let functions = functions + usize::try_from(sections.functions.count()).unwrap();
+	let globals = globals + usize::try_from(sections.globals.count()).unwrap();
+
+	if functions > MAX_ITEMS {
+		return Err(CompileError::Limit {
+			limit: "function count",
+			offset: sections.functions.range().start,
+		});
+	}
+
+	if globals > MAX_ITEMS {
+		return Err(CompileError::Limit {
+			limit: "global count",
+			offset: sections.globals.range().start,
+		});
+	}
+
+	Ok(())
+}
+
+// Returns the number of parameters of each type, so that they can be
+// counted along with the locals of the functions using it.
+fn load_parameter_counts(section: SectionLimited<RecGroup>) -> Result<Vec<usize>, CompileError> {
+	let mut parameters = Vec::new();
+
+	for group in section.into_iter_with_offsets() {
+		let (offset, group) = group?;
+
+		for sub_type in group.types() {
+			let CompositeInnerType::Func(function) = &sub_type.composite_type.inner else {
+				continue;
+			};
+
+			if function.params().len() > MAX_LOCALS || function.results().len() > MAX_LOCALS {
+				return Err(CompileError::Limit {
+					limit: "function type size",
+					offset,
+				});
+			}
+
+			parameters.push(function.params().len());
+		}
+	}
+
+	Ok(parameters)
+}
+
+fn check_locals(
+	body: &FunctionBody,
+	r#type: u32,
+	parameters: &[usize],
+) -> Result<(), CompileError> {
+	let mut locals = parameters[usize::try_from(r#type).unwrap()];
+
+	for local in body.get_locals_reader()? {
+		locals += usize::try_from(local?.0).unwrap();
+	}
+
+	if locals > MAX_LOCALS {
+		return Err(CompileError::Limit {
+			limit: "local count",
+			offset: body.range().start,
+		});
+	}
+
+	Ok(())
+}
+
+fn check_function_body(body: &FunctionBody) -> Result<(), CompileError> {
+	for operator in body.get_operators_reader()?.into_iter_with_offsets() {
+		let (operator, offset) = operator?;
+
+		if let Some(proposal) = find_proposal(&operator) {
+			return Err(CompileError::Unsupported { proposal, offset });
+		}
+	}
+
+	Ok(())
+}
+
+/// Checks that all features used by an already validated module are supported
+/// and that it fits within the limits of the implementation.
+pub fn check(sections: &Sections) -> Result<(), CompileError> {
+	check_types(sections.types.clone())?;
+	check_tables(sections.tables.clone())?;
+	check_memories(sections.memories.clone())?;
+	check_items(sections)?;
+
+	let parameters = load_parameter_counts(sections.types.clone())?;
+
+	for (body, r#type) in sections.code.iter().zip(sections.functions.clone()) {
+		check_locals(body, r#type?, &parameters)?;
+	}
+
+	sections.code.iter().try_for_each(check_function_body)
+}
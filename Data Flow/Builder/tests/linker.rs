@@ -0,0 +1,131 @@
+use data_flow_builder::{CompileError, DataFlowBuilder, Instance};
+use data_flow_graph::DataFlowGraph;
+use wast::{
+	Wat,
+	parser::{self, ParseBuffer},
+};
+
+fn encode(source: &str) -> Vec<u8> {
+	let buffer = ParseBuffer::new(source).expect("`source` should lex");
+	let mut wat: Wat = parser::parse(&buffer).expect("`source` should parse");
+
+	wat.encode().expect("`source` should encode")
+}
+
+// Links an instance `a` exporting `export` to an instance `b` importing it
+// as `import`, returning the message of the error if they do not link.
+fn link(export: &str, import: &str) -> Result<(), String> {
+	let a = encode(&format!("(module {export})"));
+	let b = encode(&format!("(module {import})"));
+	let instances = [
+		Instance {
+			name: "a",
+			data: &a,
+		},
+		Instance {
+			name: "b",
+			data: &b,
+		},
+	];
+
+	match DataFlowBuilder::new().run_linked(&mut DataFlowGraph::new(), &instances) {
+		Ok(_) => Ok(()),
+		Err(CompileError::Instance { error, .. }) => match *error {
+			CompileError::Unlinkable { message, .. } => Err(message),
+			error => panic!("instance should be unlinkable, not `{error}`"),
+		},
+		Err(error) => panic!("instance should be unlinkable, not `{error}`"),
+	}
+}
+
+fn incompatible() -> Result<(), String> {
+	Err("incompatible import type for `a.x`".into())
+}
+
+#[test]
+fn compatible_limits() {
+	assert_eq!(
+		link(
+			r#"(memory (export "x") 2 4)"#,
+			r#"(import "a" "x" (memory 1 5))"#
+		),
+		Ok(())
+	);
+}
+
+#[test]
+fn global_value_type() {
+	assert_eq!(
+		link(
+			r#"(global (export "x") i32 (i32.const 0))"#,
+			r#"(import "a" "x" (global i64))"#
+		),
+		incompatible()
+	);
+}
+
+#[test]
+fn global_mutability() {
+	assert_eq!(
+		link(
+			r#"(global (export "x") i32 (i32.const 0))"#,
+			r#"(import "a" "x" (global (mut i32)))"#
+		),
+		incompatible()
+	);
+}
+
+#[test]
+fn table_minimum() {
+	assert_eq!(
+		link(
+			r#"(table (export "x") 1 funcref)"#,
+			r#"(import "a" "x" (table 2 funcref))"#
+		),
+		incompatible()
+	);
+}
+
+#[test]
+fn table_maximum() {
+	assert_eq!(
+		link(
+			r#"(table (export "x") 1 funcref)"#,
+			r#"(import "a" "x" (table 1 10 funcref))"#
+		),
+		incompatible()
+	);
+}
+
+#[test]
+fn table_element_type() {
+	assert_eq!(
+		link(
+			r#"(table (export "x") 1 externref)"#,
+			r#"(import "a" "x" (table 1 funcref))"#
+		),
+		incompatible()
+	);
+}
+
+#[test]
+fn memory_minimum() {
+	assert_eq!(
+		link(
+			r#"(memory (export "x") 1)"#,
+			r#"(import "a" "x" (memory 2))"#
+		),
+		incompatible()
+	);
+}
+
+#[test]
+fn memory_maximum() {
+	assert_eq!(
+		link(
+			r#"(memory (export "x") 1 8)"#,
+			r#"(import "a" "x" (memory 1 4))"#
+		),
+		incompatible()
+	);
+}
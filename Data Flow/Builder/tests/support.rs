@@ -0,0 +1,47 @@
+use data_flow_builder::{CompileError, DataFlowBuilder};
+use data_flow_graph::DataFlowGraph;
+use wast::{
+	Wat,
+	parser::{self, ParseBuffer},
+};
+
+fn encode(source: &str) -> Vec<u8> {
+	let buffer = ParseBuffer::new(source).expect("`source` should lex");
+	let mut wat: Wat = parser::parse(&buffer).expect("`source` should parse");
+
+	wat.encode().expect("`source` should encode")
+}
+
+// Compiles a module with a single function of `body`, returning the
+// proposal it was rejected for, if any.
+fn find_unsupported(signature: &str, body: &str) -> Option<&'static str> {
+	let data = encode(&format!("(module (func {signature} {body}))"));
+
+	match DataFlowBuilder::new().run(&mut DataFlowGraph::new(), &data) {
+		Ok(_) => None,
+		Err(CompileError::Unsupported { proposal, .. }) => Some(proposal),
+		Err(error) => panic!("module should compile or be unsupported, not `{error}`"),
+	}
+}
+
+#[test]
+fn implemented_proposal() {
+	assert_eq!(
+		find_unsupported(
+			"(param v128) (result v128)",
+			"(i32x4.add (local.get 0) (local.get 0))"
+		),
+		None
+	);
+}
+
+#[test]
+fn unimplemented_proposal() {
+	assert_eq!(
+		find_unsupported(
+			"(param v128) (result v128)",
+			"(i8x16.relaxed_swizzle (local.get 0) (local.get 0))"
+		),
+		Some("relaxed-simd")
+	);
+}
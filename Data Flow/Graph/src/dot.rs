@@ -44,9 +44,12 @@ impl Vertex {
 			| Node::I64(_)
 			| Node::F32(_)
 			| Node::F64(_)
+			| Node::V128(_)
 			| Node::Call(_)
 			| Node::Merge(_)
 			| Node::RefIsNull(_)
+			| Node::FunctionNew(_)
+			| Node::FunctionCheck(_)
 			| Node::IntegerUnaryOperation(_)
 			| Node::IntegerBinaryOperation(_)
 			| Node::IntegerCompareOperation(_)
@@ -62,6 +65,14 @@ impl Vertex {
 			| Node::NumberWiden(_)
 			| Node::NumberTruncateToInteger(_)
 			| Node::NumberTransmuteToInteger(_)
+			| Node::V128UnaryOperation(_)
+			| Node::V128BinaryOperation(_)
+			| Node::V128ReduceOperation(_)
+			| Node::V128Splat(_)
+			| Node::V128ExtractLane(_)
+			| Node::V128ReplaceLane(_)
+			| Node::V128Shuffle(_)
+			| Node::V128BitSelect(_)
 			| Node::GlobalNew(_)
 			| Node::GlobalGet(_)
 			| Node::GlobalSet(_)
@@ -84,7 +95,12 @@ impl Vertex {
 			| Node::MemoryCopy(_)
 			| Node::MemoryInit(_)
 			| Node::DataNew(_)
-			| Node::DataDrop(_) => Self::Operation,
+			| Node::DataDrop(_)
+			| Node::TagNew
+			| Node::Throw(_)
+			| Node::ExceptionNew(_)
+			| Node::ExceptionTest(_)
+			| Node::ExceptionGet(_) => Self::Operation,
 		}
 	}
 
@@ -149,9 +165,19 @@ impl<'inner> Dot<'inner> {
 			Node::Trap => "Trap",
 			Node::Null => "Null",
 			Node::Identity(_) => "Identity",
-			Node::Call(_) => "Call",
+			Node::Call(call) => {
+				if call.tail {
+					"Tail Call"
+				} else if call.catch {
+					"Protected Call"
+				} else {
+					"Call"
+				}
+			}
 			Node::Merge(_) => "Merge",
 			Node::RefIsNull(_) => "Ref Is Null",
+			Node::FunctionNew(_) => "Function New",
+			Node::FunctionCheck(_) => "Function Check",
 			Node::IntegerNarrow(_) => "Integer Narrow",
 			Node::IntegerWiden(_) => "Integer Widen",
 			Node::IntegerConvertToNumber(_) => "Convert To Number",
@@ -160,6 +186,8 @@ impl<'inner> Dot<'inner> {
 			Node::NumberWiden(_) => "Number Widen",
 			Node::NumberTruncateToInteger(_) => "Truncate To Integer",
 			Node::NumberTransmuteToInteger(_) => "Transmute To Integer",
+			Node::V128Shuffle(_) => "V128 Shuffle",
+			Node::V128BitSelect(_) => "V128 Bit Select",
 			Node::GlobalNew(_) => "Global New",
 			Node::GlobalGet(_) => "Global Get",
 			Node::GlobalSet(_) => "Global Set",
@@ -182,6 +210,11 @@ impl<'inner> Dot<'inner> {
 			Node::MemoryCopy(_) => "Memory Copy",
 			Node::MemoryInit(_) => "Memory Init",
 			Node::DataDrop(_) => "Data Drop",
+			Node::TagNew => "Tag New",
+			Node::Throw(_) => "Throw",
+			Node::ExceptionNew(_) => "Exception New",
+			Node::ExceptionTest(_) => "Exception Test",
+			Node::ExceptionGet(_) => "Exception Get",
 
 			_ => return None,
 		};
@@ -376,6 +409,7 @@ impl<'inner> Dot<'inner> {
 			Node::I64(i64) => write!(f, "{i64}_i64"),
 			Node::F32(f32) => write!(f, "{f32:e}_f32"),
 			Node::F64(f64) => write!(f, "{f64:e}_f64"),
+			Node::V128(v128) => write!(f, "{v128:#034x}_v128"),
 			Node::IntegerUnaryOperation(integer_unary_operation) => {
 				Self::fmt_integer_unary_operation(integer_unary_operation, f)
 			}
@@ -395,6 +429,26 @@ impl<'inner> Dot<'inner> {
 			Node::NumberCompareOperation(number_compare_operation) => {
 				Self::fmt_number_compare_operation(number_compare_operation, f)
 			}
+			Node::V128UnaryOperation(v128_unary_operation) => {
+				write!(f, "V128 {:?}", v128_unary_operation.operator)
+			}
+			Node::V128BinaryOperation(v128_binary_operation) => {
+				write!(f, "V128 {:?}", v128_binary_operation.operator)
+			}
+			Node::V128ReduceOperation(v128_reduce_operation) => {
+				write!(f, "V128 {:?}", v128_reduce_operation.operator)
+			}
+			Node::V128Splat(v128_splat) => write!(f, "V128 Splat {:?}", v128_splat.r#type),
+			Node::V128ExtractLane(v128_extract_lane) => write!(
+				f,
+				"V128 Extract Lane {:?} {}",
+				v128_extract_lane.r#type, v128_extract_lane.lane
+			),
+			Node::V128ReplaceLane(v128_replace_lane) => write!(
+				f,
+				"V128 Replace Lane {:?} {}",
+				v128_replace_lane.r#type, v128_replace_lane.lane
+			),
 			Node::DataNew(ref data_new) => Self::fmt_data_new(data_new, f),
 
 			_ => unreachable!(),
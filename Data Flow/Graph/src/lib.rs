@@ -5,21 +5,26 @@ extern crate alloc;
 
 mod dot;
 mod node;
+mod text;
 
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 use self::node::{
 	mvp::{
-		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, ExtendType, GlobalGet, GlobalNew,
-		GlobalSet, Identity, IntegerBinaryOperation, IntegerBinaryOperator,
-		IntegerCompareOperation, IntegerCompareOperator, IntegerConvertToNumber, IntegerExtend,
-		IntegerNarrow, IntegerTransmuteToNumber, IntegerType, IntegerUnaryOperation,
-		IntegerUnaryOperator, IntegerWiden, LoadType, Location, MemoryCopy, MemoryFill, MemoryGrow,
-		MemoryInit, MemoryLoad, MemoryNew, MemorySize, MemoryStore, Merge, NumberBinaryOperation,
+		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, ExceptionGet, ExceptionNew,
+		ExceptionTest, ExtendType, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, GlobalSet,
+		Identity, IntegerBinaryOperation, IntegerBinaryOperator, IntegerCompareOperation,
+		IntegerCompareOperator, IntegerConvertToNumber, IntegerExtend, IntegerNarrow,
+		IntegerTransmuteToNumber, IntegerType, IntegerUnaryOperation, IntegerUnaryOperator,
+		IntegerWiden, LaneType, LoadType, Location, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit,
+		MemoryLoad, MemoryNew, MemorySize, MemoryStore, Merge, NumberBinaryOperation,
 		NumberBinaryOperator, NumberCompareOperation, NumberCompareOperator, NumberNarrow,
 		NumberTransmuteToInteger, NumberTruncateToInteger, NumberType, NumberUnaryOperation,
 		NumberUnaryOperator, NumberWiden, RefIsNull, StoreType, TableCopy, TableFill, TableGet,
-		TableGrow, TableInit, TableNew, TableSet, TableSize,
+		TableGrow, TableInit, TableNew, TableSet, TableSize, Throw, V128BinaryOperation,
+		V128BinaryOperator, V128BitSelect, V128ExtractLane, V128ReduceOperation,
+		V128ReduceOperator, V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
+		V128UnaryOperator,
 	},
 	nested::{
 		Export, FunctionType, GammaIn, GammaOut, Import, LambdaIn, LambdaOut, OmegaIn, OmegaOut,
@@ -30,17 +35,36 @@ use self::node::{
 pub use self::{
 	dot::Dot,
 	node::{Link, Node, mvp, nested},
+	text::{Attribute, HostParser, ParseError, Reader, Syntax, Text},
 };
 
 /// A directed graph of nodes containing operations.
+///
+/// Each node also records the code offset of the instruction it
+/// was built from, if any, to allow mapping output back to source.
 pub struct DataFlowGraph {
 	nodes: Vec<Node>,
+	positions: Vec<Option<u32>>,
+
+	position: Option<u32>,
 }
 
 impl DataFlowGraph {
 	#[must_use]
 	pub const fn new() -> Self {
-		Self { nodes: Vec::new() }
+		Self {
+			nodes: Vec::new(),
+			positions: Vec::new(),
+
+			position: None,
+		}
+	}
+
+	pub fn clear(&mut self) {
+		self.nodes.clear();
+		self.positions.clear();
+
+		self.position = None;
 	}
 
 	#[must_use]
@@ -66,6 +90,20 @@ impl DataFlowGraph {
 		&mut self.nodes
 	}
 
+	#[must_use]
+	pub fn get_position(&self, id: u32) -> Option<u32> {
+		self.positions[usize::try_from(id).unwrap()]
+	}
+
+	pub const fn positions_mut(&mut self) -> &mut Vec<Option<u32>> {
+		&mut self.positions
+	}
+
+	/// Sets the code offset recorded for any nodes added after this call.
+	pub const fn set_position(&mut self, position: Option<u32>) {
+		self.position = position;
+	}
+
 	pub fn nodes(&self) -> core::slice::Iter<'_, Node> {
 		self.nodes.iter()
 	}
@@ -75,18 +113,25 @@ impl DataFlowGraph {
 	}
 
 	pub fn add_node(&mut self, node: Node) -> u32 {
-		let position = self.nodes.len();
+		let id = self.nodes.len();
 
 		self.nodes.push(node);
+		self.positions.push(self.position);
 
-		position.try_into().unwrap()
+		id.try_into().unwrap()
 	}
 
-	pub fn add_lambda_in(&mut self, r#type: Box<FunctionType>, dependencies: Vec<Link>) -> u32 {
+	pub fn add_lambda_in(
+		&mut self,
+		r#type: Box<FunctionType>,
+		dependencies: Vec<Link>,
+		names: Vec<Option<Arc<str>>>,
+	) -> u32 {
 		self.add_node(Node::LambdaIn(LambdaIn {
 			output: u32::MAX,
 			r#type,
 			dependencies,
+			names,
 		}))
 	}
 
@@ -258,24 +303,46 @@ impl DataFlowGraph {
 		Link(self.add_node(f64), 0)
 	}
 
+	pub fn add_v128(&mut self, value: u128) -> Link {
+		let v128 = Node::V128(value);
+
+		Link(self.add_node(v128), 0)
+	}
+
 	pub fn add_ref_is_null(&mut self, source: Link) -> Link {
 		let ref_is_null = Node::RefIsNull(RefIsNull { source });
 
 		Link(self.add_node(ref_is_null), 0)
 	}
 
+	pub fn add_function_new(&mut self, source: Link, signature: Arc<str>) -> Link {
+		let function_new = Node::FunctionNew(FunctionNew { source, signature });
+
+		Link(self.add_node(function_new), 0)
+	}
+
+	pub fn add_function_check(&mut self, source: Link, signature: Arc<str>) -> Link {
+		let function_check = Node::FunctionCheck(FunctionCheck { source, signature });
+
+		Link(self.add_node(function_check), 0)
+	}
+
 	pub fn add_call(
 		&mut self,
 		function: Link,
 		arguments: Vec<Link>,
 		results: u16,
 		states: u16,
+		tail: bool,
+		catch: bool,
 	) -> u32 {
 		let call = Node::Call(Call {
 			function,
 			arguments,
 			results,
 			states,
+			tail,
+			catch,
 		});
 
 		self.add_node(call)
@@ -465,8 +532,92 @@ impl DataFlowGraph {
 		Link(self.add_node(transmute_to_integer), 0)
 	}
 
-	pub fn add_global_new(&mut self, initializer: Link) -> Link {
-		let global_new = Node::GlobalNew(GlobalNew { initializer });
+	pub fn add_v128_unary_operation(&mut self, source: Link, operator: V128UnaryOperator) -> Link {
+		let unary_operation = Node::V128UnaryOperation(V128UnaryOperation { source, operator });
+
+		Link(self.add_node(unary_operation), 0)
+	}
+
+	pub fn add_v128_binary_operation(
+		&mut self,
+		lhs: Link,
+		rhs: Link,
+		operator: V128BinaryOperator,
+	) -> Link {
+		let binary_operation =
+			Node::V128BinaryOperation(V128BinaryOperation { lhs, rhs, operator });
+
+		Link(self.add_node(binary_operation), 0)
+	}
+
+	pub fn add_v128_reduce_operation(
+		&mut self,
+		source: Link,
+		operator: V128ReduceOperator,
+	) -> Link {
+		let reduce_operation = Node::V128ReduceOperation(V128ReduceOperation { source, operator });
+
+		Link(self.add_node(reduce_operation), 0)
+	}
+
+	pub fn add_v128_splat(&mut self, source: Link, r#type: LaneType) -> Link {
+		let splat = Node::V128Splat(V128Splat { source, r#type });
+
+		Link(self.add_node(splat), 0)
+	}
+
+	pub fn add_v128_extract_lane(
+		&mut self,
+		source: Link,
+		r#type: LaneType,
+		lane: u8,
+		signed: bool,
+	) -> Link {
+		let extract_lane = Node::V128ExtractLane(V128ExtractLane {
+			source,
+			r#type,
+			lane,
+			signed,
+		});
+
+		Link(self.add_node(extract_lane), 0)
+	}
+
+	pub fn add_v128_replace_lane(
+		&mut self,
+		lhs: Link,
+		rhs: Link,
+		r#type: LaneType,
+		lane: u8,
+	) -> Link {
+		let replace_lane = Node::V128ReplaceLane(V128ReplaceLane {
+			lhs,
+			rhs,
+			r#type,
+			lane,
+		});
+
+		Link(self.add_node(replace_lane), 0)
+	}
+
+	pub fn add_v128_shuffle(&mut self, lhs: Link, rhs: Link, lanes: [u8; 16]) -> Link {
+		let shuffle = Node::V128Shuffle(V128Shuffle { lhs, rhs, lanes });
+
+		Link(self.add_node(shuffle), 0)
+	}
+
+	pub fn add_v128_bit_select(&mut self, lhs: Link, rhs: Link, condition: Link) -> Link {
+		let bit_select = Node::V128BitSelect(V128BitSelect {
+			lhs,
+			rhs,
+			condition,
+		});
+
+		Link(self.add_node(bit_select), 0)
+	}
+
+	pub fn add_global_new(&mut self, initializer: Link, name: Option<Arc<str>>) -> Link {
+		let global_new = Node::GlobalNew(GlobalNew { initializer, name });
 
 		Link(self.add_node(global_new), 0)
 	}
@@ -656,6 +807,34 @@ impl DataFlowGraph {
 
 		Link(self.add_node(data_drop), 0)
 	}
+
+	pub fn add_tag_new(&mut self) -> Link {
+		Link(self.add_node(Node::TagNew), 0)
+	}
+
+	pub fn add_throw(&mut self, source: Link, states: Vec<Link>) -> u32 {
+		let throw = Node::Throw(Throw { source, states });
+
+		self.add_node(throw)
+	}
+
+	pub fn add_exception_new(&mut self, tag: Link, arguments: Vec<Link>) -> Link {
+		let exception_new = Node::ExceptionNew(ExceptionNew { tag, arguments });
+
+		Link(self.add_node(exception_new), 0)
+	}
+
+	pub fn add_exception_test(&mut self, source: Link, tag: Link) -> Link {
+		let exception_test = Node::ExceptionTest(ExceptionTest { source, tag });
+
+		Link(self.add_node(exception_test), 0)
+	}
+
+	pub fn add_exception_get(&mut self, source: Link, index: u16) -> Link {
+		let exception_get = Node::ExceptionGet(ExceptionGet { source, index });
+
+		Link(self.add_node(exception_get), 0)
+	}
 }
 
 impl Default for DataFlowGraph {
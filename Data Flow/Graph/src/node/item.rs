@@ -2,14 +2,16 @@ use alloc::boxed::Box;
 
 use super::{
 	mvp::{
-		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, GlobalGet, GlobalNew, GlobalSet, Host,
-		Identity, IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber,
-		IntegerExtend, IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation,
-		IntegerWiden, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemoryNew,
-		MemorySize, MemoryStore, Merge, NumberBinaryOperation, NumberCompareOperation,
-		NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation,
-		NumberWiden, RefIsNull, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableNew,
-		TableSet, TableSize,
+		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, ExceptionGet, ExceptionNew,
+		ExceptionTest, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, GlobalSet, Host, Identity,
+		IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
+		IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, MemoryCopy,
+		MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemoryNew, MemorySize, MemoryStore, Merge,
+		NumberBinaryOperation, NumberCompareOperation, NumberNarrow, NumberTransmuteToInteger,
+		NumberTruncateToInteger, NumberUnaryOperation, NumberWiden, RefIsNull, TableCopy,
+		TableFill, TableGet, TableGrow, TableInit, TableNew, TableSet, TableSize, Throw,
+		V128BinaryOperation, V128BitSelect, V128ExtractLane, V128ReduceOperation, V128ReplaceLane,
+		V128Shuffle, V128Splat, V128UnaryOperation,
 	},
 	nested::{
 		GammaIn, GammaOut, Import, LambdaIn, LambdaOut, OmegaIn, OmegaOut, RegionIn, RegionOut,
@@ -45,11 +47,14 @@ pub enum Node {
 	I64(i64),
 	F32(f32),
 	F64(f64),
+	V128(u128),
 
 	Call(Call),
 	Merge(Merge),
 
 	RefIsNull(RefIsNull),
+	FunctionNew(FunctionNew),
+	FunctionCheck(FunctionCheck),
 
 	IntegerUnaryOperation(IntegerUnaryOperation),
 	IntegerBinaryOperation(IntegerBinaryOperation),
@@ -68,6 +73,15 @@ pub enum Node {
 	NumberTruncateToInteger(NumberTruncateToInteger),
 	NumberTransmuteToInteger(NumberTransmuteToInteger),
 
+	V128UnaryOperation(V128UnaryOperation),
+	V128BinaryOperation(V128BinaryOperation),
+	V128ReduceOperation(V128ReduceOperation),
+	V128Splat(V128Splat),
+	V128ExtractLane(V128ExtractLane),
+	V128ReplaceLane(V128ReplaceLane),
+	V128Shuffle(V128Shuffle),
+	V128BitSelect(V128BitSelect),
+
 	GlobalNew(GlobalNew),
 	GlobalGet(GlobalGet),
 	GlobalSet(GlobalSet),
@@ -95,6 +109,13 @@ pub enum Node {
 
 	DataNew(DataNew),
 	DataDrop(DataDrop),
+
+	TagNew,
+	Throw(Throw),
+
+	ExceptionNew(ExceptionNew),
+	ExceptionTest(ExceptionTest),
+	ExceptionGet(ExceptionGet),
 }
 
 macro_rules! as_ref_inner {
@@ -123,6 +144,17 @@ macro_rules! as_mut_inner {
 	};
 }
 
+macro_rules! try_clone {
+	($node:expr, [$($unit:ident),*], [$($copy:ident),*], [$($clone:ident),*]) => {
+		match $node {
+			$(Self::$unit => Self::$unit,)*
+			$(Self::$copy(node) => Self::$copy(*node),)*
+			$(Self::$clone(node) => Self::$clone(node.clone()),)*
+			Self::Host(_) => return None,
+		}
+	};
+}
+
 impl Node {
 	as_ref_inner!(LambdaIn, as_lambda_in);
 	as_ref_inner!(LambdaOut, as_lambda_out);
@@ -145,6 +177,94 @@ impl Node {
 	as_mut_inner!(ThetaOut, as_mut_theta_out);
 	as_mut_inner!(OmegaIn, as_mut_omega_in);
 	as_mut_inner!(OmegaOut, as_mut_omega_out);
+
+	/// Returns a copy of the node, unless it is a [`Host`] node, as those
+	/// cannot be copied.
+	#[must_use]
+	pub fn try_clone(&self) -> Option<Self> {
+		let node = try_clone!(
+			self,
+			[Trap, Null, TagNew],
+			[
+				Identity,
+				I32,
+				I64,
+				F32,
+				F64,
+				V128,
+				RefIsNull,
+				IntegerUnaryOperation,
+				IntegerBinaryOperation,
+				IntegerCompareOperation,
+				IntegerNarrow,
+				IntegerWiden,
+				IntegerExtend,
+				IntegerConvertToNumber,
+				IntegerTransmuteToNumber,
+				NumberUnaryOperation,
+				NumberBinaryOperation,
+				NumberCompareOperation,
+				NumberNarrow,
+				NumberWiden,
+				NumberTruncateToInteger,
+				NumberTransmuteToInteger,
+				V128UnaryOperation,
+				V128BinaryOperation,
+				V128ReduceOperation,
+				V128Splat,
+				V128ExtractLane,
+				V128ReplaceLane,
+				V128Shuffle,
+				V128BitSelect,
+				GlobalGet,
+				GlobalSet,
+				TableNew,
+				TableGet,
+				TableSet,
+				TableSize,
+				TableGrow,
+				TableFill,
+				TableCopy,
+				TableInit,
+				ElementsDrop,
+				MemoryNew,
+				MemoryLoad,
+				MemoryStore,
+				MemorySize,
+				MemoryGrow,
+				MemoryFill,
+				MemoryCopy,
+				MemoryInit,
+				DataDrop,
+				ExceptionTest,
+				ExceptionGet
+			],
+			[
+				LambdaIn,
+				LambdaOut,
+				RegionIn,
+				RegionOut,
+				GammaIn,
+				GammaOut,
+				ThetaIn,
+				ThetaOut,
+				OmegaIn,
+				OmegaOut,
+				Import,
+				Call,
+				Merge,
+				FunctionNew,
+				FunctionCheck,
+				GlobalNew,
+				ElementsNew,
+				DataNew,
+				Throw,
+				ExceptionNew
+			]
+		);
+
+		Some(node)
+	}
 }
 
 impl Default for Node {
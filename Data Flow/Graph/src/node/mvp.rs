@@ -1,11 +1,13 @@
+use core::fmt::Formatter;
+
 use alloc::{sync::Arc, vec::Vec};
 
 use super::link::Link;
 
 pub use control_flow_graph::instruction::{
 	ExtendType, IntegerBinaryOperator, IntegerCompareOperator, IntegerType, IntegerUnaryOperator,
-	LoadType, NumberBinaryOperator, NumberCompareOperator, NumberType, NumberUnaryOperator,
-	StoreType,
+	LaneType, LoadType, NumberBinaryOperator, NumberCompareOperator, NumberType,
+	NumberUnaryOperator, StoreType, V128BinaryOperator, V128ReduceOperator, V128UnaryOperator,
 };
 
 pub trait Host {
@@ -20,6 +22,12 @@ pub trait Host {
 	fn for_each_argument(&self, handler: &mut dyn FnMut(Link));
 
 	fn for_each_mut_argument(&mut self, handler: &mut dyn FnMut(&mut Link));
+
+	/// Writes the attributes that follow the identifier in the textual
+	/// format, each preceded by a space.
+	fn fmt_attributes(&self, _f: &mut Formatter<'_>) -> core::fmt::Result {
+		Ok(())
+	}
 }
 
 #[derive(Clone, Copy)]
@@ -27,30 +35,46 @@ pub struct Identity {
 	pub source: Link,
 }
 
+#[derive(Clone)]
 pub struct Call {
 	pub function: Link,
 	pub arguments: Vec<Link>,
 	pub results: u16,
 	pub states: u16,
+	pub tail: bool,
+	pub catch: bool,
 }
 
+#[derive(Clone)]
 pub struct Merge {
 	pub states: Vec<Link>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct RefIsNull {
 	pub source: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
+pub struct FunctionNew {
+	pub source: Link,
+	pub signature: Arc<str>,
+}
+
+#[derive(Clone)]
+pub struct FunctionCheck {
+	pub source: Link,
+	pub signature: Arc<str>,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerUnaryOperation {
 	pub source: Link,
 	pub r#type: IntegerType,
 	pub operator: IntegerUnaryOperator,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerBinaryOperation {
 	pub lhs: Link,
 	pub rhs: Link,
@@ -58,7 +82,7 @@ pub struct IntegerBinaryOperation {
 	pub operator: IntegerBinaryOperator,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerCompareOperation {
 	pub lhs: Link,
 	pub rhs: Link,
@@ -66,23 +90,23 @@ pub struct IntegerCompareOperation {
 	pub operator: IntegerCompareOperator,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerNarrow {
 	pub source: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerWiden {
 	pub source: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerExtend {
 	pub source: Link,
 	pub r#type: ExtendType,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerConvertToNumber {
 	pub source: Link,
 	pub signed: bool,
@@ -90,20 +114,20 @@ pub struct IntegerConvertToNumber {
 	pub from: IntegerType,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct IntegerTransmuteToNumber {
 	pub source: Link,
 	pub from: IntegerType,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct NumberUnaryOperation {
 	pub source: Link,
 	pub r#type: NumberType,
 	pub operator: NumberUnaryOperator,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct NumberBinaryOperation {
 	pub lhs: Link,
 	pub rhs: Link,
@@ -111,7 +135,7 @@ pub struct NumberBinaryOperation {
 	pub operator: NumberBinaryOperator,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct NumberCompareOperation {
 	pub lhs: Link,
 	pub rhs: Link,
@@ -119,7 +143,7 @@ pub struct NumberCompareOperation {
 	pub operator: NumberCompareOperator,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct NumberTruncateToInteger {
 	pub source: Link,
 	pub signed: bool,
@@ -128,34 +152,90 @@ pub struct NumberTruncateToInteger {
 	pub from: NumberType,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct NumberTransmuteToInteger {
 	pub source: Link,
 	pub from: NumberType,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct NumberNarrow {
 	pub source: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct NumberWiden {
 	pub source: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128UnaryOperation {
+	pub source: Link,
+	pub operator: V128UnaryOperator,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128BinaryOperation {
+	pub lhs: Link,
+	pub rhs: Link,
+	pub operator: V128BinaryOperator,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128ReduceOperation {
+	pub source: Link,
+	pub operator: V128ReduceOperator,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128Splat {
+	pub source: Link,
+	pub r#type: LaneType,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128ExtractLane {
+	pub source: Link,
+	pub r#type: LaneType,
+	pub lane: u8,
+	pub signed: bool,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128ReplaceLane {
+	pub lhs: Link,
+	pub rhs: Link,
+	pub r#type: LaneType,
+	pub lane: u8,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128Shuffle {
+	pub lhs: Link,
+	pub rhs: Link,
+	pub lanes: [u8; 16],
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+pub struct V128BitSelect {
+	pub lhs: Link,
+	pub rhs: Link,
+	pub condition: Link,
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct Location {
 	pub reference: Link,
 	pub offset: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct GlobalNew {
 	pub initializer: Link,
+	pub name: Option<Arc<str>>,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct GlobalGet {
 	pub source: Link,
 }
@@ -173,7 +253,7 @@ pub struct TableNew {
 	pub maximum: u32,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct TableGet {
 	pub source: Location,
 }
@@ -184,7 +264,7 @@ pub struct TableSet {
 	pub source: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct TableSize {
 	pub source: Link,
 }
@@ -217,6 +297,7 @@ pub struct TableInit {
 	pub size: Link,
 }
 
+#[derive(Clone)]
 pub struct ElementsNew {
 	pub content: Vec<Link>,
 }
@@ -226,13 +307,13 @@ pub struct ElementsDrop {
 	pub source: Link,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub struct MemoryNew {
 	pub minimum: u32,
 	pub maximum: u32,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct MemoryLoad {
 	pub source: Location,
 	pub r#type: LoadType,
@@ -245,7 +326,7 @@ pub struct MemoryStore {
 	pub r#type: StoreType,
 }
 
-#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
 pub struct MemorySize {
 	pub source: Link,
 }
@@ -277,7 +358,7 @@ pub struct MemoryInit {
 	pub size: Link,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct DataNew {
 	pub content: Arc<[u8]>,
 }
@@ -286,3 +367,27 @@ pub struct DataNew {
 pub struct DataDrop {
 	pub source: Link,
 }
+
+#[derive(Clone)]
+pub struct Throw {
+	pub source: Link,
+	pub states: Vec<Link>,
+}
+
+#[derive(Clone)]
+pub struct ExceptionNew {
+	pub tag: Link,
+	pub arguments: Vec<Link>,
+}
+
+#[derive(Clone, Copy)]
+pub struct ExceptionTest {
+	pub source: Link,
+	pub tag: Link,
+}
+
+#[derive(Clone, Copy)]
+pub struct ExceptionGet {
+	pub source: Link,
+	pub index: u16,
+}
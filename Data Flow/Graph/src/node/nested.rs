@@ -3,12 +3,13 @@ use list::resizable::Resizable;
 
 use super::link::Link;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum ValueType {
 	I32,
 	I64,
 	F32,
 	F64,
+	V128,
 
 	Reference,
 }
@@ -24,6 +25,7 @@ pub struct LambdaIn {
 	pub output: u32,
 	pub r#type: Box<FunctionType>,
 	pub dependencies: Vec<Link>,
+	pub names: Vec<Option<Arc<str>>>,
 }
 
 impl LambdaIn {
@@ -108,6 +110,7 @@ pub struct OmegaIn {
 
 #[derive(Clone)]
 pub struct Export {
+	pub instance: Option<Arc<str>>,
 	pub identifier: Arc<str>,
 	pub reference: Link,
 }
@@ -6,14 +6,16 @@ use super::{
 	item::Node,
 	link::Link,
 	mvp::{
-		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, GlobalGet, GlobalNew, GlobalSet,
-		Identity, IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber,
-		IntegerExtend, IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation,
-		IntegerWiden, Location, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad,
-		MemoryNew, MemorySize, MemoryStore, Merge, NumberBinaryOperation, NumberCompareOperation,
-		NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation,
-		NumberWiden, RefIsNull, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableNew,
-		TableSet, TableSize,
+		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, ExceptionGet, ExceptionNew,
+		ExceptionTest, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, GlobalSet, Identity,
+		IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
+		IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Location,
+		MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemoryNew, MemorySize,
+		MemoryStore, Merge, NumberBinaryOperation, NumberCompareOperation, NumberNarrow,
+		NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation, NumberWiden,
+		RefIsNull, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableNew, TableSet,
+		TableSize, Throw, V128BinaryOperation, V128BitSelect, V128ExtractLane, V128ReduceOperation,
+		V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
 	},
 	nested::{
 		Export, GammaIn, GammaOut, Import, LambdaIn, LambdaOut, OmegaIn, OmegaOut, RegionIn,
@@ -36,12 +38,20 @@ macro_rules! for_each_visit {
 			Self::OmegaOut(omega_out) => omega_out.$visit($handler),
 			Self::Import(import) => import.$visit($handler),
 			Self::Host(host) => host.$visit(&mut $handler),
-			Self::Trap | Self::Null | Self::I32(_) | Self::I64(_) | Self::F32(_) | Self::F64(_) => {
-			}
+			Self::Trap
+			| Self::Null
+			| Self::I32(_)
+			| Self::I64(_)
+			| Self::F32(_)
+			| Self::F64(_)
+			| Self::V128(_)
+			| Self::TagNew => {}
 			Self::Identity(identity) => identity.$visit($handler),
 			Self::Call(call) => call.$visit($handler),
 			Self::Merge(merge) => merge.$visit($handler),
 			Self::RefIsNull(ref_is_null) => ref_is_null.$visit($handler),
+			Self::FunctionNew(function_new) => function_new.$visit($handler),
+			Self::FunctionCheck(function_check) => function_check.$visit($handler),
 			Self::IntegerUnaryOperation(integer_unary_operation) => {
 				integer_unary_operation.$visit($handler)
 			}
@@ -77,6 +87,18 @@ macro_rules! for_each_visit {
 			Self::NumberTransmuteToInteger(number_transmute_to_integer) => {
 				number_transmute_to_integer.$visit($handler)
 			}
+			Self::V128UnaryOperation(v128_unary_operation) => v128_unary_operation.$visit($handler),
+			Self::V128BinaryOperation(v128_binary_operation) => {
+				v128_binary_operation.$visit($handler)
+			}
+			Self::V128ReduceOperation(v128_reduce_operation) => {
+				v128_reduce_operation.$visit($handler)
+			}
+			Self::V128Splat(v128_splat) => v128_splat.$visit($handler),
+			Self::V128ExtractLane(v128_extract_lane) => v128_extract_lane.$visit($handler),
+			Self::V128ReplaceLane(v128_replace_lane) => v128_replace_lane.$visit($handler),
+			Self::V128Shuffle(v128_shuffle) => v128_shuffle.$visit($handler),
+			Self::V128BitSelect(v128_bit_select) => v128_bit_select.$visit($handler),
 			Self::GlobalNew(global_new) => global_new.$visit($handler),
 			Self::GlobalGet(global_get) => global_get.$visit($handler),
 			Self::GlobalSet(global_set) => global_set.$visit($handler),
@@ -100,6 +122,10 @@ macro_rules! for_each_visit {
 			Self::MemoryInit(memory_init) => memory_init.$visit($handler),
 			Self::DataNew(data_new) => data_new.$visit($handler),
 			Self::DataDrop(data_drop) => data_drop.$visit($handler),
+			Self::Throw(throw) => throw.$visit($handler),
+			Self::ExceptionNew(exception_new) => exception_new.$visit($handler),
+			Self::ExceptionTest(exception_test) => exception_test.$visit($handler),
+			Self::ExceptionGet(exception_get) => exception_get.$visit($handler),
 		}
 	};
 }
@@ -118,6 +144,7 @@ impl LambdaIn {
 			output,
 			r#type: _,
 			dependencies,
+			names: _,
 		} = self;
 
 		handler(*output);
@@ -130,6 +157,7 @@ impl LambdaIn {
 			output,
 			r#type: _,
 			dependencies,
+			names: _,
 		} = self;
 
 		handler(output);
@@ -142,6 +170,7 @@ impl LambdaIn {
 			output: _,
 			r#type: _,
 			dependencies,
+			names: _,
 		} = self;
 
 		dependencies.iter().copied().for_each(handler);
@@ -152,6 +181,7 @@ impl LambdaIn {
 			output: _,
 			r#type: _,
 			dependencies,
+			names: _,
 		} = self;
 
 		dependencies.iter_mut().for_each(handler);
@@ -516,6 +546,7 @@ impl OmegaIn {
 impl Export {
 	fn for_each_id<H: FnMut(u32)>(&self, mut handler: H) {
 		let Self {
+			instance: _,
 			identifier: _,
 			reference,
 		} = self;
@@ -525,6 +556,7 @@ impl Export {
 
 	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
 		let Self {
+			instance: _,
 			identifier: _,
 			reference,
 		} = self;
@@ -534,6 +566,7 @@ impl Export {
 
 	fn for_each_argument<H: FnMut(Link)>(&self, mut handler: H) {
 		let Self {
+			instance: _,
 			identifier: _,
 			reference,
 		} = self;
@@ -543,6 +576,7 @@ impl Export {
 
 	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
 		let Self {
+			instance: _,
 			identifier: _,
 			reference,
 		} = self;
@@ -696,6 +730,8 @@ impl Call {
 			arguments,
 			results: _,
 			states: _,
+			tail: _,
+			catch: _,
 		} = self;
 
 		handler(function.0);
@@ -708,6 +744,8 @@ impl Call {
 			arguments,
 			results: _,
 			states: _,
+			tail: _,
+			catch: _,
 		} = self;
 
 		handler(&mut function.0);
@@ -720,6 +758,8 @@ impl Call {
 			arguments,
 			results: _,
 			states: _,
+			tail: _,
+			catch: _,
 		} = self;
 
 		handler(*function);
@@ -732,6 +772,8 @@ impl Call {
 			arguments,
 			results: _,
 			states: _,
+			tail: _,
+			catch: _,
 		} = self;
 
 		handler(function);
@@ -791,6 +833,82 @@ impl RefIsNull {
 	}
 }
 
+impl FunctionNew {
+	fn for_each_id<H: FnMut(u32)>(&self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(source.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(&mut source.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(&self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(*source);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(source);
+	}
+}
+
+impl FunctionCheck {
+	fn for_each_id<H: FnMut(u32)>(&self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(source.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(&mut source.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(&self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(*source);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		handler(source);
+	}
+}
+
 impl IntegerUnaryOperation {
 	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
 		let Self {
@@ -1383,27 +1501,363 @@ impl Location {
 	}
 }
 
-impl GlobalNew {
+impl V128UnaryOperation {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(source.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(&mut source.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(source);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(source);
+	}
+}
+
+impl V128BinaryOperation {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			operator: _,
+		} = self;
+
+		handler(lhs.0);
+		handler(rhs.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			operator: _,
+		} = self;
+
+		handler(&mut lhs.0);
+		handler(&mut rhs.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			operator: _,
+		} = self;
+
+		handler(lhs);
+		handler(rhs);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			operator: _,
+		} = self;
+
+		handler(lhs);
+		handler(rhs);
+	}
+}
+
+impl V128ReduceOperation {
 	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
-		let Self { initializer } = self;
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(source.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(&mut source.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(source);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		handler(source);
+	}
+}
+
+impl V128Splat {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self { source, r#type: _ } = self;
+
+		handler(source.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self { source, r#type: _ } = self;
+
+		handler(&mut source.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self { source, r#type: _ } = self;
+
+		handler(source);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self { source, r#type: _ } = self;
+
+		handler(source);
+	}
+}
+
+impl V128ExtractLane {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self {
+			source,
+			r#type: _,
+			lane: _,
+			signed: _,
+		} = self;
+
+		handler(source.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			r#type: _,
+			lane: _,
+			signed: _,
+		} = self;
+
+		handler(&mut source.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self {
+			source,
+			r#type: _,
+			lane: _,
+			signed: _,
+		} = self;
+
+		handler(source);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			source,
+			r#type: _,
+			lane: _,
+			signed: _,
+		} = self;
+
+		handler(source);
+	}
+}
+
+impl V128ReplaceLane {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			r#type: _,
+			lane: _,
+		} = self;
+
+		handler(lhs.0);
+		handler(rhs.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			r#type: _,
+			lane: _,
+		} = self;
+
+		handler(&mut lhs.0);
+		handler(&mut rhs.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			r#type: _,
+			lane: _,
+		} = self;
+
+		handler(lhs);
+		handler(rhs);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			r#type: _,
+			lane: _,
+		} = self;
+
+		handler(lhs);
+		handler(rhs);
+	}
+}
+
+impl V128Shuffle {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self { lhs, rhs, lanes: _ } = self;
+
+		handler(lhs.0);
+		handler(rhs.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self { lhs, rhs, lanes: _ } = self;
+
+		handler(&mut lhs.0);
+		handler(&mut rhs.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self { lhs, rhs, lanes: _ } = self;
+
+		handler(lhs);
+		handler(rhs);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self { lhs, rhs, lanes: _ } = self;
+
+		handler(lhs);
+		handler(rhs);
+	}
+}
+
+impl V128BitSelect {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			condition,
+		} = self;
+
+		handler(lhs.0);
+		handler(rhs.0);
+		handler(condition.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			condition,
+		} = self;
+
+		handler(&mut lhs.0);
+		handler(&mut rhs.0);
+		handler(&mut condition.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			condition,
+		} = self;
+
+		handler(lhs);
+		handler(rhs);
+		handler(condition);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self {
+			lhs,
+			rhs,
+			condition,
+		} = self;
+
+		handler(lhs);
+		handler(rhs);
+		handler(condition);
+	}
+}
+
+impl GlobalNew {
+	fn for_each_id<H: FnMut(u32)>(&self, mut handler: H) {
+		let Self {
+			initializer,
+			name: _,
+		} = self;
 
 		handler(initializer.0);
 	}
 
 	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
-		let Self { initializer } = self;
+		let Self {
+			initializer,
+			name: _,
+		} = self;
 
 		handler(&mut initializer.0);
 	}
 
-	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
-		let Self { initializer } = self;
+	fn for_each_argument<H: FnMut(Link)>(&self, mut handler: H) {
+		let Self {
+			initializer,
+			name: _,
+		} = self;
 
-		handler(initializer);
+		handler(*initializer);
 	}
 
 	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
-		let Self { initializer } = self;
+		let Self {
+			initializer,
+			name: _,
+		} = self;
 
 		handler(initializer);
 	}
@@ -2264,6 +2718,122 @@ impl DataDrop {
 	}
 }
 
+impl Throw {
+	fn for_each_id<H: FnMut(u32)>(&self, mut handler: H) {
+		let Self { source, states } = self;
+
+		handler(source.0);
+		for_each_link_list(states, handler);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self { source, states } = self;
+
+		handler(&mut source.0);
+		for_each_mut_link_list(states, handler);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(&self, mut handler: H) {
+		let Self { source, states } = self;
+
+		handler(*source);
+		states.iter().copied().for_each(handler);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self { source, states } = self;
+
+		handler(source);
+		states.iter_mut().for_each(handler);
+	}
+}
+
+impl ExceptionNew {
+	fn for_each_id<H: FnMut(u32)>(&self, mut handler: H) {
+		let Self { tag, arguments } = self;
+
+		handler(tag.0);
+		for_each_link_list(arguments, handler);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self { tag, arguments } = self;
+
+		handler(&mut tag.0);
+		for_each_mut_link_list(arguments, handler);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(&self, mut handler: H) {
+		let Self { tag, arguments } = self;
+
+		handler(*tag);
+		arguments.iter().copied().for_each(handler);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self { tag, arguments } = self;
+
+		handler(tag);
+		arguments.iter_mut().for_each(handler);
+	}
+}
+
+impl ExceptionTest {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self { source, tag } = self;
+
+		handler(source.0);
+		handler(tag.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self { source, tag } = self;
+
+		handler(&mut source.0);
+		handler(&mut tag.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self { source, tag } = self;
+
+		handler(source);
+		handler(tag);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self { source, tag } = self;
+
+		handler(source);
+		handler(tag);
+	}
+}
+
+impl ExceptionGet {
+	fn for_each_id<H: FnMut(u32)>(self, mut handler: H) {
+		let Self { source, index: _ } = self;
+
+		handler(source.0);
+	}
+
+	fn for_each_mut_id<H: FnMut(&mut u32)>(&mut self, mut handler: H) {
+		let Self { source, index: _ } = self;
+
+		handler(&mut source.0);
+	}
+
+	fn for_each_argument<H: FnMut(Link)>(self, mut handler: H) {
+		let Self { source, index: _ } = self;
+
+		handler(source);
+	}
+
+	fn for_each_mut_argument<H: FnMut(&mut Link)>(&mut self, mut handler: H) {
+		let Self { source, index: _ } = self;
+
+		handler(source);
+	}
+}
+
 impl Node {
 	#[must_use]
 	pub fn ports_output(&self, graph: &DataFlowGraph) -> Option<usize> {
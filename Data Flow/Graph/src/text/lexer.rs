@@ -0,0 +1,167 @@
+use alloc::{format, string::String, vec::Vec};
+
+pub enum Token<'source> {
+	Word(&'source str),
+	String(Vec<u8>),
+	Open,
+	Close,
+	OpenList,
+	CloseList,
+	Comma,
+	Equals,
+	Newline,
+}
+
+pub struct Lexer<'source> {
+	source: &'source str,
+	position: usize,
+	line: usize,
+}
+
+impl<'source> Lexer<'source> {
+	pub const fn new(source: &'source str) -> Self {
+		Self {
+			source,
+			position: 0,
+			line: 1,
+		}
+	}
+
+	pub const fn line(&self) -> usize {
+		self.line
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.source[self.position..].chars().next()
+	}
+
+	fn bump(&mut self) -> Option<char> {
+		let next = self.peek()?;
+
+		self.position += next.len_utf8();
+
+		Some(next)
+	}
+
+	const fn is_delimiter(next: char) -> bool {
+		matches!(next, '(' | ')' | '[' | ']' | ',' | '=' | '"' | '#')
+	}
+
+	fn skip_blank(&mut self) {
+		while let Some(next) = self.peek() {
+			if next == '#' {
+				while self.peek().is_some_and(|next| next != '\n') {
+					self.bump();
+				}
+			} else if next.is_whitespace() && next != '\n' {
+				self.bump();
+			} else {
+				break;
+			}
+		}
+	}
+
+	// Words may contain brackets after their first character, which
+	// lets operators such as `Divide{signed:true}` stay a single word.
+	fn read_word(&mut self) -> &'source str {
+		let start = self.position;
+		let mut depth = 0_usize;
+
+		while let Some(next) = self.peek() {
+			let is_first = self.position == start;
+
+			match next {
+				'(' | '{' if !is_first => depth += 1,
+				')' | '}' if depth != 0 => depth -= 1,
+				',' if depth != 0 => {}
+				_ if next.is_whitespace() || Self::is_delimiter(next) => break,
+				_ => {}
+			}
+
+			self.bump();
+		}
+
+		&self.source[start..self.position]
+	}
+
+	fn read_hex_escape(&mut self) -> Result<u8, String> {
+		let start = self.position;
+
+		self.bump();
+		self.bump();
+
+		self.source
+			.get(start..self.position)
+			.and_then(|digits| u8::from_str_radix(digits, 16).ok())
+			.ok_or_else(|| "malformed `\\x` escape".into())
+	}
+
+	fn read_escape(&mut self) -> Result<u8, String> {
+		let escape = match self.bump() {
+			Some('n') => b'\n',
+			Some('r') => b'\r',
+			Some('t') => b'\t',
+			Some('0') => b'\0',
+			Some('\\') => b'\\',
+			Some('\'') => b'\'',
+			Some('"') => b'"',
+			Some('x') => return self.read_hex_escape(),
+			Some(other) => return Err(format!("unknown escape `\\{other}`")),
+			None => return Err("unterminated string".into()),
+		};
+
+		Ok(escape)
+	}
+
+	fn read_string(&mut self) -> Result<Vec<u8>, String> {
+		let mut content = Vec::new();
+
+		self.bump();
+
+		loop {
+			match self.bump() {
+				Some('"') => return Ok(content),
+				Some('\\') => content.push(self.read_escape()?),
+				Some('\n') | None => return Err("unterminated string".into()),
+				Some(other) => {
+					let mut buffer = [0; 4];
+
+					content.extend_from_slice(other.encode_utf8(&mut buffer).as_bytes());
+				}
+			}
+		}
+	}
+
+	/// Returns the next token, or `None` at the end of the source.
+	pub fn next_token(&mut self) -> Result<Option<Token<'source>>, String> {
+		self.skip_blank();
+
+		let Some(next) = self.peek() else {
+			return Ok(None);
+		};
+
+		let token = match next {
+			'"' => {
+				return self
+					.read_string()
+					.map(|content| Some(Token::String(content)));
+			}
+			'(' => Token::Open,
+			')' => Token::Close,
+			'[' => Token::OpenList,
+			']' => Token::CloseList,
+			',' => Token::Comma,
+			'=' => Token::Equals,
+			'\n' => {
+				self.line += 1;
+
+				Token::Newline
+			}
+			_ => return Ok(Some(Token::Word(self.read_word()))),
+		};
+
+		self.bump();
+
+		Ok(Some(token))
+	}
+}
@@ -0,0 +1,43 @@
+use core::fmt::{Debug, Result, Write};
+
+use alloc::string::String;
+
+mod lexer;
+mod parser;
+mod printer;
+mod words;
+
+pub use self::{
+	parser::{Attribute, HostParser, ParseError, Reader, Syntax},
+	printer::Text,
+};
+
+// Writes the `Debug` form of values without any whitespace, so that
+// they can be read back as a single word.
+struct Compact<'out>(&'out mut dyn Write);
+
+impl Write for Compact<'_> {
+	fn write_str(&mut self, s: &str) -> Result {
+		s.chars()
+			.filter(|next| !next.is_whitespace())
+			.try_for_each(|next| self.0.write_char(next))
+	}
+}
+
+fn write_compact<T: Debug>(out: &mut dyn Write, value: &T) -> Result {
+	write!(Compact(out), "{value:?}")
+}
+
+fn find_compact<T, I>(word: &str, candidates: I) -> Option<T>
+where
+	T: Debug,
+	I: IntoIterator<Item = T>,
+{
+	let mut buffer = String::new();
+
+	candidates.into_iter().find(|candidate| {
+		buffer.clear();
+
+		write_compact(&mut buffer, candidate).is_ok() && buffer == word
+	})
+}
@@ -0,0 +1,1231 @@
+use core::{
+	fmt::{Debug, Display, Formatter},
+	iter::Peekable,
+	str::FromStr,
+};
+
+use alloc::{
+	boxed::Box,
+	format,
+	string::{String, ToString},
+	sync::Arc,
+	vec::{IntoIter, Vec},
+};
+
+use crate::{
+	DataFlowGraph, Link,
+	mvp::{
+		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, ExceptionGet, ExceptionNew,
+		ExceptionTest, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, GlobalSet, Host, Identity,
+		IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
+		IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Location,
+		MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemoryNew, MemorySize,
+		MemoryStore, Merge, NumberBinaryOperation, NumberCompareOperation, NumberNarrow,
+		NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation, NumberWiden,
+		RefIsNull, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableNew, TableSet,
+		TableSize, Throw, V128BinaryOperation, V128BitSelect, V128ExtractLane, V128ReduceOperation,
+		V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
+	},
+	nested::{
+		Export, FunctionType, GammaIn, GammaOut, Import, LambdaIn, LambdaOut, OmegaIn, OmegaOut,
+		RegionIn, RegionOut, ThetaIn, ThetaOut,
+	},
+	node::Node,
+};
+
+use super::{
+	find_compact,
+	lexer::{Lexer, Token},
+	words,
+};
+
+/// A single attribute of a node, as written between its name and links.
+pub enum Attribute<'source> {
+	Word(&'source str),
+	String(Vec<u8>),
+	List(Vec<Attribute<'source>>),
+}
+
+/// Reads the attributes of a node in order.
+pub struct Reader<'list, 'source> {
+	attributes: core::slice::Iter<'list, Attribute<'source>>,
+}
+
+impl<'list, 'source> Reader<'list, 'source> {
+	#[must_use]
+	pub fn new(attributes: &'list [Attribute<'source>]) -> Self {
+		Self {
+			attributes: attributes.iter(),
+		}
+	}
+
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.attributes.as_slice().is_empty()
+	}
+
+	#[must_use]
+	pub fn peek(&self) -> Option<&'list Attribute<'source>> {
+		self.attributes.as_slice().first()
+	}
+
+	/// # Errors
+	///
+	/// Returns an error if there are no attributes left.
+	pub fn read(&mut self) -> Result<&'list Attribute<'source>, String> {
+		self.attributes
+			.next()
+			.ok_or_else(|| "expected more attributes".into())
+	}
+
+	/// # Errors
+	///
+	/// Returns an error if the next attribute is not a word.
+	pub fn word(&mut self) -> Result<&'source str, String> {
+		match self.read()? {
+			Attribute::Word(word) => Ok(word),
+			_ => Err("expected a word".into()),
+		}
+	}
+
+	/// # Errors
+	///
+	/// Returns an error if the next attribute is not a string.
+	pub fn string(&mut self) -> Result<&'list [u8], String> {
+		match self.read()? {
+			Attribute::String(content) => Ok(content),
+			_ => Err("expected a string".into()),
+		}
+	}
+
+	/// # Errors
+	///
+	/// Returns an error if the next attribute is not a list.
+	pub fn list(&mut self) -> Result<Self, String> {
+		match self.read()? {
+			Attribute::List(list) => Ok(Self::new(list)),
+			_ => Err("expected a list".into()),
+		}
+	}
+
+	/// # Errors
+	///
+	/// Returns an error if the next attribute is not a UTF-8 string.
+	pub fn text(&mut self) -> Result<Arc<str>, String> {
+		let content = self.string()?;
+
+		core::str::from_utf8(content)
+			.map(Arc::from)
+			.map_err(|error| error.to_string())
+	}
+
+	/// Reads either a string or `_` for no name.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the next attribute is neither.
+	pub fn name(&mut self) -> Result<Option<Arc<str>>, String> {
+		if self.flag("_") {
+			Ok(None)
+		} else {
+			self.text().map(Some)
+		}
+	}
+
+	/// # Errors
+	///
+	/// Returns an error if the next attribute is not a word that parses as `T`.
+	pub fn number<T: FromStr>(&mut self) -> Result<T, String> {
+		let word = self.word()?;
+
+		word.parse()
+			.map_err(|_| format!("`{word}` is not a valid number"))
+	}
+
+	/// Reads a node reference written as `@id`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the next attribute is not a node reference.
+	pub fn id(&mut self) -> Result<u32, String> {
+		let word = self.word()?;
+
+		word.strip_prefix('@')
+			.and_then(|id| id.parse().ok())
+			.ok_or_else(|| format!("`{word}` is not a node reference"))
+	}
+
+	/// Reads a word equal to the compact `Debug` form of one of the `candidates`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if no candidate matches the next attribute.
+	pub fn value<T, I>(&mut self, candidates: I) -> Result<T, String>
+	where
+		T: Debug,
+		I: IntoIterator<Item = T>,
+	{
+		let word = self.word()?;
+
+		find_compact(word, candidates).ok_or_else(|| format!("`{word}` is not a valid value"))
+	}
+
+	/// Skips the next attribute if it is the word `name`, returning whether it was.
+	pub fn flag(&mut self, name: &str) -> bool {
+		let is_match = matches!(self.peek(), Some(Attribute::Word(word)) if *word == name);
+
+		if is_match {
+			self.attributes.next();
+		}
+
+		is_match
+	}
+
+	fn signed(&mut self) -> Result<bool, String> {
+		match self.word()? {
+			"signed" => Ok(true),
+			"unsigned" => Ok(false),
+			word => Err(format!("expected `signed` or `unsigned`, found `{word}`")),
+		}
+	}
+
+	fn list_of<T, H>(&mut self, mut handler: H) -> Result<Vec<T>, String>
+	where
+		H: FnMut(&mut Self) -> Result<T, String>,
+	{
+		let mut list = self.list()?;
+		let mut result = Vec::new();
+
+		while !list.is_empty() {
+			result.push(handler(&mut list)?);
+		}
+
+		Ok(result)
+	}
+
+	/// # Errors
+	///
+	/// Returns an error if any attributes are left.
+	pub fn finish(&self) -> Result<(), String> {
+		if self.is_empty() {
+			Ok(())
+		} else {
+			Err("too many attributes".into())
+		}
+	}
+}
+
+/// Builds a host node from its attributes and links.
+pub type HostParser = fn(&mut Reader<'_, '_>, Vec<Link>) -> Result<Box<dyn Host>, String>;
+
+/// The error of a textual graph that could not be read.
+#[derive(Debug)]
+pub struct ParseError {
+	pub line: usize,
+	pub message: String,
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+		write!(f, "line {}: {}", self.line, self.message)
+	}
+}
+
+impl core::error::Error for ParseError {}
+
+type Tokens<'source> = Peekable<IntoIter<Token<'source>>>;
+
+type Builder = fn(&str, &mut Reader<'_, '_>, &[Link]) -> Result<Option<Node>, String>;
+
+fn parse_link(word: &str) -> Option<Link> {
+	let (id, port) = word.split_once(':')?;
+
+	Some(Link(id.parse().ok()?, port.parse().ok()?))
+}
+
+fn parse_attribute<'source>(tokens: &mut Tokens<'source>) -> Result<Attribute<'source>, String> {
+	let attribute = match tokens.next() {
+		Some(Token::Word(word)) => Attribute::Word(word),
+		Some(Token::String(content)) => Attribute::String(content),
+		Some(Token::OpenList) => {
+			let mut list = Vec::new();
+
+			if tokens
+				.next_if(|token| matches!(token, Token::CloseList))
+				.is_none()
+			{
+				loop {
+					list.push(parse_attribute(tokens)?);
+
+					match tokens.next() {
+						Some(Token::Comma) => {}
+						Some(Token::CloseList) => break,
+						_ => return Err("expected `,` or `]`".into()),
+					}
+				}
+			}
+
+			Attribute::List(list)
+		}
+		_ => return Err("expected an attribute".into()),
+	};
+
+	Ok(attribute)
+}
+
+fn parse_links(tokens: &mut Tokens<'_>) -> Result<Vec<Link>, String> {
+	let mut links = Vec::new();
+
+	if tokens
+		.next_if(|token| matches!(token, Token::Open))
+		.is_none()
+		|| tokens
+			.next_if(|token| matches!(token, Token::Close))
+			.is_some()
+	{
+		return Ok(links);
+	}
+
+	loop {
+		let Some(Token::Word(word)) = tokens.next() else {
+			return Err("expected a link".into());
+		};
+
+		links.push(parse_link(word).ok_or_else(|| format!("`{word}` is not a link"))?);
+
+		match tokens.next() {
+			Some(Token::Comma) => {}
+			Some(Token::Close) => return Ok(links),
+			_ => return Err("expected `,` or `)`".into()),
+		}
+	}
+}
+
+fn fixed<const N: usize>(links: &[Link]) -> Result<[Link; N], String> {
+	links
+		.try_into()
+		.map_err(|_| format!("expected {N} links, found {}", links.len()))
+}
+
+fn split_first(links: &[Link]) -> Result<(Link, Vec<Link>), String> {
+	let (first, rest) = links.split_first().ok_or("expected at least 1 link")?;
+
+	Ok((*first, rest.to_vec()))
+}
+
+// Exports of linked modules are written as `["instance", "name"]`.
+fn read_export_name(reader: &mut Reader<'_, '_>) -> Result<(Option<Arc<str>>, Arc<str>), String> {
+	if let Some(Attribute::List(_)) = reader.peek() {
+		let mut list = reader.list()?;
+		let instance = list.text()?;
+		let identifier = list.text()?;
+
+		list.finish()?;
+
+		Ok((Some(instance), identifier))
+	} else {
+		reader.text().map(|identifier| (None, identifier))
+	}
+}
+
+fn parse_f32(word: &str) -> Option<f32> {
+	match word.strip_prefix("0x") {
+		Some(bits) => u32::from_str_radix(bits, 16).ok().map(f32::from_bits),
+		None => word.parse().ok(),
+	}
+}
+
+fn parse_f64(word: &str) -> Option<f64> {
+	match word.strip_prefix("0x") {
+		Some(bits) => u64::from_str_radix(bits, 16).ok().map(f64::from_bits),
+		None => word.parse().ok(),
+	}
+}
+
+fn parse_v128(word: &str) -> Option<u128> {
+	let digits = word.strip_prefix("0x")?;
+
+	u128::from_str_radix(digits, 16).ok()
+}
+
+fn build_nested(
+	name: &str,
+	reader: &mut Reader<'_, '_>,
+	links: &[Link],
+) -> Result<Option<Node>, String> {
+	let node = match name {
+		"lambda_in" => {
+			let arguments = reader.list_of(|list| list.value(words::VALUE_TYPES))?;
+
+			if reader.word()? != "->" {
+				return Err("expected `->`".into());
+			}
+
+			let results = reader.list_of(|list| list.value(words::VALUE_TYPES))?;
+			let names = reader.list_of(Reader::name)?;
+
+			Node::LambdaIn(LambdaIn {
+				output: u32::MAX,
+				r#type: Box::new(FunctionType {
+					arguments: arguments.into_iter().collect(),
+					results: results.into_iter().collect(),
+				}),
+				dependencies: links.to_vec(),
+				names,
+			})
+		}
+		"lambda_out" => Node::LambdaOut(LambdaOut {
+			input: reader.id()?,
+			results: links.to_vec(),
+		}),
+		"region_in" => {
+			let [] = fixed(links)?;
+
+			Node::RegionIn(RegionIn {
+				input: reader.id()?,
+				output: u32::MAX,
+			})
+		}
+		"region_out" => Node::RegionOut(RegionOut {
+			input: reader.id()?,
+			output: u32::MAX,
+			results: links.to_vec(),
+		}),
+		"gamma_in" => {
+			let (condition, arguments) = split_first(links)?;
+
+			Node::GammaIn(GammaIn {
+				output: u32::MAX,
+				condition,
+				arguments,
+			})
+		}
+		"gamma_out" => {
+			let [] = fixed(links)?;
+
+			Node::GammaOut(GammaOut {
+				input: reader.id()?,
+				regions: reader.list_of(Reader::id)?,
+			})
+		}
+		"theta_in" => Node::ThetaIn(ThetaIn {
+			output: u32::MAX,
+			arguments: links.to_vec(),
+		}),
+		"theta_out" => {
+			let (condition, results) = split_first(links)?;
+
+			Node::ThetaOut(ThetaOut {
+				input: reader.id()?,
+				condition,
+				results,
+			})
+		}
+		"omega_in" => {
+			let [] = fixed(links)?;
+
+			Node::OmegaIn(OmegaIn { output: u32::MAX })
+		}
+		"omega_out" => {
+			let input = reader.id()?;
+			let identifiers = reader.list_of(read_export_name)?;
+			let (state, references) = split_first(links)?;
+
+			if identifiers.len() != references.len() {
+				return Err("expected a link for every export".into());
+			}
+
+			let exports = identifiers
+				.into_iter()
+				.zip(references)
+				.map(|((instance, identifier), reference)| Export {
+					instance,
+					identifier,
+					reference,
+				})
+				.collect();
+
+			Node::OmegaOut(OmegaOut {
+				input,
+				state,
+				exports,
+			})
+		}
+		"import" => {
+			let [environment] = fixed(links)?;
+
+			Node::Import(Box::new(Import {
+				environment,
+				namespace: reader.text()?,
+				identifier: reader.text()?,
+			}))
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(node))
+}
+
+fn build_integer(
+	name: &str,
+	reader: &mut Reader<'_, '_>,
+	links: &[Link],
+) -> Result<Option<Node>, String> {
+	let node = match name {
+		"i32" => {
+			let [] = fixed(links)?;
+
+			Node::I32(reader.number()?)
+		}
+		"i64" => {
+			let [] = fixed(links)?;
+
+			Node::I64(reader.number()?)
+		}
+		"integer_unary" => {
+			let [source] = fixed(links)?;
+
+			Node::IntegerUnaryOperation(IntegerUnaryOperation {
+				source,
+				r#type: reader.value(words::INTEGER_TYPES)?,
+				operator: reader.value(words::INTEGER_UNARY_OPERATORS)?,
+			})
+		}
+		"integer_binary" => {
+			let [lhs, rhs] = fixed(links)?;
+
+			Node::IntegerBinaryOperation(IntegerBinaryOperation {
+				lhs,
+				rhs,
+				r#type: reader.value(words::INTEGER_TYPES)?,
+				operator: reader.value(words::INTEGER_BINARY_OPERATORS)?,
+			})
+		}
+		"integer_compare" => {
+			let [lhs, rhs] = fixed(links)?;
+
+			Node::IntegerCompareOperation(IntegerCompareOperation {
+				lhs,
+				rhs,
+				r#type: reader.value(words::INTEGER_TYPES)?,
+				operator: reader.value(words::INTEGER_COMPARE_OPERATORS)?,
+			})
+		}
+		"integer_narrow" => {
+			let [source] = fixed(links)?;
+
+			Node::IntegerNarrow(IntegerNarrow { source })
+		}
+		"integer_widen" => {
+			let [source] = fixed(links)?;
+
+			Node::IntegerWiden(IntegerWiden { source })
+		}
+		"integer_extend" => {
+			let [source] = fixed(links)?;
+
+			Node::IntegerExtend(IntegerExtend {
+				source,
+				r#type: reader.value(words::EXTEND_TYPES)?,
+			})
+		}
+		"integer_convert_to_number" => {
+			let [source] = fixed(links)?;
+
+			Node::IntegerConvertToNumber(IntegerConvertToNumber {
+				source,
+				from: reader.value(words::INTEGER_TYPES)?,
+				to: reader.value(words::NUMBER_TYPES)?,
+				signed: reader.signed()?,
+			})
+		}
+		"integer_transmute_to_number" => {
+			let [source] = fixed(links)?;
+
+			Node::IntegerTransmuteToNumber(IntegerTransmuteToNumber {
+				source,
+				from: reader.value(words::INTEGER_TYPES)?,
+			})
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(node))
+}
+
+fn build_number(
+	name: &str,
+	reader: &mut Reader<'_, '_>,
+	links: &[Link],
+) -> Result<Option<Node>, String> {
+	let node = match name {
+		"f32" => {
+			let [] = fixed(links)?;
+			let word = reader.word()?;
+
+			Node::F32(parse_f32(word).ok_or_else(|| format!("`{word}` is not a valid f32"))?)
+		}
+		"f64" => {
+			let [] = fixed(links)?;
+			let word = reader.word()?;
+
+			Node::F64(parse_f64(word).ok_or_else(|| format!("`{word}` is not a valid f64"))?)
+		}
+		"number_unary" => {
+			let [source] = fixed(links)?;
+
+			Node::NumberUnaryOperation(NumberUnaryOperation {
+				source,
+				r#type: reader.value(words::NUMBER_TYPES)?,
+				operator: reader.value(words::NUMBER_UNARY_OPERATORS)?,
+			})
+		}
+		"number_binary" => {
+			let [lhs, rhs] = fixed(links)?;
+
+			Node::NumberBinaryOperation(NumberBinaryOperation {
+				lhs,
+				rhs,
+				r#type: reader.value(words::NUMBER_TYPES)?,
+				operator: reader.value(words::NUMBER_BINARY_OPERATORS)?,
+			})
+		}
+		"number_compare" => {
+			let [lhs, rhs] = fixed(links)?;
+
+			Node::NumberCompareOperation(NumberCompareOperation {
+				lhs,
+				rhs,
+				r#type: reader.value(words::NUMBER_TYPES)?,
+				operator: reader.value(words::NUMBER_COMPARE_OPERATORS)?,
+			})
+		}
+		"number_narrow" => {
+			let [source] = fixed(links)?;
+
+			Node::NumberNarrow(NumberNarrow { source })
+		}
+		"number_widen" => {
+			let [source] = fixed(links)?;
+
+			Node::NumberWiden(NumberWiden { source })
+		}
+		"number_truncate_to_integer" => {
+			let [source] = fixed(links)?;
+
+			Node::NumberTruncateToInteger(NumberTruncateToInteger {
+				source,
+				from: reader.value(words::NUMBER_TYPES)?,
+				to: reader.value(words::INTEGER_TYPES)?,
+				signed: reader.signed()?,
+				saturate: reader.flag("saturate"),
+			})
+		}
+		"number_transmute_to_integer" => {
+			let [source] = fixed(links)?;
+
+			Node::NumberTransmuteToInteger(NumberTransmuteToInteger {
+				source,
+				from: reader.value(words::NUMBER_TYPES)?,
+			})
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(node))
+}
+
+fn build_v128(
+	name: &str,
+	reader: &mut Reader<'_, '_>,
+	links: &[Link],
+) -> Result<Option<Node>, String> {
+	let node = match name {
+		"v128" => {
+			let [] = fixed(links)?;
+			let word = reader.word()?;
+
+			Node::V128(parse_v128(word).ok_or_else(|| format!("`{word}` is not a valid v128"))?)
+		}
+		"v128_unary" => {
+			let [source] = fixed(links)?;
+
+			Node::V128UnaryOperation(V128UnaryOperation {
+				source,
+				operator: reader.value(words::v128_unary_operators())?,
+			})
+		}
+		"v128_binary" => {
+			let [lhs, rhs] = fixed(links)?;
+
+			Node::V128BinaryOperation(V128BinaryOperation {
+				lhs,
+				rhs,
+				operator: reader.value(words::v128_binary_operators())?,
+			})
+		}
+		"v128_reduce" => {
+			let [source] = fixed(links)?;
+
+			Node::V128ReduceOperation(V128ReduceOperation {
+				source,
+				operator: reader.value(words::v128_reduce_operators())?,
+			})
+		}
+		"v128_splat" => {
+			let [source] = fixed(links)?;
+
+			Node::V128Splat(V128Splat {
+				source,
+				r#type: reader.value(words::LANE_TYPES)?,
+			})
+		}
+		"v128_extract_lane" => {
+			let [source] = fixed(links)?;
+
+			Node::V128ExtractLane(V128ExtractLane {
+				source,
+				r#type: reader.value(words::LANE_TYPES)?,
+				lane: reader.number()?,
+				signed: reader.signed()?,
+			})
+		}
+		"v128_replace_lane" => {
+			let [lhs, rhs] = fixed(links)?;
+
+			Node::V128ReplaceLane(V128ReplaceLane {
+				lhs,
+				rhs,
+				r#type: reader.value(words::LANE_TYPES)?,
+				lane: reader.number()?,
+			})
+		}
+		"v128_shuffle" => {
+			let [lhs, rhs] = fixed(links)?;
+			let lanes: Vec<u8> = reader.list_of(Reader::number)?;
+
+			Node::V128Shuffle(V128Shuffle {
+				lhs,
+				rhs,
+				lanes: lanes.try_into().map_err(|_| "expected 16 lanes")?,
+			})
+		}
+		"v128_bit_select" => {
+			let [lhs, rhs, condition] = fixed(links)?;
+
+			Node::V128BitSelect(V128BitSelect {
+				lhs,
+				rhs,
+				condition,
+			})
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(node))
+}
+
+fn build_table(
+	name: &str,
+	reader: &mut Reader<'_, '_>,
+	links: &[Link],
+) -> Result<Option<Node>, String> {
+	let node = match name {
+		"table_new" => {
+			let [initializer] = fixed(links)?;
+
+			Node::TableNew(TableNew {
+				initializer,
+				minimum: reader.number()?,
+				maximum: reader.number()?,
+			})
+		}
+		"table_get" => {
+			let [reference, offset] = fixed(links)?;
+
+			Node::TableGet(TableGet {
+				source: Location { reference, offset },
+			})
+		}
+		"table_set" => {
+			let [reference, offset, source] = fixed(links)?;
+
+			Node::TableSet(TableSet {
+				destination: Location { reference, offset },
+				source,
+			})
+		}
+		"table_size" => {
+			let [source] = fixed(links)?;
+
+			Node::TableSize(TableSize { source })
+		}
+		"table_grow" => {
+			let [destination, initializer, size] = fixed(links)?;
+
+			Node::TableGrow(TableGrow {
+				destination,
+				initializer,
+				size,
+			})
+		}
+		"table_fill" => {
+			let [reference, offset, source, size] = fixed(links)?;
+
+			Node::TableFill(TableFill {
+				destination: Location { reference, offset },
+				source,
+				size,
+			})
+		}
+		"table_copy" => {
+			let [destination, offset, source, source_offset, size] = fixed(links)?;
+
+			Node::TableCopy(TableCopy {
+				destination: Location {
+					reference: destination,
+					offset,
+				},
+				source: Location {
+					reference: source,
+					offset: source_offset,
+				},
+				size,
+			})
+		}
+		"table_init" => {
+			let [destination, offset, source, source_offset, size] = fixed(links)?;
+
+			Node::TableInit(TableInit {
+				destination: Location {
+					reference: destination,
+					offset,
+				},
+				source: Location {
+					reference: source,
+					offset: source_offset,
+				},
+				size,
+			})
+		}
+		"elements_new" => Node::ElementsNew(ElementsNew {
+			content: links.to_vec(),
+		}),
+		"elements_drop" => {
+			let [source] = fixed(links)?;
+
+			Node::ElementsDrop(ElementsDrop { source })
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(node))
+}
+
+fn build_memory(
+	name: &str,
+	reader: &mut Reader<'_, '_>,
+	links: &[Link],
+) -> Result<Option<Node>, String> {
+	let node = match name {
+		"memory_new" => {
+			let [] = fixed(links)?;
+
+			Node::MemoryNew(MemoryNew {
+				minimum: reader.number()?,
+				maximum: reader.number()?,
+			})
+		}
+		"memory_load" => {
+			let [reference, offset] = fixed(links)?;
+
+			Node::MemoryLoad(MemoryLoad {
+				source: Location { reference, offset },
+				r#type: reader.value(words::LOAD_TYPES)?,
+			})
+		}
+		"memory_store" => {
+			let [reference, offset, source] = fixed(links)?;
+
+			Node::MemoryStore(MemoryStore {
+				destination: Location { reference, offset },
+				source,
+				r#type: reader.value(words::STORE_TYPES)?,
+			})
+		}
+		"memory_size" => {
+			let [source] = fixed(links)?;
+
+			Node::MemorySize(MemorySize { source })
+		}
+		"memory_grow" => {
+			let [destination, size] = fixed(links)?;
+
+			Node::MemoryGrow(MemoryGrow { destination, size })
+		}
+		"memory_fill" => {
+			let [reference, offset, byte, size] = fixed(links)?;
+
+			Node::MemoryFill(MemoryFill {
+				destination: Location { reference, offset },
+				byte,
+				size,
+			})
+		}
+		"memory_copy" => {
+			let [destination, offset, source, source_offset, size] = fixed(links)?;
+
+			Node::MemoryCopy(MemoryCopy {
+				destination: Location {
+					reference: destination,
+					offset,
+				},
+				source: Location {
+					reference: source,
+					offset: source_offset,
+				},
+				size,
+			})
+		}
+		"memory_init" => {
+			let [destination, offset, source, source_offset, size] = fixed(links)?;
+
+			Node::MemoryInit(MemoryInit {
+				destination: Location {
+					reference: destination,
+					offset,
+				},
+				source: Location {
+					reference: source,
+					offset: source_offset,
+				},
+				size,
+			})
+		}
+		"data_new" => {
+			let [] = fixed(links)?;
+
+			Node::DataNew(DataNew {
+				content: reader.string()?.into(),
+			})
+		}
+		"data_drop" => {
+			let [source] = fixed(links)?;
+
+			Node::DataDrop(DataDrop { source })
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(node))
+}
+
+fn build_other(
+	name: &str,
+	reader: &mut Reader<'_, '_>,
+	links: &[Link],
+) -> Result<Option<Node>, String> {
+	let node = match name {
+		"trap" => {
+			let [] = fixed(links)?;
+
+			Node::Trap
+		}
+		"null" => {
+			let [] = fixed(links)?;
+
+			Node::Null
+		}
+		"tag_new" => {
+			let [] = fixed(links)?;
+
+			Node::TagNew
+		}
+		"identity" => {
+			let [source] = fixed(links)?;
+
+			Node::Identity(Identity { source })
+		}
+		"call" => {
+			let (function, arguments) = split_first(links)?;
+
+			Node::Call(Call {
+				function,
+				arguments,
+				results: reader.number()?,
+				states: reader.number()?,
+				tail: reader.flag("tail"),
+				catch: reader.flag("catch"),
+			})
+		}
+		"merge" => Node::Merge(Merge {
+			states: links.to_vec(),
+		}),
+		"ref_is_null" => {
+			let [source] = fixed(links)?;
+
+			Node::RefIsNull(RefIsNull { source })
+		}
+		"function_new" => {
+			let [source] = fixed(links)?;
+
+			Node::FunctionNew(FunctionNew {
+				source,
+				signature: reader.text()?,
+			})
+		}
+		"function_check" => {
+			let [source] = fixed(links)?;
+
+			Node::FunctionCheck(FunctionCheck {
+				source,
+				signature: reader.text()?,
+			})
+		}
+		"global_new" => {
+			let [initializer] = fixed(links)?;
+
+			Node::GlobalNew(GlobalNew {
+				initializer,
+				name: reader.name()?,
+			})
+		}
+		"global_get" => {
+			let [source] = fixed(links)?;
+
+			Node::GlobalGet(GlobalGet { source })
+		}
+		"global_set" => {
+			let [destination, source] = fixed(links)?;
+
+			Node::GlobalSet(GlobalSet {
+				destination,
+				source,
+			})
+		}
+		"throw" => {
+			let (source, states) = split_first(links)?;
+
+			Node::Throw(Throw { source, states })
+		}
+		"exception_new" => {
+			let (tag, arguments) = split_first(links)?;
+
+			Node::ExceptionNew(ExceptionNew { tag, arguments })
+		}
+		"exception_test" => {
+			let [source, tag] = fixed(links)?;
+
+			Node::ExceptionTest(ExceptionTest { source, tag })
+		}
+		"exception_get" => {
+			let [source] = fixed(links)?;
+
+			Node::ExceptionGet(ExceptionGet {
+				source,
+				index: reader.number()?,
+			})
+		}
+
+		_ => return Ok(None),
+	};
+
+	Ok(Some(node))
+}
+
+/// Reads graphs written in the textual format of [`Text`], along with any
+/// host nodes that were registered.
+///
+/// [`Text`]: super::Text
+pub struct Syntax {
+	hosts: Vec<(&'static str, HostParser)>,
+}
+
+impl Syntax {
+	const BUILDERS: [Builder; 7] = [
+		build_nested,
+		build_integer,
+		build_number,
+		build_v128,
+		build_table,
+		build_memory,
+		build_other,
+	];
+
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { hosts: Vec::new() }
+	}
+
+	/// Adds the syntax for host nodes named `identifier`, replacing any
+	/// that was there before.
+	pub fn add(&mut self, identifier: &'static str, parser: HostParser) {
+		self.hosts.retain(|&(other, _)| other != identifier);
+		self.hosts.push((identifier, parser));
+	}
+
+	fn find(&self, identifier: &str) -> Option<HostParser> {
+		self.hosts
+			.iter()
+			.find(|&&(other, _)| other == identifier)
+			.map(|&(_, parser)| parser)
+	}
+
+	fn build(
+		&self,
+		name: &str,
+		reader: &mut Reader<'_, '_>,
+		links: Vec<Link>,
+	) -> Result<Node, String> {
+		for builder in Self::BUILDERS {
+			if let Some(node) = builder(name, reader, &links)? {
+				return Ok(node);
+			}
+		}
+
+		let parser = self
+			.find(name)
+			.ok_or_else(|| format!("`{name}` is not a known node"))?;
+
+		parser(reader, links).map(Node::Host)
+	}
+
+	fn parse_node(&self, mut tokens: Tokens<'_>, expected: usize) -> Result<Node, String> {
+		let Some(Token::Word(id)) = tokens.next() else {
+			return Err("expected a node id".into());
+		};
+
+		if id.parse::<usize>().ok() != Some(expected) {
+			return Err(format!("expected node {expected}, found `{id}`"));
+		}
+
+		let (Some(Token::Equals), Some(Token::Word(name))) = (tokens.next(), tokens.next()) else {
+			return Err("expected `=` and a node name".into());
+		};
+
+		let mut attributes = Vec::new();
+
+		while tokens
+			.peek()
+			.is_some_and(|token| !matches!(token, Token::Open))
+		{
+			attributes.push(parse_attribute(&mut tokens)?);
+		}
+
+		let links = parse_links(&mut tokens)?;
+
+		if tokens.next().is_some() {
+			return Err("expected the end of the line".into());
+		}
+
+		let mut reader = Reader::new(&attributes);
+		let node = self.build(name, &mut reader, links)?;
+
+		reader.finish()?;
+
+		Ok(node)
+	}
+
+	fn set_output(graph: &mut DataFlowGraph, id: u32, output: u32) -> Result<(), String> {
+		if usize::try_from(id).unwrap() >= graph.len() {
+			return Err(format!("node {id} does not exist"));
+		}
+
+		match graph.get_mut(id) {
+			Node::LambdaIn(LambdaIn { output: old, .. })
+			| Node::RegionIn(RegionIn { output: old, .. })
+			| Node::RegionOut(RegionOut { output: old, .. })
+			| Node::GammaIn(GammaIn { output: old, .. })
+			| Node::ThetaIn(ThetaIn { output: old, .. })
+			| Node::OmegaIn(OmegaIn { output: old }) => *old = output,
+
+			_ => return Err(format!("node {id} cannot be an input")),
+		}
+
+		Ok(())
+	}
+
+	// The `output` of every `In` node is left out of the text, so we
+	// fill it in from the `Out` nodes that refer to them.
+	fn set_outputs(graph: &mut DataFlowGraph, lines: &[usize]) -> Result<(), ParseError> {
+		let mut pairs = Vec::new();
+
+		for (output, node) in (0..).zip(graph.nodes()) {
+			match node {
+				Node::LambdaOut(LambdaOut { input, .. })
+				| Node::RegionOut(RegionOut { input, .. })
+				| Node::ThetaOut(ThetaOut { input, .. })
+				| Node::OmegaOut(OmegaOut { input, .. }) => pairs.push((*input, output)),
+				Node::GammaOut(GammaOut { input, regions }) => {
+					pairs.push((*input, output));
+					pairs.extend(regions.iter().map(|&region| (region, output)));
+				}
+
+				_ => {}
+			}
+		}
+
+		for (input, output) in pairs {
+			Self::set_output(graph, input, output).map_err(|message| ParseError {
+				line: lines[usize::try_from(output).unwrap()],
+				message,
+			})?;
+		}
+
+		Ok(())
+	}
+
+	/// Reads a graph from its textual format.
+	///
+	/// # Errors
+	///
+	/// Returns an error naming the line that could not be read.
+	pub fn parse(&self, source: &str) -> Result<DataFlowGraph, ParseError> {
+		let mut lexer = Lexer::new(source);
+		let mut graph = DataFlowGraph::new();
+		let mut lines = Vec::new();
+
+		loop {
+			let line = lexer.line();
+			let error = |message| ParseError { line, message };
+			let mut tokens = Vec::new();
+
+			let is_end = loop {
+				match lexer.next_token().map_err(error)? {
+					Some(Token::Newline) => break false,
+					Some(token) => tokens.push(token),
+					None => break true,
+				}
+			};
+
+			if !tokens.is_empty() {
+				let node = self
+					.parse_node(tokens.into_iter().peekable(), graph.len())
+					.map_err(error)?;
+
+				graph.add_node(node);
+				lines.push(line);
+			}
+
+			if is_end {
+				break;
+			}
+		}
+
+		Self::set_outputs(&mut graph, &lines)?;
+
+		Ok(graph)
+	}
+}
+
+impl Default for Syntax {
+	fn default() -> Self {
+		Self::new()
+	}
+}
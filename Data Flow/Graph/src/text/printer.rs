@@ -0,0 +1,435 @@
+use core::fmt::{Debug, Display, Formatter, Result};
+
+use alloc::{sync::Arc, vec::Vec};
+
+use crate::{
+	DataFlowGraph, Link,
+	mvp::{
+		Call, IntegerConvertToNumber, NumberTruncateToInteger, V128ExtractLane, V128ReplaceLane,
+	},
+	nested::{FunctionType, GammaOut, Import, LambdaIn, OmegaOut},
+	node::Node,
+};
+
+use super::write_compact;
+
+/// Writes a graph in its textual format, which [`Syntax::parse`] can read back.
+///
+/// Every node is written on its own line as its id, an `=`, its name,
+/// any attributes and finally its links in parentheses.
+///
+/// ```text
+/// 0 = omega_in
+/// 1 = lambda_in [I32] -> [I32] []
+/// 2 = i32 1
+/// 3 = integer_binary I32 Add (1:0, 2:0)
+/// 4 = lambda_out @1 (3:0, 1:1)
+/// 5 = omega_out @0 ["increment"] (0:1, 4:0)
+/// ```
+///
+/// Links are written as `id:port` and plain node references as `@id`.
+/// The `output` of every `In` node is implied by the `Out` node that
+/// refers to it, so it is left out.
+///
+/// [`Syntax::parse`]: super::Syntax::parse
+pub struct Text<'inner> {
+	inner: &'inner DataFlowGraph,
+}
+
+impl<'inner> Text<'inner> {
+	#[must_use]
+	pub const fn new(inner: &'inner DataFlowGraph) -> Self {
+		Self { inner }
+	}
+
+	fn write_string(content: &[u8], f: &mut Formatter<'_>) -> Result {
+		write!(f, "\"{}\"", content.escape_ascii())
+	}
+
+	fn write_name(name: Option<&Arc<str>>, f: &mut Formatter<'_>) -> Result {
+		match name {
+			Some(name) => Self::write_string(name.as_bytes(), f),
+			None => f.write_str("_"),
+		}
+	}
+
+	fn fmt_id(id: u32, f: &mut Formatter<'_>) -> Result {
+		write!(f, " @{id}")
+	}
+
+	fn fmt_value<T: Debug>(value: &T, f: &mut Formatter<'_>) -> Result {
+		f.write_str(" ")?;
+
+		write_compact(f, value)
+	}
+
+	fn fmt_string(content: &[u8], f: &mut Formatter<'_>) -> Result {
+		f.write_str(" ")?;
+
+		Self::write_string(content, f)
+	}
+
+	fn fmt_name(name: Option<&Arc<str>>, f: &mut Formatter<'_>) -> Result {
+		f.write_str(" ")?;
+
+		Self::write_name(name, f)
+	}
+
+	fn fmt_signed(signed: bool, f: &mut Formatter<'_>) -> Result {
+		f.write_str(if signed { " signed" } else { " unsigned" })
+	}
+
+	fn fmt_list<T, H>(list: &[T], f: &mut Formatter<'_>, mut handler: H) -> Result
+	where
+		H: FnMut(&T, &mut Formatter<'_>) -> Result,
+	{
+		f.write_str(" [")?;
+
+		for (index, item) in list.iter().enumerate() {
+			if index != 0 {
+				f.write_str(", ")?;
+			}
+
+			handler(item, f)?;
+		}
+
+		f.write_str("]")
+	}
+
+	fn fmt_function_type(r#type: &FunctionType, f: &mut Formatter<'_>) -> Result {
+		let arguments: Vec<_> = r#type.arguments.iter().copied().collect();
+		let results: Vec<_> = r#type.results.iter().copied().collect();
+
+		Self::fmt_list(&arguments, f, |r#type, f| write_compact(f, r#type))?;
+
+		f.write_str(" ->")?;
+
+		Self::fmt_list(&results, f, |r#type, f| write_compact(f, r#type))
+	}
+
+	fn fmt_lambda_in(lambda_in: &LambdaIn, f: &mut Formatter<'_>) -> Result {
+		f.write_str("lambda_in")?;
+
+		Self::fmt_function_type(&lambda_in.r#type, f)?;
+		Self::fmt_list(&lambda_in.names, f, |name, f| {
+			Self::write_name(name.as_ref(), f)
+		})
+	}
+
+	fn fmt_gamma_out(gamma_out: &GammaOut, f: &mut Formatter<'_>) -> Result {
+		f.write_str("gamma_out")?;
+
+		Self::fmt_id(gamma_out.input, f)?;
+		Self::fmt_list(&gamma_out.regions, f, |region, f| write!(f, "@{region}"))
+	}
+
+	fn fmt_omega_out(omega_out: &OmegaOut, f: &mut Formatter<'_>) -> Result {
+		f.write_str("omega_out")?;
+
+		Self::fmt_id(omega_out.input, f)?;
+		Self::fmt_list(&omega_out.exports, f, |export, f| {
+			let Some(instance) = &export.instance else {
+				return Self::write_string(export.identifier.as_bytes(), f);
+			};
+
+			let instance = instance.as_bytes().escape_ascii();
+			let identifier = export.identifier.as_bytes().escape_ascii();
+
+			write!(f, "[\"{instance}\", \"{identifier}\"]")
+		})
+	}
+
+	fn fmt_import(import: &Import, f: &mut Formatter<'_>) -> Result {
+		f.write_str("import")?;
+
+		Self::fmt_string(import.namespace.as_bytes(), f)?;
+		Self::fmt_string(import.identifier.as_bytes(), f)
+	}
+
+	fn fmt_f32(value: f32, f: &mut Formatter<'_>) -> Result {
+		// Not a number values are written by their bits to keep their payloads.
+		if value.is_nan() {
+			write!(f, "f32 {:#010x}", value.to_bits())
+		} else {
+			write!(f, "f32 {value:?}")
+		}
+	}
+
+	fn fmt_f64(value: f64, f: &mut Formatter<'_>) -> Result {
+		if value.is_nan() {
+			write!(f, "f64 {:#018x}", value.to_bits())
+		} else {
+			write!(f, "f64 {value:?}")
+		}
+	}
+
+	fn fmt_call(call: &Call, f: &mut Formatter<'_>) -> Result {
+		write!(f, "call {} {}", call.results, call.states)?;
+
+		if call.tail {
+			f.write_str(" tail")?;
+		}
+
+		if call.catch {
+			f.write_str(" catch")?;
+		}
+
+		Ok(())
+	}
+
+	fn fmt_integer_convert_to_number(
+		node: IntegerConvertToNumber,
+		f: &mut Formatter<'_>,
+	) -> Result {
+		f.write_str("integer_convert_to_number")?;
+
+		Self::fmt_value(&node.from, f)?;
+		Self::fmt_value(&node.to, f)?;
+		Self::fmt_signed(node.signed, f)
+	}
+
+	fn fmt_number_truncate_to_integer(
+		node: NumberTruncateToInteger,
+		f: &mut Formatter<'_>,
+	) -> Result {
+		f.write_str("number_truncate_to_integer")?;
+
+		Self::fmt_value(&node.from, f)?;
+		Self::fmt_value(&node.to, f)?;
+		Self::fmt_signed(node.signed, f)?;
+
+		if node.saturate {
+			f.write_str(" saturate")?;
+		}
+
+		Ok(())
+	}
+
+	fn fmt_v128_extract_lane(node: V128ExtractLane, f: &mut Formatter<'_>) -> Result {
+		f.write_str("v128_extract_lane")?;
+
+		Self::fmt_value(&node.r#type, f)?;
+
+		write!(f, " {}", node.lane)?;
+
+		Self::fmt_signed(node.signed, f)
+	}
+
+	fn fmt_v128_replace_lane(node: V128ReplaceLane, f: &mut Formatter<'_>) -> Result {
+		f.write_str("v128_replace_lane")?;
+
+		Self::fmt_value(&node.r#type, f)?;
+
+		write!(f, " {}", node.lane)
+	}
+
+	fn fmt_named<T: Debug>(name: &str, value: &T, f: &mut Formatter<'_>) -> Result {
+		f.write_str(name)?;
+
+		Self::fmt_value(value, f)
+	}
+
+	fn fmt_typed<T: Debug, U: Debug>(
+		name: &str,
+		r#type: &T,
+		operator: &U,
+		f: &mut Formatter<'_>,
+	) -> Result {
+		f.write_str(name)?;
+
+		Self::fmt_value(r#type, f)?;
+		Self::fmt_value(operator, f)
+	}
+
+	fn try_node_name(node: &Node) -> Option<&'static str> {
+		let name = match node {
+			Node::LambdaOut(_) => "lambda_out",
+			Node::RegionIn(_) => "region_in",
+			Node::RegionOut(_) => "region_out",
+			Node::GammaIn(_) => "gamma_in",
+			Node::ThetaIn(_) => "theta_in",
+			Node::ThetaOut(_) => "theta_out",
+			Node::OmegaIn(_) => "omega_in",
+
+			Node::Trap => "trap",
+			Node::Null => "null",
+			Node::Identity(_) => "identity",
+			Node::Merge(_) => "merge",
+			Node::RefIsNull(_) => "ref_is_null",
+			Node::IntegerNarrow(_) => "integer_narrow",
+			Node::IntegerWiden(_) => "integer_widen",
+			Node::NumberNarrow(_) => "number_narrow",
+			Node::NumberWiden(_) => "number_widen",
+			Node::V128BitSelect(_) => "v128_bit_select",
+			Node::GlobalGet(_) => "global_get",
+			Node::GlobalSet(_) => "global_set",
+			Node::TableGet(_) => "table_get",
+			Node::TableSet(_) => "table_set",
+			Node::TableSize(_) => "table_size",
+			Node::TableGrow(_) => "table_grow",
+			Node::TableFill(_) => "table_fill",
+			Node::TableCopy(_) => "table_copy",
+			Node::TableInit(_) => "table_init",
+			Node::ElementsNew(_) => "elements_new",
+			Node::ElementsDrop(_) => "elements_drop",
+			Node::MemoryStore(_) => "memory_store",
+			Node::MemorySize(_) => "memory_size",
+			Node::MemoryGrow(_) => "memory_grow",
+			Node::MemoryFill(_) => "memory_fill",
+			Node::MemoryCopy(_) => "memory_copy",
+			Node::MemoryInit(_) => "memory_init",
+			Node::DataDrop(_) => "data_drop",
+			Node::TagNew => "tag_new",
+			Node::Throw(_) => "throw",
+			Node::ExceptionNew(_) => "exception_new",
+			Node::ExceptionTest(_) => "exception_test",
+
+			_ => return None,
+		};
+
+		Some(name)
+	}
+
+	fn fmt_node(node: &Node, f: &mut Formatter<'_>) -> Result {
+		if let Some(name) = Self::try_node_name(node) {
+			f.write_str(name)?;
+
+			return match *node {
+				Node::LambdaOut(ref lambda_out) => Self::fmt_id(lambda_out.input, f),
+				Node::RegionIn(ref region_in) => Self::fmt_id(region_in.input, f),
+				Node::RegionOut(ref region_out) => Self::fmt_id(region_out.input, f),
+				Node::ThetaOut(ref theta_out) => Self::fmt_id(theta_out.input, f),
+				Node::MemoryStore(memory_store) => Self::fmt_value(&memory_store.r#type, f),
+
+				_ => Ok(()),
+			};
+		}
+
+		match *node {
+			Node::LambdaIn(ref lambda_in) => Self::fmt_lambda_in(lambda_in, f),
+			Node::GammaOut(ref gamma_out) => Self::fmt_gamma_out(gamma_out, f),
+			Node::OmegaOut(ref omega_out) => Self::fmt_omega_out(omega_out, f),
+			Node::Import(ref import) => Self::fmt_import(import, f),
+			Node::Host(ref host) => {
+				f.write_str(host.identifier())?;
+
+				host.fmt_attributes(f)
+			}
+			Node::I32(i32) => write!(f, "i32 {i32}"),
+			Node::I64(i64) => write!(f, "i64 {i64}"),
+			Node::F32(f32) => Self::fmt_f32(f32, f),
+			Node::F64(f64) => Self::fmt_f64(f64, f),
+			Node::V128(v128) => write!(f, "v128 {v128:#034x}"),
+			Node::Call(ref call) => Self::fmt_call(call, f),
+			Node::FunctionNew(ref function_new) => {
+				f.write_str("function_new")?;
+
+				Self::fmt_string(function_new.signature.as_bytes(), f)
+			}
+			Node::FunctionCheck(ref function_check) => {
+				f.write_str("function_check")?;
+
+				Self::fmt_string(function_check.signature.as_bytes(), f)
+			}
+			Node::IntegerUnaryOperation(node) => {
+				Self::fmt_typed("integer_unary", &node.r#type, &node.operator, f)
+			}
+			Node::IntegerBinaryOperation(node) => {
+				Self::fmt_typed("integer_binary", &node.r#type, &node.operator, f)
+			}
+			Node::IntegerCompareOperation(node) => {
+				Self::fmt_typed("integer_compare", &node.r#type, &node.operator, f)
+			}
+			Node::IntegerExtend(node) => Self::fmt_named("integer_extend", &node.r#type, f),
+			Node::IntegerConvertToNumber(node) => Self::fmt_integer_convert_to_number(node, f),
+			Node::IntegerTransmuteToNumber(node) => {
+				Self::fmt_named("integer_transmute_to_number", &node.from, f)
+			}
+			Node::NumberUnaryOperation(node) => {
+				Self::fmt_typed("number_unary", &node.r#type, &node.operator, f)
+			}
+			Node::NumberBinaryOperation(node) => {
+				Self::fmt_typed("number_binary", &node.r#type, &node.operator, f)
+			}
+			Node::NumberCompareOperation(node) => {
+				Self::fmt_typed("number_compare", &node.r#type, &node.operator, f)
+			}
+			Node::NumberTruncateToInteger(node) => Self::fmt_number_truncate_to_integer(node, f),
+			Node::NumberTransmuteToInteger(node) => {
+				Self::fmt_named("number_transmute_to_integer", &node.from, f)
+			}
+			Node::V128UnaryOperation(node) => Self::fmt_named("v128_unary", &node.operator, f),
+			Node::V128BinaryOperation(node) => Self::fmt_named("v128_binary", &node.operator, f),
+			Node::V128ReduceOperation(node) => Self::fmt_named("v128_reduce", &node.operator, f),
+			Node::V128Splat(node) => Self::fmt_named("v128_splat", &node.r#type, f),
+			Node::V128ExtractLane(node) => Self::fmt_v128_extract_lane(node, f),
+			Node::V128ReplaceLane(node) => Self::fmt_v128_replace_lane(node, f),
+			Node::V128Shuffle(node) => {
+				f.write_str("v128_shuffle")?;
+
+				Self::fmt_list(&node.lanes, f, |lane, f| write!(f, "{lane}"))
+			}
+			Node::GlobalNew(ref global_new) => {
+				f.write_str("global_new")?;
+
+				Self::fmt_name(global_new.name.as_ref(), f)
+			}
+			Node::TableNew(table_new) => {
+				write!(f, "table_new {} {}", table_new.minimum, table_new.maximum)
+			}
+			Node::MemoryNew(memory_new) => {
+				write!(
+					f,
+					"memory_new {} {}",
+					memory_new.minimum, memory_new.maximum
+				)
+			}
+			Node::MemoryLoad(memory_load) => Self::fmt_named("memory_load", &memory_load.r#type, f),
+			Node::DataNew(ref data_new) => {
+				f.write_str("data_new")?;
+
+				Self::fmt_string(&data_new.content, f)
+			}
+			Node::ExceptionGet(exception_get) => write!(f, "exception_get {}", exception_get.index),
+
+			_ => unreachable!(),
+		}
+	}
+
+	fn fmt_links(links: &[Link], f: &mut Formatter<'_>) -> Result {
+		if links.is_empty() {
+			return Ok(());
+		}
+
+		f.write_str(" (")?;
+
+		for (index, Link(id, port)) in links.iter().enumerate() {
+			if index != 0 {
+				f.write_str(", ")?;
+			}
+
+			write!(f, "{id}:{port}")?;
+		}
+
+		f.write_str(")")
+	}
+}
+
+impl Display for Text<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+		let mut links = Vec::new();
+
+		self.inner.nodes().enumerate().try_for_each(|(id, node)| {
+			links.clear();
+
+			node.for_each_argument(|link| links.push(link));
+
+			write!(f, "{id} = ")?;
+
+			Self::fmt_node(node, f)?;
+			Self::fmt_links(&links, f)?;
+
+			writeln!(f)
+		})
+	}
+}
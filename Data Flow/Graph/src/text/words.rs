@@ -0,0 +1,267 @@
+use alloc::vec::Vec;
+
+use crate::{
+	mvp::{
+		ExtendType, IntegerBinaryOperator, IntegerCompareOperator, IntegerType,
+		IntegerUnaryOperator, LaneType, LoadType, NumberBinaryOperator, NumberCompareOperator,
+		NumberType, NumberUnaryOperator, StoreType, V128BinaryOperator, V128ReduceOperator,
+		V128UnaryOperator,
+	},
+	nested::ValueType,
+};
+
+// Every value of the enumerations used as attributes, which are
+// matched against words by their compact `Debug` form.
+
+pub const VALUE_TYPES: [ValueType; 6] = [
+	ValueType::I32,
+	ValueType::I64,
+	ValueType::F32,
+	ValueType::F64,
+	ValueType::V128,
+	ValueType::Reference,
+];
+
+pub const INTEGER_TYPES: [IntegerType; 2] = [IntegerType::I32, IntegerType::I64];
+
+pub const NUMBER_TYPES: [NumberType; 2] = [NumberType::F32, NumberType::F64];
+
+pub const LANE_TYPES: [LaneType; 6] = [
+	LaneType::I8,
+	LaneType::I16,
+	LaneType::I32,
+	LaneType::I64,
+	LaneType::F32,
+	LaneType::F64,
+];
+
+pub const EXTEND_TYPES: [ExtendType; 5] = [
+	ExtendType::I32_S8,
+	ExtendType::I32_S16,
+	ExtendType::I64_S8,
+	ExtendType::I64_S16,
+	ExtendType::I64_S32,
+];
+
+pub const LOAD_TYPES: [LoadType; 15] = [
+	LoadType::I32_S8,
+	LoadType::I32_U8,
+	LoadType::I32_S16,
+	LoadType::I32_U16,
+	LoadType::I32,
+	LoadType::I64_S8,
+	LoadType::I64_U8,
+	LoadType::I64_S16,
+	LoadType::I64_U16,
+	LoadType::I64_S32,
+	LoadType::I64_U32,
+	LoadType::I64,
+	LoadType::F32,
+	LoadType::F64,
+	LoadType::V128,
+];
+
+pub const STORE_TYPES: [StoreType; 10] = [
+	StoreType::I32_I8,
+	StoreType::I32_I16,
+	StoreType::I32,
+	StoreType::I64_I8,
+	StoreType::I64_I16,
+	StoreType::I64_I32,
+	StoreType::I64,
+	StoreType::F32,
+	StoreType::F64,
+	StoreType::V128,
+];
+
+pub const INTEGER_UNARY_OPERATORS: [IntegerUnaryOperator; 3] = [
+	IntegerUnaryOperator::CountOnes,
+	IntegerUnaryOperator::LeadingZeroes,
+	IntegerUnaryOperator::TrailingZeroes,
+];
+
+pub const INTEGER_BINARY_OPERATORS: [IntegerBinaryOperator; 15] = [
+	IntegerBinaryOperator::Add,
+	IntegerBinaryOperator::Subtract,
+	IntegerBinaryOperator::Multiply,
+	IntegerBinaryOperator::Divide { signed: false },
+	IntegerBinaryOperator::Divide { signed: true },
+	IntegerBinaryOperator::Remainder { signed: false },
+	IntegerBinaryOperator::Remainder { signed: true },
+	IntegerBinaryOperator::And,
+	IntegerBinaryOperator::Or,
+	IntegerBinaryOperator::ExclusiveOr,
+	IntegerBinaryOperator::ShiftLeft,
+	IntegerBinaryOperator::ShiftRight { signed: false },
+	IntegerBinaryOperator::ShiftRight { signed: true },
+	IntegerBinaryOperator::RotateLeft,
+	IntegerBinaryOperator::RotateRight,
+];
+
+pub const INTEGER_COMPARE_OPERATORS: [IntegerCompareOperator; 10] = [
+	IntegerCompareOperator::Equal,
+	IntegerCompareOperator::NotEqual,
+	IntegerCompareOperator::LessThan { signed: false },
+	IntegerCompareOperator::LessThan { signed: true },
+	IntegerCompareOperator::GreaterThan { signed: false },
+	IntegerCompareOperator::GreaterThan { signed: true },
+	IntegerCompareOperator::LessThanEqual { signed: false },
+	IntegerCompareOperator::LessThanEqual { signed: true },
+	IntegerCompareOperator::GreaterThanEqual { signed: false },
+	IntegerCompareOperator::GreaterThanEqual { signed: true },
+];
+
+pub const NUMBER_UNARY_OPERATORS: [NumberUnaryOperator; 7] = [
+	NumberUnaryOperator::Absolute,
+	NumberUnaryOperator::Negate,
+	NumberUnaryOperator::SquareRoot,
+	NumberUnaryOperator::RoundUp,
+	NumberUnaryOperator::RoundDown,
+	NumberUnaryOperator::Truncate,
+	NumberUnaryOperator::Nearest,
+];
+
+pub const NUMBER_BINARY_OPERATORS: [NumberBinaryOperator; 7] = [
+	NumberBinaryOperator::Add,
+	NumberBinaryOperator::Subtract,
+	NumberBinaryOperator::Multiply,
+	NumberBinaryOperator::Divide,
+	NumberBinaryOperator::Minimum,
+	NumberBinaryOperator::Maximum,
+	NumberBinaryOperator::CopySign,
+];
+
+pub const NUMBER_COMPARE_OPERATORS: [NumberCompareOperator; 6] = [
+	NumberCompareOperator::Equal,
+	NumberCompareOperator::NotEqual,
+	NumberCompareOperator::LessThan,
+	NumberCompareOperator::GreaterThan,
+	NumberCompareOperator::LessThanEqual,
+	NumberCompareOperator::GreaterThanEqual,
+];
+
+fn for_each_signed_lane<H: FnMut(LaneType, bool)>(mut handler: H) {
+	for r#type in LANE_TYPES {
+		handler(r#type, false);
+		handler(r#type, true);
+	}
+}
+
+pub fn v128_unary_operators() -> Vec<V128UnaryOperator> {
+	let mut list = alloc::vec![
+		V128UnaryOperator::Not,
+		V128UnaryOperator::CountOnes,
+		V128UnaryOperator::Narrow,
+		V128UnaryOperator::Widen,
+	];
+
+	for r#type in LANE_TYPES {
+		list.extend([
+			V128UnaryOperator::Absolute(r#type),
+			V128UnaryOperator::Negate(r#type),
+			V128UnaryOperator::SquareRoot(r#type),
+			V128UnaryOperator::RoundUp(r#type),
+			V128UnaryOperator::RoundDown(r#type),
+			V128UnaryOperator::Truncate(r#type),
+			V128UnaryOperator::Nearest(r#type),
+		]);
+	}
+
+	for_each_signed_lane(|r#type, signed| {
+		list.extend([
+			V128UnaryOperator::ExtendLow {
+				from: r#type,
+				signed,
+			},
+			V128UnaryOperator::ExtendHigh {
+				from: r#type,
+				signed,
+			},
+			V128UnaryOperator::ExtendAddPairwise {
+				from: r#type,
+				signed,
+			},
+			V128UnaryOperator::Saturate {
+				from: r#type,
+				signed,
+			},
+			V128UnaryOperator::Convert { to: r#type, signed },
+		]);
+	});
+
+	list
+}
+
+pub fn v128_binary_operators() -> Vec<V128BinaryOperator> {
+	let mut list = alloc::vec![
+		V128BinaryOperator::And,
+		V128BinaryOperator::AndNot,
+		V128BinaryOperator::Or,
+		V128BinaryOperator::ExclusiveOr,
+		V128BinaryOperator::Swizzle,
+		V128BinaryOperator::DotProduct,
+		V128BinaryOperator::MultiplyRoundSaturate,
+	];
+
+	for r#type in LANE_TYPES {
+		list.extend([
+			V128BinaryOperator::Add(r#type),
+			V128BinaryOperator::Subtract(r#type),
+			V128BinaryOperator::Multiply(r#type),
+			V128BinaryOperator::Divide(r#type),
+			V128BinaryOperator::NumberMinimum(r#type),
+			V128BinaryOperator::NumberMaximum(r#type),
+			V128BinaryOperator::PseudoMinimum(r#type),
+			V128BinaryOperator::PseudoMaximum(r#type),
+			V128BinaryOperator::Average(r#type),
+			V128BinaryOperator::ShiftLeft(r#type),
+		]);
+
+		list.extend(
+			INTEGER_COMPARE_OPERATORS
+				.map(|operator| V128BinaryOperator::IntegerCompare(r#type, operator)),
+		);
+
+		list.extend(
+			NUMBER_COMPARE_OPERATORS
+				.map(|operator| V128BinaryOperator::NumberCompare(r#type, operator)),
+		);
+	}
+
+	for_each_signed_lane(|r#type, signed| {
+		list.extend([
+			V128BinaryOperator::AddSaturate { r#type, signed },
+			V128BinaryOperator::SubtractSaturate { r#type, signed },
+			V128BinaryOperator::IntegerMinimum { r#type, signed },
+			V128BinaryOperator::IntegerMaximum { r#type, signed },
+			V128BinaryOperator::ShiftRight { r#type, signed },
+			V128BinaryOperator::ExtendMultiplyLow {
+				from: r#type,
+				signed,
+			},
+			V128BinaryOperator::ExtendMultiplyHigh {
+				from: r#type,
+				signed,
+			},
+			V128BinaryOperator::Narrow {
+				from: r#type,
+				signed,
+			},
+		]);
+	});
+
+	list
+}
+
+pub fn v128_reduce_operators() -> Vec<V128ReduceOperator> {
+	let mut list = alloc::vec![V128ReduceOperator::AnyTrue];
+
+	for r#type in LANE_TYPES {
+		list.extend([
+			V128ReduceOperator::AllTrue(r#type),
+			V128ReduceOperator::BitMask(r#type),
+		]);
+	}
+
+	list
+}
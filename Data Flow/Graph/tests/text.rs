@@ -0,0 +1,25 @@
+use data_flow_graph::{Syntax, Text};
+use pretty_assertions::assert_eq;
+
+// A module with a loop, a branch, a tail call, an import and an active
+// segment, along with a `NaN` and a negative zero constant.
+const MODULE: &str = include_str!("fixtures/module.dfg");
+
+#[test]
+fn round_trip() {
+	let graph = Syntax::new()
+		.parse(MODULE)
+		.expect("`MODULE` should be a valid graph");
+
+	assert_eq!(Text::new(&graph).to_string(), MODULE);
+}
+
+#[test]
+fn node_out_of_order() {
+	let error = Syntax::new()
+		.parse("0 = omega_in\n2 = null\n")
+		.err()
+		.expect("node ids should be in order");
+
+	assert_eq!(error.to_string(), "line 2: expected node 1, found `2`");
+}
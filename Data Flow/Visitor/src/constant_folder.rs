@@ -0,0 +1,639 @@
+use data_flow_graph::{
+	DataFlowGraph, Link, Node,
+	mvp::{
+		ExtendType, Identity, IntegerBinaryOperation, IntegerBinaryOperator,
+		IntegerCompareOperation, IntegerCompareOperator, IntegerConvertToNumber, IntegerExtend,
+		IntegerNarrow, IntegerTransmuteToNumber, IntegerType, IntegerUnaryOperation,
+		IntegerUnaryOperator, IntegerWiden, NumberBinaryOperation, NumberBinaryOperator,
+		NumberCompareOperation, NumberCompareOperator, NumberNarrow, NumberTransmuteToInteger,
+		NumberTruncateToInteger, NumberType, NumberUnaryOperation, NumberUnaryOperator,
+		NumberWiden,
+	},
+	nested::{GammaIn, GammaOut, RegionOut},
+};
+use hashbrown::HashMap;
+
+#[derive(Clone, Copy)]
+enum Constant {
+	I32(i32),
+	I64(i64),
+	F32(f32),
+	F64(f64),
+}
+
+impl Constant {
+	const fn load(node: &Node) -> Option<Self> {
+		let constant = match *node {
+			Node::I32(value) => Self::I32(value),
+			Node::I64(value) => Self::I64(value),
+			Node::F32(value) => Self::F32(value),
+			Node::F64(value) => Self::F64(value),
+			_ => return None,
+		};
+
+		Some(constant)
+	}
+
+	const fn into_node(self) -> Node {
+		match self {
+			Self::I32(value) => Node::I32(value),
+			Self::I64(value) => Node::I64(value),
+			Self::F32(value) => Node::F32(value),
+			Self::F64(value) => Node::F64(value),
+		}
+	}
+}
+
+macro_rules! fold_integer {
+	($unary:ident, $binary:ident, $signed:ty, $unsigned:ty) => {
+		fn $unary(operator: IntegerUnaryOperator, source: $signed) -> $signed {
+			let count = match operator {
+				IntegerUnaryOperator::CountOnes => source.count_ones(),
+				IntegerUnaryOperator::LeadingZeroes => source.leading_zeros(),
+				IntegerUnaryOperator::TrailingZeroes => source.trailing_zeros(),
+			};
+
+			<$signed>::from(u8::try_from(count).unwrap())
+		}
+
+		// Division by zero and signed overflow trap at runtime, so those
+		// operations are left for it to run.
+		fn $binary(operator: IntegerBinaryOperator, lhs: $signed, rhs: $signed) -> Option<$signed> {
+			let unsigned_lhs = lhs.cast_unsigned();
+			let unsigned_rhs = rhs.cast_unsigned();
+
+			// Shifts only use the low bits of their amount, which all fit
+			// in its first byte.
+			let shift = u32::from(unsigned_rhs.to_le_bytes()[0]);
+
+			let result = match operator {
+				IntegerBinaryOperator::Add => lhs.wrapping_add(rhs),
+				IntegerBinaryOperator::Subtract => lhs.wrapping_sub(rhs),
+				IntegerBinaryOperator::Multiply => lhs.wrapping_mul(rhs),
+				IntegerBinaryOperator::Divide { signed: true } => lhs.checked_div(rhs)?,
+				IntegerBinaryOperator::Divide { signed: false } => {
+					unsigned_lhs.checked_div(unsigned_rhs)?.cast_signed()
+				}
+				IntegerBinaryOperator::Remainder { signed: true } => {
+					(rhs != 0).then(|| lhs.wrapping_rem(rhs))?
+				}
+				IntegerBinaryOperator::Remainder { signed: false } => {
+					unsigned_lhs.checked_rem(unsigned_rhs)?.cast_signed()
+				}
+				IntegerBinaryOperator::And => lhs & rhs,
+				IntegerBinaryOperator::Or => lhs | rhs,
+				IntegerBinaryOperator::ExclusiveOr => lhs ^ rhs,
+				IntegerBinaryOperator::ShiftLeft => lhs.wrapping_shl(shift),
+				IntegerBinaryOperator::ShiftRight { signed: true } => lhs.wrapping_shr(shift),
+				IntegerBinaryOperator::ShiftRight { signed: false } => {
+					unsigned_lhs.wrapping_shr(shift).cast_signed()
+				}
+				IntegerBinaryOperator::RotateLeft => unsigned_lhs.rotate_left(shift).cast_signed(),
+				IntegerBinaryOperator::RotateRight => {
+					unsigned_lhs.rotate_right(shift).cast_signed()
+				}
+			};
+
+			Some(result)
+		}
+	};
+}
+
+fold_integer!(fold_i32_unary, fold_i32_binary, i32, u32);
+fold_integer!(fold_i64_unary, fold_i64_binary, i64, u64);
+
+fn fold_integer_compare<S: Ord, U: Ord>(
+	operator: IntegerCompareOperator,
+	signed: (S, S),
+	unsigned: (U, U),
+) -> bool {
+	let (lhs, rhs) = signed;
+
+	match operator {
+		IntegerCompareOperator::Equal => lhs == rhs,
+		IntegerCompareOperator::NotEqual => lhs != rhs,
+		IntegerCompareOperator::LessThan { signed: true } => lhs < rhs,
+		IntegerCompareOperator::LessThan { signed: false } => unsigned.0 < unsigned.1,
+		IntegerCompareOperator::GreaterThan { signed: true } => lhs > rhs,
+		IntegerCompareOperator::GreaterThan { signed: false } => unsigned.0 > unsigned.1,
+		IntegerCompareOperator::LessThanEqual { signed: true } => lhs <= rhs,
+		IntegerCompareOperator::LessThanEqual { signed: false } => unsigned.0 <= unsigned.1,
+		IntegerCompareOperator::GreaterThanEqual { signed: true } => lhs >= rhs,
+		IntegerCompareOperator::GreaterThanEqual { signed: false } => unsigned.0 >= unsigned.1,
+	}
+}
+
+macro_rules! fold_number {
+	($unary:ident, $binary:ident, $float:ty) => {
+		// Negation and absolute values only ever change the sign bit, even
+		// for NaN, as Wasm requires.
+		fn $unary(operator: NumberUnaryOperator, source: $float) -> $float {
+			match operator {
+				NumberUnaryOperator::Absolute => source.abs(),
+				NumberUnaryOperator::Negate => -source,
+				NumberUnaryOperator::SquareRoot => source.sqrt(),
+				NumberUnaryOperator::RoundUp => source.ceil(),
+				NumberUnaryOperator::RoundDown => source.floor(),
+				NumberUnaryOperator::Truncate => source.trunc(),
+				NumberUnaryOperator::Nearest => source.round_ties_even(),
+			}
+		}
+
+		// Unlike `min` and `max`, Wasm propagates NaN and orders negative
+		// zero before positive zero.
+		fn $binary(operator: NumberBinaryOperator, lhs: $float, rhs: $float) -> $float {
+			match operator {
+				NumberBinaryOperator::Add => lhs + rhs,
+				NumberBinaryOperator::Subtract => lhs - rhs,
+				NumberBinaryOperator::Multiply => lhs * rhs,
+				NumberBinaryOperator::Divide => lhs / rhs,
+				NumberBinaryOperator::Minimum | NumberBinaryOperator::Maximum
+					if lhs.is_nan() || rhs.is_nan() =>
+				{
+					lhs + rhs
+				}
+				NumberBinaryOperator::Minimum if lhs == rhs => {
+					<$float>::from_bits(lhs.to_bits() | rhs.to_bits())
+				}
+				NumberBinaryOperator::Maximum if lhs == rhs => {
+					<$float>::from_bits(lhs.to_bits() & rhs.to_bits())
+				}
+				NumberBinaryOperator::Minimum => lhs.min(rhs),
+				NumberBinaryOperator::Maximum => lhs.max(rhs),
+				NumberBinaryOperator::CopySign => lhs.copysign(rhs),
+			}
+		}
+	};
+}
+
+fold_number!(fold_f32_unary, fold_f32_binary, f32);
+fold_number!(fold_f64_unary, fold_f64_binary, f64);
+
+fn fold_number_compare<T: PartialOrd>(operator: NumberCompareOperator, lhs: T, rhs: T) -> bool {
+	match operator {
+		NumberCompareOperator::Equal => lhs == rhs,
+		NumberCompareOperator::NotEqual => lhs != rhs,
+		NumberCompareOperator::LessThan => lhs < rhs,
+		NumberCompareOperator::GreaterThan => lhs > rhs,
+		NumberCompareOperator::LessThanEqual => lhs <= rhs,
+		NumberCompareOperator::GreaterThanEqual => lhs >= rhs,
+	}
+}
+
+// Conversions from integers round to the nearest value with ties to even,
+// which is what both Rust and Wasm do.
+#[expect(clippy::cast_precision_loss)]
+fn fold_convert(source: Constant, signed: bool, to: NumberType) -> Option<Constant> {
+	let constant = match (source, to) {
+		(Constant::I32(value), NumberType::F32) if signed => Constant::F32(value as f32),
+		(Constant::I32(value), NumberType::F32) => Constant::F32(value.cast_unsigned() as f32),
+		(Constant::I32(value), NumberType::F64) if signed => Constant::F64(value.into()),
+		(Constant::I32(value), NumberType::F64) => Constant::F64(value.cast_unsigned().into()),
+		(Constant::I64(value), NumberType::F32) if signed => Constant::F32(value as f32),
+		(Constant::I64(value), NumberType::F32) => Constant::F32(value.cast_unsigned() as f32),
+		(Constant::I64(value), NumberType::F64) if signed => Constant::F64(value as f64),
+		(Constant::I64(value), NumberType::F64) => Constant::F64(value.cast_unsigned() as f64),
+		_ => return None,
+	};
+
+	Some(constant)
+}
+
+// Values that are NaN or out of range trap unless saturating, in which case
+// the saturating `as` casts of Rust match Wasm exactly.
+#[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn fold_truncate(source: f64, signed: bool, saturate: bool, to: IntegerType) -> Option<Constant> {
+	let (minimum, maximum) = match (to, signed) {
+		(IntegerType::I32, true) => (-2_147_483_649.0, 2_147_483_648.0),
+		(IntegerType::I32, false) => (-1.0, 4_294_967_296.0),
+		(IntegerType::I64, true) => (-9_223_372_036_854_777_856.0, 9_223_372_036_854_775_808.0),
+		(IntegerType::I64, false) => (-1.0, 18_446_744_073_709_551_616.0),
+	};
+
+	let in_range = source > minimum && source < maximum;
+
+	if !saturate && !in_range {
+		return None;
+	}
+
+	let constant = match (to, signed) {
+		(IntegerType::I32, true) => Constant::I32(source as i32),
+		(IntegerType::I32, false) => Constant::I32((source as u32).cast_signed()),
+		(IntegerType::I64, true) => Constant::I64(source as i64),
+		(IntegerType::I64, false) => Constant::I64((source as u64).cast_signed()),
+	};
+
+	Some(constant)
+}
+
+#[expect(clippy::cast_possible_truncation)]
+const fn fold_narrow(source: f64) -> f32 {
+	source as f32
+}
+
+const fn fold_extend(source: Constant, r#type: ExtendType) -> Option<Constant> {
+	let constant = match (source, r#type) {
+		(Constant::I32(value), ExtendType::I32_S8) => Constant::I32((value << 24) >> 24),
+		(Constant::I32(value), ExtendType::I32_S16) => Constant::I32((value << 16) >> 16),
+		(Constant::I64(value), ExtendType::I64_S8) => Constant::I64((value << 56) >> 56),
+		(Constant::I64(value), ExtendType::I64_S16) => Constant::I64((value << 48) >> 48),
+		(Constant::I64(value), ExtendType::I64_S32) => Constant::I64((value << 32) >> 32),
+		_ => return None,
+	};
+
+	Some(constant)
+}
+
+/// Evaluates operations whose inputs are all constants, following the
+/// exact semantics of Wasm, and replaces them with their results. Those
+/// that would trap are left in place. Branches with a constant condition
+/// are replaced by the region that they would take.
+pub struct ConstantFolder {
+	map: HashMap<Link, Link>,
+	taken: HashMap<u32, u32>,
+}
+
+impl ConstantFolder {
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			map: HashMap::new(),
+			taken: HashMap::new(),
+		}
+	}
+
+	fn get_reference(&self, mut link: Link) -> Link {
+		while let Some(&next) = self.map.get(&link) {
+			link = next;
+		}
+
+		link
+	}
+
+	fn get_constant(&self, graph: &DataFlowGraph, link: Link) -> Option<Constant> {
+		let mut link = self.get_reference(link);
+
+		while let Node::Identity(Identity { source }) = *graph.get(link.0) {
+			link = self.get_reference(source);
+		}
+
+		Constant::load(graph.get(link.0))
+	}
+
+	fn fold_integer_unary(
+		&self,
+		graph: &DataFlowGraph,
+		operation: IntegerUnaryOperation,
+	) -> Option<Constant> {
+		let IntegerUnaryOperation {
+			source, operator, ..
+		} = operation;
+
+		let constant = match self.get_constant(graph, source)? {
+			Constant::I32(source) => Constant::I32(fold_i32_unary(operator, source)),
+			Constant::I64(source) => Constant::I64(fold_i64_unary(operator, source)),
+			_ => return None,
+		};
+
+		Some(constant)
+	}
+
+	fn fold_integer_binary(
+		&self,
+		graph: &DataFlowGraph,
+		operation: IntegerBinaryOperation,
+	) -> Option<Constant> {
+		let IntegerBinaryOperation {
+			lhs, rhs, operator, ..
+		} = operation;
+
+		let lhs = self.get_constant(graph, lhs)?;
+		let rhs = self.get_constant(graph, rhs)?;
+
+		match (lhs, rhs) {
+			(Constant::I32(lhs), Constant::I32(rhs)) => {
+				fold_i32_binary(operator, lhs, rhs).map(Constant::I32)
+			}
+			(Constant::I64(lhs), Constant::I64(rhs)) => {
+				fold_i64_binary(operator, lhs, rhs).map(Constant::I64)
+			}
+			_ => None,
+		}
+	}
+
+	fn fold_integer_compare(
+		&self,
+		graph: &DataFlowGraph,
+		operation: IntegerCompareOperation,
+	) -> Option<Constant> {
+		let IntegerCompareOperation {
+			lhs, rhs, operator, ..
+		} = operation;
+
+		let lhs = self.get_constant(graph, lhs)?;
+		let rhs = self.get_constant(graph, rhs)?;
+
+		let result = match (lhs, rhs) {
+			(Constant::I32(lhs), Constant::I32(rhs)) => fold_integer_compare(
+				operator,
+				(lhs, rhs),
+				(lhs.cast_unsigned(), rhs.cast_unsigned()),
+			),
+			(Constant::I64(lhs), Constant::I64(rhs)) => fold_integer_compare(
+				operator,
+				(lhs, rhs),
+				(lhs.cast_unsigned(), rhs.cast_unsigned()),
+			),
+			_ => return None,
+		};
+
+		Some(Constant::I32(result.into()))
+	}
+
+	fn fold_integer_narrow(
+		&self,
+		graph: &DataFlowGraph,
+		narrow: IntegerNarrow,
+	) -> Option<Constant> {
+		let Constant::I64(source) = self.get_constant(graph, narrow.source)? else {
+			return None;
+		};
+
+		let result = u32::try_from(source.cast_unsigned() & u64::from(u32::MAX)).unwrap();
+
+		Some(Constant::I32(result.cast_signed()))
+	}
+
+	fn fold_integer_widen(&self, graph: &DataFlowGraph, widen: IntegerWiden) -> Option<Constant> {
+		let Constant::I32(source) = self.get_constant(graph, widen.source)? else {
+			return None;
+		};
+
+		Some(Constant::I64(source.cast_unsigned().into()))
+	}
+
+	fn fold_integer_transmute(
+		&self,
+		graph: &DataFlowGraph,
+		transmute: IntegerTransmuteToNumber,
+	) -> Option<Constant> {
+		let constant = match self.get_constant(graph, transmute.source)? {
+			Constant::I32(source) => Constant::F32(f32::from_bits(source.cast_unsigned())),
+			Constant::I64(source) => Constant::F64(f64::from_bits(source.cast_unsigned())),
+			_ => return None,
+		};
+
+		Some(constant)
+	}
+
+	fn fold_number_unary(
+		&self,
+		graph: &DataFlowGraph,
+		operation: NumberUnaryOperation,
+	) -> Option<Constant> {
+		let NumberUnaryOperation {
+			source, operator, ..
+		} = operation;
+
+		let constant = match self.get_constant(graph, source)? {
+			Constant::F32(source) => Constant::F32(fold_f32_unary(operator, source)),
+			Constant::F64(source) => Constant::F64(fold_f64_unary(operator, source)),
+			_ => return None,
+		};
+
+		Some(constant)
+	}
+
+	fn fold_number_binary(
+		&self,
+		graph: &DataFlowGraph,
+		operation: NumberBinaryOperation,
+	) -> Option<Constant> {
+		let NumberBinaryOperation {
+			lhs, rhs, operator, ..
+		} = operation;
+
+		let lhs = self.get_constant(graph, lhs)?;
+		let rhs = self.get_constant(graph, rhs)?;
+
+		let constant = match (lhs, rhs) {
+			(Constant::F32(lhs), Constant::F32(rhs)) => {
+				Constant::F32(fold_f32_binary(operator, lhs, rhs))
+			}
+			(Constant::F64(lhs), Constant::F64(rhs)) => {
+				Constant::F64(fold_f64_binary(operator, lhs, rhs))
+			}
+			_ => return None,
+		};
+
+		Some(constant)
+	}
+
+	fn fold_number_compare(
+		&self,
+		graph: &DataFlowGraph,
+		operation: NumberCompareOperation,
+	) -> Option<Constant> {
+		let NumberCompareOperation {
+			lhs, rhs, operator, ..
+		} = operation;
+
+		let lhs = self.get_constant(graph, lhs)?;
+		let rhs = self.get_constant(graph, rhs)?;
+
+		let result = match (lhs, rhs) {
+			(Constant::F32(lhs), Constant::F32(rhs)) => fold_number_compare(operator, lhs, rhs),
+			(Constant::F64(lhs), Constant::F64(rhs)) => fold_number_compare(operator, lhs, rhs),
+			_ => return None,
+		};
+
+		Some(Constant::I32(result.into()))
+	}
+
+	fn fold_number_narrow(&self, graph: &DataFlowGraph, narrow: NumberNarrow) -> Option<Constant> {
+		let Constant::F64(source) = self.get_constant(graph, narrow.source)? else {
+			return None;
+		};
+
+		Some(Constant::F32(fold_narrow(source)))
+	}
+
+	fn fold_number_widen(&self, graph: &DataFlowGraph, widen: NumberWiden) -> Option<Constant> {
+		let Constant::F32(source) = self.get_constant(graph, widen.source)? else {
+			return None;
+		};
+
+		Some(Constant::F64(source.into()))
+	}
+
+	fn fold_number_truncate(
+		&self,
+		graph: &DataFlowGraph,
+		truncate: NumberTruncateToInteger,
+	) -> Option<Constant> {
+		let NumberTruncateToInteger {
+			source,
+			signed,
+			saturate,
+			to,
+			..
+		} = truncate;
+
+		// Widening is exact, so both sources can be checked the same way.
+		let source = match self.get_constant(graph, source)? {
+			Constant::F32(source) => source.into(),
+			Constant::F64(source) => source,
+			_ => return None,
+		};
+
+		fold_truncate(source, signed, saturate, to)
+	}
+
+	fn fold_number_transmute(
+		&self,
+		graph: &DataFlowGraph,
+		transmute: NumberTransmuteToInteger,
+	) -> Option<Constant> {
+		let constant = match self.get_constant(graph, transmute.source)? {
+			Constant::F32(source) => Constant::I32(source.to_bits().cast_signed()),
+			Constant::F64(source) => Constant::I64(source.to_bits().cast_signed()),
+			_ => return None,
+		};
+
+		Some(constant)
+	}
+
+	fn fold_node(&self, graph: &DataFlowGraph, node: &Node) -> Option<Constant> {
+		match *node {
+			Node::IntegerUnaryOperation(operation) => self.fold_integer_unary(graph, operation),
+			Node::IntegerBinaryOperation(operation) => self.fold_integer_binary(graph, operation),
+			Node::IntegerCompareOperation(operation) => self.fold_integer_compare(graph, operation),
+			Node::IntegerNarrow(narrow) => self.fold_integer_narrow(graph, narrow),
+			Node::IntegerWiden(widen) => self.fold_integer_widen(graph, widen),
+			Node::IntegerExtend(IntegerExtend { source, r#type }) => {
+				fold_extend(self.get_constant(graph, source)?, r#type)
+			}
+			Node::IntegerConvertToNumber(IntegerConvertToNumber {
+				source, signed, to, ..
+			}) => fold_convert(self.get_constant(graph, source)?, signed, to),
+			Node::IntegerTransmuteToNumber(transmute) => {
+				self.fold_integer_transmute(graph, transmute)
+			}
+			Node::NumberUnaryOperation(operation) => self.fold_number_unary(graph, operation),
+			Node::NumberBinaryOperation(operation) => self.fold_number_binary(graph, operation),
+			Node::NumberCompareOperation(operation) => self.fold_number_compare(graph, operation),
+			Node::NumberNarrow(narrow) => self.fold_number_narrow(graph, narrow),
+			Node::NumberWiden(widen) => self.fold_number_widen(graph, widen),
+			Node::NumberTruncateToInteger(truncate) => self.fold_number_truncate(graph, truncate),
+			Node::NumberTransmuteToInteger(transmute) => {
+				self.fold_number_transmute(graph, transmute)
+			}
+
+			_ => None,
+		}
+	}
+
+	// Conditions past the last region take the last one, as `br_table`
+	// does with its default target.
+	fn find_taken_region(
+		&self,
+		graph: &DataFlowGraph,
+		condition: Link,
+		regions: &[u32],
+	) -> Option<u32> {
+		let Constant::I32(condition) = self.get_constant(graph, condition)? else {
+			return None;
+		};
+
+		let last = regions.len() - 1;
+		let index =
+			usize::try_from(condition.cast_unsigned()).map_or(last, |index| index.min(last));
+
+		Some(regions[index])
+	}
+
+	fn handle_gamma_in(&mut self, graph: &DataFlowGraph, gamma_in: &GammaIn) {
+		let GammaOut { regions, .. } = graph.get(gamma_in.output).as_gamma_out().unwrap();
+
+		let Some(region) = self.find_taken_region(graph, gamma_in.condition, regions) else {
+			return;
+		};
+
+		let RegionOut { input, .. } = graph.get(region).as_region_out().unwrap();
+
+		for (&argument, port) in gamma_in.arguments.iter().zip(0..) {
+			self.map.insert(Link(*input, port), argument);
+		}
+
+		self.taken.insert(gamma_in.output, region);
+	}
+
+	// The region is chosen by the `GammaIn`, as its arguments have already
+	// been forwarded into it.
+	fn handle_gamma_out(&mut self, graph: &DataFlowGraph, id: u32) {
+		let Some(&region) = self.taken.get(&id) else {
+			return;
+		};
+
+		let RegionOut { results, .. } = graph.get(region).as_region_out().unwrap();
+
+		for (&result, port) in results.iter().zip(0..) {
+			self.map.insert(Link(id, port), result);
+		}
+	}
+
+	fn replace_arguments(&self, node: &mut Node) -> bool {
+		let mut changed = false;
+
+		node.for_each_mut_argument(|link| {
+			let reference = self.get_reference(*link);
+
+			changed |= reference != *link;
+			*link = reference;
+		});
+
+		changed
+	}
+
+	// Nodes are visited in order so that, in a topologically sorted graph,
+	// the inputs of an operation are folded before it is. Returns whether
+	// any node was folded or any link replaced.
+	pub fn run(&mut self, graph: &mut DataFlowGraph) -> bool {
+		let mut changed = false;
+
+		self.map.clear();
+		self.taken.clear();
+
+		for id in 0..graph.len().try_into().unwrap() {
+			let mut node = std::mem::take(graph.get_mut(id));
+
+			changed |= self.replace_arguments(&mut node);
+
+			match &node {
+				Node::GammaIn(gamma_in) => self.handle_gamma_in(graph, gamma_in),
+				Node::GammaOut(_) => self.handle_gamma_out(graph, id),
+
+				_ => {}
+			}
+
+			if let Some(constant) = self.fold_node(graph, &node) {
+				node = constant.into_node();
+				changed = true;
+			}
+
+			*graph.get_mut(id) = node;
+		}
+
+		for node in graph.nodes_mut() {
+			changed |= self.replace_arguments(node);
+		}
+
+		changed
+	}
+}
+
+impl Default for ConstantFolder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,207 @@
+use data_flow_graph::{
+	DataFlowGraph, Link, Node,
+	mvp::{
+		DataDrop, ElementsDrop, FunctionNew, GlobalNew, GlobalSet, MemoryInit, Merge, TableFill,
+		TableInit,
+	},
+};
+use set::Set;
+
+use crate::verifier::Verifier;
+
+// Follows the state of an item back to the node that created it, noting
+// whether an active segment was written to it on the way.
+fn find_root(graph: &DataFlowGraph, mut link: Link) -> (u32, bool) {
+	let mut active = false;
+
+	loop {
+		link = match graph.get(link.0) {
+			Node::GlobalSet(GlobalSet { destination, .. }) => *destination,
+			Node::TableFill(TableFill { destination, .. }) => destination.reference,
+			Node::TableInit(TableInit { destination, .. }) => {
+				active = true;
+
+				destination.reference
+			}
+			Node::MemoryInit(MemoryInit { destination, .. }) => {
+				active = true;
+
+				destination.reference
+			}
+			Node::ElementsDrop(ElementsDrop { source }) | Node::DataDrop(DataDrop { source }) => {
+				*source
+			}
+
+			_ => return (link.0, active),
+		};
+	}
+}
+
+// Imported functions are stored in a global of their own, which is
+// created from the import.
+fn is_import(graph: &DataFlowGraph, link: Link) -> bool {
+	match graph.get(link.0) {
+		Node::Import(_) => true,
+		Node::FunctionNew(FunctionNew { source, .. }) => is_import(graph, *source),
+
+		_ => false,
+	}
+}
+
+fn is_item(graph: &DataFlowGraph, id: u32) -> bool {
+	match graph.get(id) {
+		Node::GlobalNew(GlobalNew { initializer, .. }) => !is_import(graph, *initializer),
+		Node::TableNew(_) | Node::MemoryNew(_) | Node::DataNew(_) => true,
+
+		_ => false,
+	}
+}
+
+/// Removes the functions, globals, tables, memories and segments of a
+/// module that cannot be reached from its exports or start functions.
+///
+/// Items are kept alive by the final state of the module, so those that
+/// nothing else uses are dropped from it. Items that active segments are
+/// written to are always kept, as writing them may trap while the module
+/// is instantiated, and so are imports, as instantiating the module must
+/// still fail when the host does not provide them.
+pub struct DeadItemEliminator {
+	verifier: Verifier,
+
+	merges: Vec<u32>,
+	pending: Vec<(Link, u32)>,
+
+	seen: Set,
+	stack: Vec<u32>,
+}
+
+impl DeadItemEliminator {
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			verifier: Verifier::new(),
+
+			merges: Vec::new(),
+			pending: Vec::new(),
+
+			seen: Set::new(),
+			stack: Vec::new(),
+		}
+	}
+
+	// The final states of modules are merged with the states of the ones
+	// before them, so those are only followed through the items they list.
+	fn mark(&mut self, graph: &DataFlowGraph, id: u32) {
+		self.stack.push(id);
+
+		while let Some(id) = self.stack.pop() {
+			if self.seen.grow_insert(id.try_into().unwrap()) || self.merges.contains(&id) {
+				continue;
+			}
+
+			let node = graph.get(id);
+
+			node.for_each_requirement(|id| self.stack.push(id));
+			node.for_each_argument(|link| self.stack.push(link.0));
+		}
+	}
+
+	fn find_merges(&mut self, graph: &DataFlowGraph, omega: u32) {
+		for id in 0..graph.len().try_into().unwrap() {
+			if matches!(graph.get(id), Node::Merge(_)) && self.verifier.get_owner(id) == Some(omega)
+			{
+				self.merges.push(id);
+			}
+		}
+	}
+
+	// States of items wait until something reaches their root, while any
+	// others, such as those of start functions, are always kept.
+	fn handle_merge(&mut self, graph: &DataFlowGraph, id: u32) {
+		let Node::Merge(Merge { states }) = graph.get(id) else {
+			unreachable!("node {id} should be a merge");
+		};
+
+		for &state in states {
+			let (root, active) = find_root(graph, state);
+
+			if !active && is_item(graph, root) {
+				self.pending.push((state, root));
+			} else {
+				self.mark(graph, state.0);
+			}
+		}
+	}
+
+	fn handle_pending(&mut self, graph: &DataFlowGraph) {
+		let mut pending = std::mem::take(&mut self.pending);
+
+		loop {
+			let len = pending.len();
+
+			pending.retain(|&(state, root)| {
+				if !self.seen.contains(root.try_into().unwrap()) {
+					return true;
+				}
+
+				self.mark(graph, state.0);
+
+				false
+			});
+
+			if pending.len() == len {
+				break;
+			}
+		}
+
+		self.pending = pending;
+	}
+
+	fn sweep(&self, graph: &mut DataFlowGraph) -> bool {
+		let mut changed = false;
+
+		for &id in &self.merges {
+			let Node::Merge(Merge { states }) = graph.get_mut(id) else {
+				unreachable!("node {id} should be a merge");
+			};
+
+			let len = states.len();
+
+			states.retain(|state| self.seen.contains(state.0.try_into().unwrap()));
+
+			changed |= states.len() != len;
+		}
+
+		changed
+	}
+
+	/// Returns whether any item was removed.
+	pub fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> bool {
+		self.merges.clear();
+		self.pending.clear();
+		self.seen.clear();
+
+		if self.verifier.run(graph, omega).is_err() {
+			return false;
+		}
+
+		self.find_merges(graph, omega);
+
+		for export in &graph.get(omega).as_omega_out().unwrap().exports {
+			self.mark(graph, export.reference.0);
+		}
+
+		for id in self.merges.clone() {
+			self.handle_merge(graph, id);
+		}
+
+		self.handle_pending(graph);
+		self.sweep(graph)
+	}
+}
+
+impl Default for DeadItemEliminator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
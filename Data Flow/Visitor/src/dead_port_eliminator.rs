@@ -142,24 +142,31 @@ impl DeadPortEliminator {
 		}
 	}
 
-	fn sweep_inputs(&self, graph: &mut DataFlowGraph, id: u32) {
+	fn sweep_inputs(&self, graph: &mut DataFlowGraph, id: u32) -> bool {
 		let Some(arguments) = graph.get_mut(id).as_mut_ports() else {
-			return;
+			return false;
 		};
 
 		let mut links = (0..u16::MAX)
 			.map(|port| Link(id, port))
 			.map(Link::into_usize);
 
+		let len = arguments.len();
+
 		arguments.retain(|_| self.seen.contains(links.next().unwrap()));
+
+		arguments.len() != len
 	}
 
-	fn sweep(&mut self, graph: &mut DataFlowGraph) {
+	fn sweep(&mut self, graph: &mut DataFlowGraph) -> bool {
+		let mut changed = false;
+
 		self.map.clear();
 
 		for id in (0..graph.len().try_into().unwrap()).rev() {
 			self.sweep_outputs(graph, id);
-			self.sweep_inputs(graph, id);
+
+			changed |= self.sweep_inputs(graph, id);
 		}
 
 		for node in graph.nodes_mut() {
@@ -169,11 +176,14 @@ impl DeadPortEliminator {
 				}
 			});
 		}
+
+		changed || !self.map.is_empty()
 	}
 
-	pub fn run(&mut self, graph: &mut DataFlowGraph, result: Link) {
+	/// Returns whether any port was removed.
+	pub fn run(&mut self, graph: &mut DataFlowGraph, result: Link) -> bool {
 		self.mark(graph, result);
-		self.sweep(graph);
+		self.sweep(graph)
 	}
 }
 
@@ -91,7 +91,10 @@ impl FallthroughMover {
 		self.handle_simple_reference(arguments, results, *input, *output);
 	}
 
-	pub fn run(&mut self, graph: &mut DataFlowGraph) {
+	/// Returns whether any link was moved to the value passed through.
+	pub fn run(&mut self, graph: &mut DataFlowGraph) -> bool {
+		let mut changed = false;
+
 		self.map.clear();
 
 		for node in graph.nodes() {
@@ -107,9 +110,12 @@ impl FallthroughMover {
 			node.for_each_mut_argument(|old| {
 				if let Some(new) = self.map.get(old) {
 					*old = *new;
+					changed = true;
 				}
 			});
 		}
+
+		changed
 	}
 }
 
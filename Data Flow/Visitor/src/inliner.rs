@@ -0,0 +1,472 @@
+use data_flow_graph::{
+	DataFlowGraph, Link, Node,
+	mvp::{Call, FunctionNew, GlobalGet, GlobalSet},
+	nested::{GammaIn, LambdaIn, LambdaOut, RegionIn, RegionOut, ThetaIn},
+};
+use hashbrown::{HashMap, HashSet};
+
+use crate::{topological_normalizer::TopologicalNormalizer, verifier::Verifier};
+
+// Follows a state back to the reference that it belongs to, which is the
+// same for every function that depends on it.
+fn find_root(graph: &DataFlowGraph, mut link: Link) -> Link {
+	loop {
+		let port = usize::from(link.1);
+
+		link = match graph.get(link.0) {
+			Node::LambdaIn(LambdaIn { dependencies, .. }) if port < dependencies.len() => {
+				dependencies[port]
+			}
+			Node::RegionIn(RegionIn { input, .. }) => {
+				graph.get(*input).as_gamma_in().unwrap().arguments[port]
+			}
+			Node::ThetaIn(ThetaIn { arguments, .. }) => arguments[port],
+			Node::Identity(identity) => identity.source,
+			Node::GlobalSet(GlobalSet { destination, .. }) => *destination,
+
+			_ => return link,
+		};
+	}
+}
+
+fn load_function(graph: &DataFlowGraph, source: Link) -> Option<u32> {
+	let Node::FunctionNew(FunctionNew { source, .. }) = graph.get(source.0) else {
+		return None;
+	};
+
+	graph.get(source.0).as_lambda_out().map(|_| source.0)
+}
+
+// Pushes the functions reachable from `entry` that were not seen yet, in
+// post order.
+fn visit_functions(
+	edges: &HashMap<u32, Vec<u32>>,
+	entry: u32,
+	seen: &mut HashSet<u32>,
+	result: &mut Vec<u32>,
+) {
+	let mut stack = vec![(entry, false)];
+
+	while let Some((id, post)) = stack.pop() {
+		if post {
+			result.push(id);
+		} else if seen.insert(id) {
+			stack.push((id, true));
+			stack.extend(
+				edges[&id]
+					.iter()
+					.filter(|id| !seen.contains(*id))
+					.map(|&id| (id, false)),
+			);
+		}
+	}
+}
+
+/// Copies the bodies of small functions into the calls made to them.
+///
+/// Calls are only known to reach a function when they read it from a
+/// global that is set once and never exported. The caller must already
+/// depend on everything that the callee does, so that the states of the
+/// call can stand in for those of the callee.
+pub struct Inliner {
+	verifier: Verifier,
+	normalizer: TopologicalNormalizer,
+	threshold: usize,
+
+	functions: HashMap<Link, u32>,
+	bodies: HashMap<u32, Vec<u32>>,
+	recursive: HashSet<u32>,
+	changed: HashSet<u32>,
+
+	map: HashMap<Link, Link>,
+}
+
+impl Inliner {
+	/// The most nodes that a function may have to be inlined by default.
+	pub const DEFAULT_THRESHOLD: usize = 32;
+
+	#[must_use]
+	pub fn new() -> Self {
+		Self::with_threshold(Self::DEFAULT_THRESHOLD)
+	}
+
+	/// Creates an inliner for functions of at most `threshold` nodes.
+	#[must_use]
+	pub fn with_threshold(threshold: usize) -> Self {
+		Self {
+			verifier: Verifier::new(),
+			normalizer: TopologicalNormalizer::new(),
+			threshold,
+
+			functions: HashMap::new(),
+			bodies: HashMap::new(),
+			recursive: HashSet::new(),
+			changed: HashSet::new(),
+
+			map: HashMap::new(),
+		}
+	}
+
+	fn get_reference(&self, mut link: Link) -> Link {
+		while let Some(&next) = self.map.get(&link) {
+			link = next;
+		}
+
+		link
+	}
+
+	// Globals that are set more than once, or that the host can reach,
+	// may hold another function by the time that they are called.
+	fn find_functions(&mut self, graph: &DataFlowGraph, omega: u32) {
+		let mut globals = HashMap::new();
+
+		for node in graph.nodes() {
+			if let Node::GlobalSet(GlobalSet {
+				destination,
+				source,
+			}) = node
+			{
+				globals
+					.entry(find_root(graph, *destination))
+					.and_modify(|function| *function = None)
+					.or_insert_with(|| load_function(graph, *source));
+			}
+		}
+
+		for export in &graph.get(omega).as_omega_out().unwrap().exports {
+			globals.remove(&find_root(graph, export.reference));
+		}
+
+		let functions = globals
+			.into_iter()
+			.filter_map(|(root, function)| Some((root, function?)));
+
+		self.functions.extend(functions);
+	}
+
+	// `In` nodes are never owned, so they are placed with their `Out` node.
+	fn find_scope(&self, graph: &DataFlowGraph, id: u32) -> Option<u32> {
+		let owner = match graph.get(id) {
+			Node::GammaIn(GammaIn { output, .. })
+			| Node::RegionOut(RegionOut { output, .. })
+			| Node::ThetaIn(ThetaIn { output, .. }) => *output,
+			Node::RegionIn(RegionIn { output, .. }) => {
+				graph.get(*output).as_region_out().unwrap().output
+			}
+
+			_ => id,
+		};
+
+		self.verifier.get_owner(owner)
+	}
+
+	// Returns the `LambdaOut` of the function that `scope` is nested in.
+	fn find_lambda(&self, graph: &DataFlowGraph, mut scope: u32) -> Option<u32> {
+		loop {
+			scope = match graph.get(scope) {
+				Node::LambdaOut(_) => return Some(scope),
+				Node::RegionOut(RegionOut { output, .. }) => self.verifier.get_owner(*output)?,
+				Node::ThetaOut(_) => self.verifier.get_owner(scope)?,
+
+				_ => return None,
+			};
+		}
+	}
+
+	fn find_bodies(&mut self, graph: &DataFlowGraph) {
+		for &function in self.functions.values() {
+			self.bodies.insert(function, Vec::new());
+		}
+
+		for id in 0..graph.len().try_into().unwrap() {
+			let lambda = self
+				.find_scope(graph, id)
+				.and_then(|scope| self.find_lambda(graph, scope));
+
+			if let Some(lambda) = lambda
+				&& let Some(body) = self.bodies.get_mut(&lambda)
+			{
+				body.push(id);
+			}
+		}
+	}
+
+	fn find_callee(&self, graph: &DataFlowGraph, call: &Call) -> Option<u32> {
+		let Node::GlobalGet(GlobalGet { source }) = graph.get(call.function.0) else {
+			return None;
+		};
+
+		self.functions.get(&find_root(graph, *source)).copied()
+	}
+
+	// Finds the functions on a cycle of calls, as inlining them could go on
+	// forever, using the components of the call graph.
+	fn find_recursive(&mut self, graph: &DataFlowGraph) {
+		let mut calls: HashMap<_, _> = self.bodies.keys().map(|&id| (id, Vec::new())).collect();
+		let mut callers = calls.clone();
+
+		for (&function, body) in &self.bodies {
+			for &id in body {
+				if let Node::Call(call) = graph.get(id)
+					&& let Some(callee) = self.find_callee(graph, call)
+				{
+					calls.get_mut(&function).unwrap().push(callee);
+					callers.get_mut(&callee).unwrap().push(function);
+				}
+			}
+		}
+
+		let mut seen = HashSet::new();
+		let mut post = Vec::new();
+
+		for &function in calls.keys() {
+			visit_functions(&calls, function, &mut seen, &mut post);
+		}
+
+		let mut component = Vec::new();
+
+		seen.clear();
+
+		while let Some(function) = post.pop() {
+			visit_functions(&callers, function, &mut seen, &mut component);
+
+			if component.len() > 1 || calls[&function].contains(&function) {
+				self.recursive.extend(component.iter().copied());
+			}
+
+			component.clear();
+		}
+	}
+
+	// Functions that were inlined into are skipped until the next run, as
+	// the nodes that they gained are not part of their bodies yet. Tail
+	// calls in the callee are only kept when the call is a tail call too.
+	fn is_inlinable(&self, graph: &DataFlowGraph, function: u32, tail: bool) -> bool {
+		let body = &self.bodies[&function];
+
+		body.len() <= self.threshold
+			&& !self.changed.contains(&function)
+			&& !self.recursive.contains(&function)
+			&& body.iter().all(|&id| match graph.get(id) {
+				Node::Host(_) => false,
+				Node::Call(call) => tail || !call.tail,
+
+				_ => true,
+			})
+	}
+
+	fn has_tail_call(&self, graph: &DataFlowGraph, function: u32) -> bool {
+		self.bodies[&function]
+			.iter()
+			.any(|&id| matches!(graph.get(id), Node::Call(Call { tail: true, .. })))
+	}
+
+	// A tail call copied from the callee ends the caller where it is, so the
+	// states that the callee does not share must be left as they came in.
+	fn is_tail_safe(graph: &DataFlowGraph, call: &Call, shared: &[Option<usize>]) -> bool {
+		let states = &call.arguments[call.arguments.len() - shared.len()..];
+
+		shared.iter().zip(states).all(|(port, state)| {
+			port.is_some()
+				|| matches!(
+					graph.get(state.0),
+					Node::LambdaIn(_) | Node::RegionIn(_) | Node::ThetaIn(_)
+				)
+		})
+	}
+
+	// Returns what each input port of the callee is replaced with at the
+	// call, along with the port of the callee dependency that each state of
+	// the call shares a root with.
+	fn load_inputs(
+		graph: &DataFlowGraph,
+		call: &Call,
+		caller: u32,
+		callee: u32,
+	) -> Option<(Vec<Link>, Vec<Option<usize>>)> {
+		let LambdaOut { input, results } = graph.get(callee).as_lambda_out().unwrap();
+		let LambdaIn {
+			r#type,
+			dependencies,
+			..
+		} = graph.get(*input).as_lambda_in().unwrap();
+
+		let caller_in = graph.get(caller).as_lambda_out().unwrap().input;
+		let roots: Vec<_> = graph
+			.get(caller_in)
+			.as_lambda_in()
+			.unwrap()
+			.dependencies
+			.iter()
+			.map(|&dependency| find_root(graph, dependency))
+			.collect();
+
+		let arguments = r#type.arguments.len();
+		let states = usize::from(call.states);
+
+		if usize::from(call.results) != r#type.results.len()
+			|| call.arguments.len() != arguments + states
+			|| states != roots.len() + 1
+			|| results.len() != r#type.results.len() + dependencies.len() + 1
+		{
+			return None;
+		}
+
+		let mut inputs = Vec::with_capacity(dependencies.len() + arguments + 1);
+		let mut shared = vec![None; roots.len()];
+
+		for (port, &dependency) in dependencies.iter().enumerate() {
+			let root = find_root(graph, dependency);
+			let index = roots.iter().position(|&other| other == root)?;
+
+			inputs.push(call.arguments[arguments + 1 + index]);
+			shared[index] = Some(port);
+		}
+
+		inputs.extend_from_slice(&call.arguments[..=arguments]);
+
+		Some((inputs, shared))
+	}
+
+	// Copies the body of the callee to the end of the graph, with links to
+	// its inputs replaced, and returns where each node was copied to.
+	fn copy_body(
+		graph: &mut DataFlowGraph,
+		body: &[u32],
+		input: u32,
+		inputs: &[Link],
+	) -> HashMap<u32, u32> {
+		let start: u32 = graph.len().try_into().unwrap();
+		let ids: HashMap<_, _> = body.iter().copied().zip(start..).collect();
+
+		for &id in body {
+			let mut node = graph.get(id).try_clone().unwrap();
+
+			node.for_each_mut_id(|old| {
+				if let Some(&new) = ids.get(old) {
+					*old = new;
+				}
+			});
+
+			node.for_each_mut_argument(|link| {
+				if link.0 == input {
+					*link = inputs[usize::from(link.1)];
+				}
+			});
+
+			graph.set_position(graph.get_position(id));
+			graph.add_node(node);
+		}
+
+		graph.set_position(None);
+
+		ids
+	}
+
+	fn handle_call(&mut self, graph: &mut DataFlowGraph, call: &Call, id: u32) {
+		let Some(callee) = self.find_callee(graph, call) else {
+			return;
+		};
+
+		let Some(scope) = self.verifier.get_owner(id) else {
+			return;
+		};
+
+		let Some(caller) = self.find_lambda(graph, scope) else {
+			return;
+		};
+
+		if call.catch || caller == callee || !self.is_inlinable(graph, callee, call.tail) {
+			return;
+		}
+
+		let Some((inputs, shared)) = Self::load_inputs(graph, call, caller, callee) else {
+			return;
+		};
+
+		if call.tail
+			&& self.has_tail_call(graph, callee)
+			&& !Self::is_tail_safe(graph, call, &shared)
+		{
+			return;
+		}
+
+		let LambdaOut { input, results } = graph.get(callee).as_lambda_out().unwrap().clone();
+		let ids = Self::copy_body(graph, &self.bodies[&callee], input, &inputs);
+		let load = |link: Link| {
+			if link.0 == input {
+				inputs[usize::from(link.1)]
+			} else {
+				ids.get(&link.0).map_or(link, |&id| Link(id, link.1))
+			}
+		};
+
+		let values = usize::from(call.results);
+		let arguments = call.arguments.len() - usize::from(call.states);
+		let outputs = results[..values]
+			.iter()
+			.copied()
+			.chain(core::iter::once(results[results.len() - 1]))
+			.map(load)
+			.chain(
+				shared
+					.iter()
+					.zip(&call.arguments[arguments + 1..])
+					.map(|(port, &state)| port.map_or(state, |port| load(results[values + port]))),
+			);
+
+		for (output, port) in outputs.zip(0..) {
+			self.map.insert(Link(id, port), output);
+		}
+
+		self.changed.insert(caller);
+	}
+
+	/// Returns whether the last run inlined any call.
+	#[must_use]
+	pub fn has_changed(&self) -> bool {
+		!self.changed.is_empty()
+	}
+
+	// Copies are added after the rest of the graph, so it is sorted again
+	// for the passes that expect their inputs to come first.
+	pub fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> u32 {
+		self.functions.clear();
+		self.bodies.clear();
+		self.recursive.clear();
+		self.changed.clear();
+		self.map.clear();
+
+		if self.verifier.run(graph, omega).is_err() {
+			return omega;
+		}
+
+		self.find_functions(graph, omega);
+		self.find_bodies(graph);
+		self.find_recursive(graph);
+
+		for id in 0..graph.len().try_into().unwrap() {
+			if let Node::Call(call) = graph.get(id) {
+				let call = call.clone();
+
+				self.handle_call(graph, &call, id);
+			}
+		}
+
+		for node in graph.nodes_mut() {
+			node.for_each_mut_argument(|link| *link = self.get_reference(*link));
+		}
+
+		if self.changed.is_empty() {
+			return omega;
+		}
+
+		self.normalizer.run(graph, omega)
+	}
+}
+
+impl Default for Inliner {
+	fn default() -> Self {
+		Self::new()
+	}
+}
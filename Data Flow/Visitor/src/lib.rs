@@ -1,5 +1,12 @@
+pub mod constant_folder;
+pub mod dead_item_eliminator;
 pub mod dead_port_eliminator;
 pub mod fallthrough_mover;
+pub mod inliner;
+pub mod loop_invariant_mover;
+pub mod pass_manager;
 pub mod region_identity;
 pub mod successor_finder;
 pub mod topological_normalizer;
+pub mod value_numberer;
+pub mod verifier;
@@ -0,0 +1,242 @@
+use data_flow_graph::{
+	DataFlowGraph, Link, Node,
+	mvp::{IntegerBinaryOperation, IntegerBinaryOperator, NumberTruncateToInteger},
+	nested::{ThetaIn, ThetaOut},
+};
+use hashbrown::HashMap;
+
+// Operations that cannot trap, as moving one out of a loop would
+// otherwise run it before the effects that precede it in the body.
+const fn is_movable(node: &Node) -> bool {
+	match node {
+		Node::IntegerBinaryOperation(IntegerBinaryOperation { operator, .. }) => !matches!(
+			operator,
+			IntegerBinaryOperator::Divide { .. } | IntegerBinaryOperator::Remainder { .. }
+		),
+		Node::NumberTruncateToInteger(NumberTruncateToInteger { saturate, .. }) => *saturate,
+
+		Node::RefIsNull(_)
+		| Node::IntegerUnaryOperation(_)
+		| Node::IntegerCompareOperation(_)
+		| Node::IntegerNarrow(_)
+		| Node::IntegerWiden(_)
+		| Node::IntegerExtend(_)
+		| Node::IntegerConvertToNumber(_)
+		| Node::IntegerTransmuteToNumber(_)
+		| Node::NumberUnaryOperation(_)
+		| Node::NumberBinaryOperation(_)
+		| Node::NumberCompareOperation(_)
+		| Node::NumberNarrow(_)
+		| Node::NumberWiden(_)
+		| Node::NumberTransmuteToInteger(_)
+		| Node::V128UnaryOperation(_)
+		| Node::V128BinaryOperation(_)
+		| Node::V128ReduceOperation(_)
+		| Node::V128Splat(_)
+		| Node::V128ExtractLane(_)
+		| Node::V128ReplaceLane(_)
+		| Node::V128Shuffle(_)
+		| Node::V128BitSelect(_) => true,
+
+		_ => false,
+	}
+}
+
+const fn copy_constant(node: &Node) -> Option<Node> {
+	let constant = match *node {
+		Node::Null => Node::Null,
+		Node::I32(value) => Node::I32(value),
+		Node::I64(value) => Node::I64(value),
+		Node::F32(value) => Node::F32(value),
+		Node::F64(value) => Node::F64(value),
+		Node::V128(value) => Node::V128(value),
+		_ => return None,
+	};
+
+	Some(constant)
+}
+
+struct Theta {
+	invariant: Vec<bool>,
+	added: Vec<Link>,
+}
+
+/// Moves operations out of loops when they only depend on arguments
+/// that every iteration passes back unchanged.
+///
+/// Moved operations reach the body through new arguments of the loop,
+/// and the constants that they use are copied out of it.
+pub struct LoopInvariantMover {
+	thetas: HashMap<u32, Theta>,
+	moved: HashMap<u32, u32>,
+	ports: HashMap<Link, Link>,
+	constants: HashMap<u32, u32>,
+}
+
+impl LoopInvariantMover {
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			thetas: HashMap::new(),
+			moved: HashMap::new(),
+			ports: HashMap::new(),
+			constants: HashMap::new(),
+		}
+	}
+
+	// Arguments are invariant when the body passes them back unchanged.
+	fn handle_theta_out(&mut self, theta_out: &ThetaOut) {
+		let ThetaOut { input, results, .. } = theta_out;
+		let invariant: Vec<_> = results
+			.iter()
+			.zip(0..)
+			.map(|(&result, port)| result == Link(*input, port))
+			.collect();
+
+		if invariant.contains(&true) {
+			self.thetas.insert(
+				*input,
+				Theta {
+					invariant,
+					added: Vec::new(),
+				},
+			);
+		}
+	}
+
+	// Returns the `ThetaIn` of the loop that the operation can be moved out
+	// of, which is the one that all of its inputs agree on.
+	fn find_theta(&self, graph: &DataFlowGraph, node: &Node) -> Option<u32> {
+		if !is_movable(node) {
+			return None;
+		}
+
+		let mut theta = None;
+		let mut valid = true;
+
+		node.for_each_argument(|Link(id, port)| {
+			let owner = if let Some(Theta { invariant, .. }) = self.thetas.get(&id) {
+				valid &= invariant[usize::from(port)];
+
+				id
+			} else if let Some(&owner) = self.moved.get(&id) {
+				owner
+			} else {
+				valid &= copy_constant(graph.get(id)).is_some();
+
+				return;
+			};
+
+			valid &= *theta.get_or_insert(owner) == owner;
+		});
+
+		theta.filter(|_| valid)
+	}
+
+	// Links from the body to a moved operation are passed in through a
+	// new argument of its loop, shared by all of them.
+	fn load_inner(&mut self, link: Link) -> Link {
+		let Some(&theta_in) = self.moved.get(&link.0) else {
+			return link;
+		};
+
+		*self.ports.entry(link).or_insert_with(|| {
+			let Theta { invariant, added } = self.thetas.get_mut(&theta_in).unwrap();
+			let port = (invariant.len() + added.len()).try_into().unwrap();
+
+			added.push(link);
+
+			Link(theta_in, port)
+		})
+	}
+
+	// Links from a moved operation are taken from outside of its loop.
+	fn load_outer(&mut self, graph: &mut DataFlowGraph, link: Link) -> Link {
+		if let Some(ThetaIn { arguments, .. }) = graph.get(link.0).as_theta_in() {
+			let argument = arguments[usize::from(link.1)];
+
+			return self.load_inner(argument);
+		}
+
+		if self.moved.contains_key(&link.0) {
+			return link;
+		}
+
+		let copy = *self.constants.entry(link.0).or_insert_with(|| {
+			let constant = copy_constant(graph.get(link.0)).unwrap();
+
+			graph.add_node(constant)
+		});
+
+		Link(copy, link.1)
+	}
+
+	fn add_arguments(&mut self, graph: &mut DataFlowGraph) {
+		for (&input, Theta { added, .. }) in &mut self.thetas {
+			if added.is_empty() {
+				continue;
+			}
+
+			let ThetaIn { output, arguments } = graph.get_mut(input).as_mut_theta_in().unwrap();
+			let output = *output;
+			let start = arguments.len();
+
+			arguments.extend_from_slice(added);
+
+			let ThetaOut { results, .. } = graph.get_mut(output).as_mut_theta_out().unwrap();
+			let ports = start..start + added.len();
+
+			results.extend(ports.map(|port| Link(input, port.try_into().unwrap())));
+		}
+	}
+
+	// Nodes are visited in order so that, in a topologically sorted graph,
+	// the inputs of an operation are moved before it is. Returns whether any
+	// operation was moved.
+	pub fn run(&mut self, graph: &mut DataFlowGraph) -> bool {
+		self.thetas.clear();
+		self.moved.clear();
+		self.ports.clear();
+		self.constants.clear();
+
+		for node in graph.nodes() {
+			if let Node::ThetaOut(theta_out) = node {
+				self.handle_theta_out(theta_out);
+			}
+		}
+
+		if self.thetas.is_empty() {
+			return false;
+		}
+
+		let len = graph.len().try_into().unwrap();
+
+		for id in 0..len {
+			if let Some(theta) = self.find_theta(graph, graph.get(id)) {
+				self.moved.insert(id, theta);
+			}
+		}
+
+		for id in 0..len {
+			let mut node = std::mem::take(graph.get_mut(id));
+
+			if self.moved.contains_key(&id) {
+				node.for_each_mut_argument(|link| *link = self.load_outer(graph, *link));
+			} else {
+				node.for_each_mut_argument(|link| *link = self.load_inner(*link));
+			}
+
+			*graph.get_mut(id) = node;
+		}
+
+		self.add_arguments(graph);
+
+		!self.moved.is_empty()
+	}
+}
+
+impl Default for LoopInvariantMover {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,265 @@
+use std::{
+	fmt::{Display, Formatter},
+	time::{Duration, Instant},
+};
+
+use data_flow_graph::{DataFlowGraph, Link};
+
+use crate::{
+	constant_folder::ConstantFolder, dead_item_eliminator::DeadItemEliminator,
+	dead_port_eliminator::DeadPortEliminator, fallthrough_mover::FallthroughMover,
+	inliner::Inliner, loop_invariant_mover::LoopInvariantMover, region_identity,
+	topological_normalizer::TopologicalNormalizer, value_numberer::ValueNumberer,
+	verifier::Verifier,
+};
+
+/// A transformation over a whole graph.
+pub trait Pass {
+	/// Runs the pass over the `graph` with its `OmegaOut` at `omega`,
+	/// returning the id that it has afterwards and whether the pass
+	/// changed the graph.
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool);
+}
+
+impl Pass for TopologicalNormalizer {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		let omega = Self::run(self, graph, omega);
+
+		(omega, self.has_changed())
+	}
+}
+
+impl Pass for FallthroughMover {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, Self::run(self, graph))
+	}
+}
+
+impl Pass for ConstantFolder {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, Self::run(self, graph))
+	}
+}
+
+impl Pass for ValueNumberer {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, Self::run(self, graph))
+	}
+}
+
+impl Pass for LoopInvariantMover {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, Self::run(self, graph))
+	}
+}
+
+impl Pass for Inliner {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		let omega = Self::run(self, graph, omega);
+
+		(omega, self.has_changed())
+	}
+}
+
+impl Pass for DeadItemEliminator {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, Self::run(self, graph, omega))
+	}
+}
+
+impl Pass for DeadPortEliminator {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, Self::run(self, graph, Link(omega, 0)))
+	}
+}
+
+pub struct IdentityInserter;
+
+impl Pass for IdentityInserter {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, region_identity::insert(graph))
+	}
+}
+
+pub struct IdentityRemover;
+
+impl Pass for IdentityRemover {
+	fn run(&mut self, graph: &mut DataFlowGraph, omega: u32) -> (u32, bool) {
+		(omega, region_identity::remove(graph))
+	}
+}
+
+type Constructor = Box<dyn Fn() -> Box<dyn Pass>>;
+
+/// A set of passes that can be referred to by name.
+pub struct Registry {
+	passes: Vec<(&'static str, Constructor)>,
+}
+
+impl Registry {
+	#[must_use]
+	pub const fn new() -> Self {
+		Self { passes: Vec::new() }
+	}
+
+	#[must_use]
+	pub fn with_built_ins() -> Self {
+		let mut registry = Self::new();
+
+		registry.add("inline", || Box::new(Inliner::new()));
+		registry.add("prune", || Box::new(DeadItemEliminator::new()));
+		registry.add("fold", || Box::new(ConstantFolder::new()));
+		registry.add("normalize", || Box::new(TopologicalNormalizer::new()));
+		registry.add("gvn", || Box::new(ValueNumberer::new()));
+		registry.add("licm", || Box::new(LoopInvariantMover::new()));
+		registry.add("fallthrough", || Box::new(FallthroughMover::new()));
+		registry.add("dce", || Box::new(DeadPortEliminator::new()));
+		registry.add("insert-identity", || Box::new(IdentityInserter));
+		registry.add("remove-identity", || Box::new(IdentityRemover));
+
+		registry
+	}
+
+	/// Adds a pass under `name`, replacing any that was there before.
+	pub fn add<C>(&mut self, name: &'static str, constructor: C)
+	where
+		C: Fn() -> Box<dyn Pass> + 'static,
+	{
+		self.passes.retain(|&(other, _)| other != name);
+		self.passes.push((name, Box::new(constructor)));
+	}
+
+	pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+		self.passes.iter().map(|&(name, _)| name)
+	}
+
+	fn find(&self, name: &str) -> Option<&(&'static str, Constructor)> {
+		self.passes.iter().find(|&&(other, _)| other == name)
+	}
+}
+
+impl Default for Registry {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// The error of a pipeline naming a pass that is not registered.
+#[derive(Debug)]
+pub struct UnknownPass {
+	pub name: String,
+}
+
+impl Display for UnknownPass {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "`{}` is not a known pass", self.name)
+	}
+}
+
+impl std::error::Error for UnknownPass {}
+
+struct Entry {
+	name: &'static str,
+	pass: Box<dyn Pass>,
+	elapsed: Duration,
+}
+
+/// An ordered list of passes, along with the time spent in each.
+///
+/// In debug builds, the graph is verified after every pass.
+pub struct Pipeline {
+	entries: Vec<Entry>,
+	verifier: Verifier,
+}
+
+impl Pipeline {
+	// Guards against passes that keep changing the graph back and forth.
+	const FIXED_POINT_LIMIT: usize = 64;
+
+	/// Parses a comma separated list of pass names, such as
+	/// `normalize,fallthrough,dce`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if any name is not in the `registry`.
+	pub fn parse(source: &str, registry: &Registry) -> Result<Self, UnknownPass> {
+		let entries = source
+			.split(',')
+			.map(str::trim)
+			.filter(|name| !name.is_empty())
+			.map(|name| {
+				let &(name, ref constructor) = registry
+					.find(name)
+					.ok_or_else(|| UnknownPass { name: name.into() })?;
+
+				Ok(Entry {
+					name,
+					pass: constructor(),
+					elapsed: Duration::ZERO,
+				})
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self {
+			entries,
+			verifier: Verifier::new(),
+		})
+	}
+
+	// Runs every pass once, returning whether any of them changed the graph.
+	fn run_once(&mut self, graph: &mut DataFlowGraph, omega: &mut u32) -> bool {
+		let mut changed = false;
+
+		for entry in &mut self.entries {
+			let start = Instant::now();
+			let (next, progress) = entry.pass.run(graph, *omega);
+
+			*omega = next;
+			changed |= progress;
+			entry.elapsed += start.elapsed();
+
+			if cfg!(debug_assertions)
+				&& let Err(error) = self.verifier.run(graph, *omega)
+			{
+				panic!(
+					"graph should be well formed after `{}`, {error}",
+					entry.name
+				);
+			}
+		}
+
+		changed
+	}
+
+	/// Runs every pass once, in order.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if a pass leaves the graph malformed.
+	pub fn run(&mut self, graph: &mut DataFlowGraph, mut omega: u32) -> u32 {
+		self.run_once(graph, &mut omega);
+
+		omega
+	}
+
+	/// Runs the whole pipeline repeatedly until none of its passes change
+	/// the graph.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if a pass leaves the graph malformed.
+	pub fn run_to_fixed_point(&mut self, graph: &mut DataFlowGraph, mut omega: u32) -> u32 {
+		for _ in 0..Self::FIXED_POINT_LIMIT {
+			if !self.run_once(graph, &mut omega) {
+				break;
+			}
+		}
+
+		omega
+	}
+
+	/// Returns the total time spent in each pass, in pipeline order.
+	pub fn timings(&self) -> impl Iterator<Item = (&'static str, Duration)> + '_ {
+		self.entries.iter().map(|entry| (entry.name, entry.elapsed))
+	}
+}
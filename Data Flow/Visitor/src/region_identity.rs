@@ -4,15 +4,20 @@ use data_flow_graph::{
 	nested::{RegionOut, ThetaIn, ThetaOut},
 };
 
-fn replace_with_producer(graph: &DataFlowGraph, from: &mut Link) {
+fn replace_with_producer(graph: &DataFlowGraph, from: &mut Link) -> bool {
 	let Node::Identity(Identity { source }) = *graph.get(from.0) else {
-		return;
+		return false;
 	};
 
 	*from = source;
+
+	true
 }
 
-pub fn remove(graph: &mut DataFlowGraph) {
+/// Returns whether any identity was bypassed.
+pub fn remove(graph: &mut DataFlowGraph) -> bool {
+	let mut changed = false;
+
 	for id in 0..graph.len().try_into().unwrap() {
 		let mut node = std::mem::take(graph.get_mut(id));
 
@@ -20,11 +25,15 @@ pub fn remove(graph: &mut DataFlowGraph) {
 			node,
 			Node::RegionOut(_) | Node::ThetaIn(_) | Node::ThetaOut(_)
 		) {
-			node.for_each_mut_argument(|argument| replace_with_producer(graph, argument));
+			node.for_each_mut_argument(|argument| {
+				changed |= replace_with_producer(graph, argument);
+			});
 		}
 
 		*graph.get_mut(id) = node;
 	}
+
+	changed
 }
 
 fn replace_with_identity(graph: &mut DataFlowGraph, from: &mut Link) {
@@ -71,12 +80,17 @@ fn insert_at(graph: &mut DataFlowGraph, node: &mut Node) {
 	}
 }
 
-pub fn insert(graph: &mut DataFlowGraph) {
-	for id in 0..graph.len().try_into().unwrap() {
+/// Returns whether any identity was inserted.
+pub fn insert(graph: &mut DataFlowGraph) -> bool {
+	let len = graph.len();
+
+	for id in 0..len.try_into().unwrap() {
 		let mut node = std::mem::take(graph.get_mut(id));
 
 		insert_at(graph, &mut node);
 
 		*graph.get_mut(id) = node;
 	}
+
+	graph.len() != len
 }
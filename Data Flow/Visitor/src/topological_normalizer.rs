@@ -53,7 +53,9 @@ impl DepthFirstSearcher {
 
 pub struct TopologicalNormalizer {
 	nodes: Vec<Node>,
+	positions: Vec<Option<u32>>,
 	id_to_post: Vec<u32>,
+	changed: bool,
 
 	depth_first_searcher: DepthFirstSearcher,
 }
@@ -63,7 +65,9 @@ impl TopologicalNormalizer {
 	pub const fn new() -> Self {
 		Self {
 			nodes: Vec::new(),
+			positions: Vec::new(),
 			id_to_post: Vec::new(),
+			changed: false,
 
 			depth_first_searcher: DepthFirstSearcher::new(),
 		}
@@ -73,6 +77,7 @@ impl TopologicalNormalizer {
 		let mut post = 0;
 
 		self.nodes.clear();
+		self.positions.clear();
 		self.id_to_post.clear();
 		self.id_to_post.resize(graph.len(), u32::MAX);
 
@@ -80,12 +85,17 @@ impl TopologicalNormalizer {
 			let node = std::mem::take(graph.get_mut(id));
 
 			self.nodes.push(node);
+			self.positions.push(graph.get_position(id));
 			self.id_to_post[usize::try_from(id).unwrap()] = post;
+			self.changed |= id != post;
 
 			post += 1;
 		});
 
+		self.changed |= self.nodes.len() != graph.len();
+
 		std::mem::swap(graph.inner_mut(), &mut self.nodes);
+		std::mem::swap(graph.positions_mut(), &mut self.positions);
 	}
 
 	fn handle_edges(&self, graph: &mut DataFlowGraph, result: u32) -> u32 {
@@ -100,7 +110,14 @@ impl TopologicalNormalizer {
 		self.id_to_post[usize::try_from(result).unwrap()]
 	}
 
+	/// Returns whether the last run moved or removed any node.
+	#[must_use]
+	pub const fn has_changed(&self) -> bool {
+		self.changed
+	}
+
 	pub fn run(&mut self, graph: &mut DataFlowGraph, result: u32) -> u32 {
+		self.changed = false;
 		self.handle_nodes(graph, result);
 		self.handle_edges(graph, result)
 	}
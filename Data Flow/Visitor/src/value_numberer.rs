@@ -0,0 +1,146 @@
+use data_flow_graph::{
+	DataFlowGraph, Node,
+	mvp::{
+		GlobalGet, IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber,
+		IntegerExtend, IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation,
+		IntegerWiden, MemoryLoad, MemorySize, NumberBinaryOperation, NumberCompareOperation,
+		NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation,
+		NumberWiden, RefIsNull, TableGet, TableSize, V128BinaryOperation, V128BitSelect,
+		V128ExtractLane, V128ReduceOperation, V128ReplaceLane, V128Shuffle, V128Splat,
+		V128UnaryOperation,
+	},
+};
+use hashbrown::{HashMap, hash_map::Entry};
+
+macro_rules! define_key {
+	($($name:ident),* $(,)?) => {
+		#[derive(PartialEq, Eq, Hash)]
+		enum Key {
+			$($name($name),)*
+		}
+
+		impl Key {
+			const fn load(node: &Node) -> Option<Self> {
+				let key = match *node {
+					$(Node::$name(node) => Self::$name(node),)*
+					_ => return None,
+				};
+
+				Some(key)
+			}
+		}
+	};
+}
+
+// Reads of globals, tables, and memories take the state they read from
+// as an input, so two of them are only equal when no write happened in
+// between. Constants are printed inline and gain nothing from sharing.
+define_key![
+	RefIsNull,
+	IntegerUnaryOperation,
+	IntegerBinaryOperation,
+	IntegerCompareOperation,
+	IntegerNarrow,
+	IntegerWiden,
+	IntegerExtend,
+	IntegerConvertToNumber,
+	IntegerTransmuteToNumber,
+	NumberUnaryOperation,
+	NumberBinaryOperation,
+	NumberCompareOperation,
+	NumberNarrow,
+	NumberWiden,
+	NumberTruncateToInteger,
+	NumberTransmuteToInteger,
+	V128UnaryOperation,
+	V128BinaryOperation,
+	V128ReduceOperation,
+	V128Splat,
+	V128ExtractLane,
+	V128ReplaceLane,
+	V128Shuffle,
+	V128BitSelect,
+	GlobalGet,
+	TableGet,
+	TableSize,
+	MemoryLoad,
+	MemorySize,
+];
+
+/// Merges operations that compute the same value from the same inputs.
+///
+/// Links never reach into another region, so operations with equal inputs
+/// always share one and can be merged without looking at where they are.
+pub struct ValueNumberer {
+	keys: HashMap<Key, u32>,
+	map: HashMap<u32, u32>,
+}
+
+impl ValueNumberer {
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			keys: HashMap::new(),
+			map: HashMap::new(),
+		}
+	}
+
+	fn get_reference(&self, id: u32) -> u32 {
+		self.map.get(&id).copied().unwrap_or(id)
+	}
+
+	fn replace_arguments(&self, node: &mut Node) -> bool {
+		let mut changed = false;
+
+		node.for_each_mut_argument(|link| {
+			let reference = self.get_reference(link.0);
+
+			changed |= reference != link.0;
+			link.0 = reference;
+		});
+
+		changed
+	}
+
+	// Arguments are renamed to the node they were merged into before the key
+	// is built, so an operation on two duplicates matches the one on their
+	// originals. Links that point forward are only renamed by the last sweep.
+	// Returns whether any link was renamed.
+	pub fn run(&mut self, graph: &mut DataFlowGraph) -> bool {
+		let mut changed = false;
+
+		self.keys.clear();
+		self.map.clear();
+
+		for id in 0..graph.len().try_into().unwrap() {
+			let node = graph.get_mut(id);
+
+			changed |= self.replace_arguments(node);
+
+			let Some(key) = Key::load(node) else {
+				continue;
+			};
+
+			match self.keys.entry(key) {
+				Entry::Occupied(entry) => {
+					self.map.insert(id, *entry.get());
+				}
+				Entry::Vacant(entry) => {
+					entry.insert(id);
+				}
+			}
+		}
+
+		for node in graph.nodes_mut() {
+			changed |= self.replace_arguments(node);
+		}
+
+		changed
+	}
+}
+
+impl Default for ValueNumberer {
+	fn default() -> Self {
+		Self::new()
+	}
+}
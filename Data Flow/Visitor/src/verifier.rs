@@ -0,0 +1,428 @@
+use std::fmt::{Display, Formatter};
+
+use data_flow_graph::{
+	DataFlowGraph, Link, Node,
+	nested::{
+		GammaIn, GammaOut, LambdaIn, LambdaOut, OmegaOut, RegionIn, RegionOut, ThetaIn, ThetaOut,
+	},
+};
+
+/// The error of a graph that is not well formed, along with the
+/// node that is at fault.
+#[derive(Debug)]
+pub struct VerifyError {
+	pub id: u32,
+	pub message: String,
+}
+
+impl Display for VerifyError {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "node {}: {}", self.id, self.message)
+	}
+}
+
+impl std::error::Error for VerifyError {}
+
+fn fail<T>(id: u32, message: String) -> Result<T, VerifyError> {
+	Err(VerifyError { id, message })
+}
+
+// Returns the amount of output ports of a node, if it is known.
+fn ports_output(graph: &DataFlowGraph, node: &Node) -> Option<usize> {
+	let result = match node {
+		Node::LambdaIn(lambda_in) => usize::from(lambda_in.output_ports().end) + 1,
+		Node::RegionIn(region_in) => graph.get(region_in.input).as_gamma_in()?.arguments.len(),
+		Node::GammaOut(gamma_out) => {
+			let first = *gamma_out.regions.first()?;
+
+			graph.get(first).as_region_out()?.results.len()
+		}
+		Node::ThetaIn(theta_in) => theta_in.arguments.len(),
+		Node::ThetaOut(theta_out) => theta_out.results.len(),
+		Node::Call(call) => usize::from(call.results) + usize::from(call.states),
+		Node::Throw(throw) => throw.states.len(),
+
+		Node::GammaIn(_) | Node::RegionOut(_) | Node::OmegaOut(_) => 0,
+
+		Node::OmegaIn(_)
+		| Node::GlobalGet(_)
+		| Node::TableGet(_)
+		| Node::TableSize(_)
+		| Node::TableGrow(_)
+		| Node::TableCopy(_)
+		| Node::TableInit(_)
+		| Node::MemoryLoad(_)
+		| Node::MemorySize(_)
+		| Node::MemoryGrow(_)
+		| Node::MemoryCopy(_)
+		| Node::MemoryInit(_) => 2,
+
+		Node::Host(_) => return None,
+
+		_ => 1,
+	};
+
+	Some(result)
+}
+
+struct Use {
+	from: u32,
+	to: Link,
+	scope: u32,
+}
+
+/// Checks that the part of a graph reachable from its `OmegaOut` is well formed.
+///
+/// Every link must point at an existing node and one of its output ports,
+/// every `In` and `Out` pair must reference each other, and no link may
+/// reach into another region. Nodes that are no longer reachable are not
+/// checked, as passes are free to leave them stale until the next
+/// normalization.
+pub struct Verifier {
+	owners: Vec<u32>,
+	stack: Vec<Use>,
+}
+
+impl Verifier {
+	const UNOWNED: u32 = u32::MAX;
+
+	#[must_use]
+	pub const fn new() -> Self {
+		Self {
+			owners: Vec::new(),
+			stack: Vec::new(),
+		}
+	}
+
+	fn get(graph: &DataFlowGraph, from: u32, id: u32) -> Result<&Node, VerifyError> {
+		if usize::try_from(id).unwrap() < graph.len() {
+			Ok(graph.get(id))
+		} else {
+			fail(from, format!("references missing node {id}"))
+		}
+	}
+
+	// Returns the `In` node whose ports are visible inside of `scope`.
+	fn get_start(graph: &DataFlowGraph, scope: u32) -> u32 {
+		match graph.get(scope) {
+			Node::LambdaOut(lambda_out) => lambda_out.input,
+			Node::RegionOut(region_out) => region_out.input,
+			Node::ThetaOut(theta_out) => theta_out.input,
+			Node::OmegaOut(omega_out) => omega_out.input,
+
+			_ => unreachable!("node {scope} should be a scope"),
+		}
+	}
+
+	fn add_uses(&mut self, links: &[Link], from: u32, scope: u32) {
+		let uses = links.iter().map(|&to| Use { from, to, scope });
+
+		self.stack.extend(uses);
+	}
+
+	fn handle_lambda(
+		&mut self,
+		graph: &DataFlowGraph,
+		lambda_out: &LambdaOut,
+		id: u32,
+		scope: u32,
+	) -> Result<(), VerifyError> {
+		let LambdaOut { input, results } = lambda_out;
+		let Some(LambdaIn {
+			output,
+			r#type,
+			dependencies,
+			..
+		}) = Self::get(graph, id, *input)?.as_lambda_in()
+		else {
+			return fail(id, format!("has input {input}, which is not a `LambdaIn`"));
+		};
+
+		if *output != id {
+			return fail(*input, format!("has output {output}, expected {id}"));
+		}
+
+		if results.len() < r#type.results.len() {
+			return fail(
+				id,
+				format!(
+					"has {} results, expected at least {}",
+					results.len(),
+					r#type.results.len()
+				),
+			);
+		}
+
+		self.add_uses(dependencies, *input, scope);
+		self.add_uses(results, id, id);
+
+		Ok(())
+	}
+
+	fn handle_region(
+		graph: &DataFlowGraph,
+		region: u32,
+		gamma_in: u32,
+		gamma_out: u32,
+	) -> Result<&RegionOut, VerifyError> {
+		let Some(region_out) = Self::get(graph, gamma_out, region)?.as_region_out() else {
+			return fail(
+				gamma_out,
+				format!("has region {region}, which is not a `RegionOut`"),
+			);
+		};
+
+		let RegionOut { input, output, .. } = region_out;
+
+		if *output != gamma_out {
+			return fail(region, format!("has output {output}, expected {gamma_out}"));
+		}
+
+		let Some(RegionIn {
+			input: start,
+			output,
+		}) = Self::get(graph, region, *input)?.as_region_in()
+		else {
+			return fail(
+				region,
+				format!("has input {input}, which is not a `RegionIn`"),
+			);
+		};
+
+		if *output != region {
+			return fail(*input, format!("has output {output}, expected {region}"));
+		}
+
+		if *start != gamma_in {
+			return fail(*input, format!("has input {start}, expected {gamma_in}"));
+		}
+
+		Ok(region_out)
+	}
+
+	fn handle_gamma(
+		&mut self,
+		graph: &DataFlowGraph,
+		gamma_out: &GammaOut,
+		id: u32,
+		scope: u32,
+	) -> Result<(), VerifyError> {
+		let GammaOut { input, regions } = gamma_out;
+		let Some(GammaIn {
+			output,
+			condition,
+			arguments,
+		}) = Self::get(graph, id, *input)?.as_gamma_in()
+		else {
+			return fail(id, format!("has input {input}, which is not a `GammaIn`"));
+		};
+
+		if *output != id {
+			return fail(*input, format!("has output {output}, expected {id}"));
+		}
+
+		let Some(&first) = regions.first() else {
+			return fail(id, "has no regions".into());
+		};
+
+		let expected = Self::handle_region(graph, first, *input, id)?.results.len();
+
+		for &region in regions {
+			let RegionOut { results, .. } = Self::handle_region(graph, region, *input, id)?;
+
+			if results.len() != expected {
+				return fail(
+					region,
+					format!("has {} results, expected {expected}", results.len()),
+				);
+			}
+
+			self.add_uses(results, region, region);
+		}
+
+		self.add_uses(std::slice::from_ref(condition), *input, scope);
+		self.add_uses(arguments, *input, scope);
+
+		Ok(())
+	}
+
+	fn handle_theta(
+		&mut self,
+		graph: &DataFlowGraph,
+		theta_out: &ThetaOut,
+		id: u32,
+		scope: u32,
+	) -> Result<(), VerifyError> {
+		let ThetaOut {
+			input,
+			condition,
+			results,
+		} = theta_out;
+
+		let Some(ThetaIn { output, arguments }) = Self::get(graph, id, *input)?.as_theta_in()
+		else {
+			return fail(id, format!("has input {input}, which is not a `ThetaIn`"));
+		};
+
+		if *output != id {
+			return fail(*input, format!("has output {output}, expected {id}"));
+		}
+
+		if results.len() != arguments.len() {
+			return fail(
+				id,
+				format!(
+					"has {} results, expected {}",
+					results.len(),
+					arguments.len()
+				),
+			);
+		}
+
+		self.add_uses(arguments, *input, scope);
+		self.add_uses(std::slice::from_ref(condition), id, id);
+		self.add_uses(results, id, id);
+
+		Ok(())
+	}
+
+	fn handle_operation(&mut self, node: &Node, id: u32, scope: u32) {
+		node.for_each_argument(|to| {
+			self.stack.push(Use {
+				from: id,
+				to,
+				scope,
+			})
+		});
+	}
+
+	fn handle_node(
+		&mut self,
+		graph: &DataFlowGraph,
+		node: &Node,
+		id: u32,
+		scope: u32,
+	) -> Result<(), VerifyError> {
+		match node {
+			Node::LambdaOut(lambda_out) => self.handle_lambda(graph, lambda_out, id, scope),
+			Node::GammaOut(gamma_out) => self.handle_gamma(graph, gamma_out, id, scope),
+			Node::ThetaOut(theta_out) => self.handle_theta(graph, theta_out, id, scope),
+
+			node => {
+				self.handle_operation(node, id, scope);
+
+				Ok(())
+			}
+		}
+	}
+
+	// Nodes are checked the first time they are reached, so their ports
+	// are only counted once we know that they are well formed.
+	fn handle_use(&mut self, graph: &DataFlowGraph, current: &Use) -> Result<(), VerifyError> {
+		let &Use {
+			from,
+			to: Link(id, port),
+			scope,
+		} = current;
+
+		let node = Self::get(graph, from, id)?;
+		let is_start = matches!(
+			node,
+			Node::LambdaIn(_) | Node::RegionIn(_) | Node::ThetaIn(_) | Node::OmegaIn(_)
+		);
+
+		if is_start {
+			if id != Self::get_start(graph, scope) {
+				return fail(
+					from,
+					format!("references node {id} from outside of its region"),
+				);
+			}
+		} else {
+			let owner = &mut self.owners[usize::try_from(id).unwrap()];
+
+			if *owner == Self::UNOWNED {
+				*owner = scope;
+
+				self.handle_node(graph, node, id, scope)?;
+			} else if *owner != scope {
+				return fail(
+					from,
+					format!("references node {id} from outside of its region"),
+				);
+			}
+		}
+
+		if let Some(ports) = ports_output(graph, node)
+			&& usize::from(port) >= ports
+		{
+			return fail(
+				from,
+				format!("references port {port} of node {id}, which has {ports}"),
+			);
+		}
+
+		Ok(())
+	}
+
+	fn handle_omega(&mut self, graph: &DataFlowGraph, omega: u32) -> Result<(), VerifyError> {
+		let Some(OmegaOut { input, .. }) = Self::get(graph, omega, omega)?.as_omega_out() else {
+			return fail(omega, "is not an `OmegaOut`".into());
+		};
+
+		let Some(omega_in) = Self::get(graph, omega, *input)?.as_omega_in() else {
+			return fail(
+				omega,
+				format!("has input {input}, which is not an `OmegaIn`"),
+			);
+		};
+
+		if omega_in.output != omega {
+			return fail(
+				*input,
+				format!("has output {}, expected {omega}", omega_in.output),
+			);
+		}
+
+		self.owners[usize::try_from(omega).unwrap()] = omega;
+
+		self.handle_operation(graph.get(omega), omega, omega);
+
+		Ok(())
+	}
+
+	/// Verifies the `graph` with its `OmegaOut` at `omega`.
+	///
+	/// # Errors
+	///
+	/// Returns an error naming the first node found to be malformed.
+	pub fn run(&mut self, graph: &DataFlowGraph, omega: u32) -> Result<(), VerifyError> {
+		self.owners.clear();
+		self.owners.resize(graph.len(), Self::UNOWNED);
+		self.stack.clear();
+
+		self.handle_omega(graph, omega)?;
+
+		while let Some(current) = self.stack.pop() {
+			self.handle_use(graph, &current)?;
+		}
+
+		Ok(())
+	}
+
+	/// Returns the `Out` node of the region that the node at `id` was found
+	/// in by the last run. `In` nodes and nodes that were not reached have
+	/// no owner.
+	#[must_use]
+	pub fn get_owner(&self, id: u32) -> Option<u32> {
+		let owner = *self.owners.get(usize::try_from(id).unwrap())?;
+
+		(owner != Self::UNOWNED).then_some(owner)
+	}
+}
+
+impl Default for Verifier {
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,95 @@
+use std::{fs, path::Path};
+
+use data_flow_graph::{Syntax, Text};
+use data_flow_visitor::{
+	inliner::Inliner,
+	pass_manager::{Pipeline, Registry},
+};
+use pretty_assertions::assert_eq;
+
+// Each fixture starts with a `# passes:` line naming the passes to run,
+// optionally followed by an `# inline-threshold:` line, then by the graph
+// before them and, after an `# expected` line, the graph that they leave.
+// Graphs have their `OmegaOut` as their last node.
+struct Fixture<'source> {
+	passes: &'source str,
+	threshold: Option<usize>,
+	source: &'source str,
+	expected: &'source str,
+}
+
+impl<'source> Fixture<'source> {
+	fn parse(text: &'source str) -> Self {
+		let (header, rest) = text.split_once('\n').unwrap();
+		let passes = header
+			.strip_prefix("# passes: ")
+			.expect("fixture should start with its passes");
+
+		let threshold = rest
+			.split_once('\n')
+			.and_then(|(line, rest)| Some((line.strip_prefix("# inline-threshold: ")?, rest)));
+
+		let (threshold, rest) = match threshold {
+			Some((threshold, rest)) => {
+				let threshold = threshold
+					.parse()
+					.expect("inline threshold should be a number");
+
+				(Some(threshold), rest)
+			}
+			None => (None, rest),
+		};
+
+		let (source, expected) = rest
+			.split_once("# expected\n")
+			.expect("fixture should have an expected graph");
+
+		Self {
+			passes,
+			threshold,
+			source,
+			expected,
+		}
+	}
+
+	// Graphs are verified after every pass in debug builds.
+	fn run(&self) -> String {
+		let mut registry = Registry::with_built_ins();
+
+		if let Some(threshold) = self.threshold {
+			registry.add("inline", move || {
+				Box::new(Inliner::with_threshold(threshold))
+			});
+		}
+
+		let mut graph = Syntax::new()
+			.parse(self.source)
+			.expect("fixture should be a valid graph");
+
+		let omega = (graph.len() - 1).try_into().unwrap();
+		let mut pipeline =
+			Pipeline::parse(self.passes, &registry).expect("fixture passes should be registered");
+
+		pipeline.run(&mut graph, omega);
+
+		Text::new(&graph).to_string()
+	}
+}
+
+#[test]
+fn fixtures() {
+	let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+	let mut paths: Vec<_> = fs::read_dir(directory)
+		.unwrap()
+		.map(|entry| entry.unwrap().path())
+		.collect();
+
+	paths.sort_unstable();
+
+	for path in paths {
+		let text = fs::read_to_string(&path).unwrap();
+		let fixture = Fixture::parse(&text);
+
+		assert_eq!(fixture.run(), fixture.expected, "{}", path.display());
+	}
+}
@@ -0,0 +1,45 @@
+use data_flow_graph::Syntax;
+use data_flow_visitor::verifier::Verifier;
+use pretty_assertions::assert_eq;
+
+// A loop that counts down from its first argument.
+const LOOP: &str = include_str!("verifier/loop.dfg");
+
+const RESULT: &str = "12 = lambda_out @3 (11:2, 11:3)";
+
+fn verify(source: &str) -> Result<(), String> {
+	let graph = Syntax::new()
+		.parse(source)
+		.expect("`source` should be a valid graph");
+
+	let omega = (graph.len() - 1).try_into().unwrap();
+
+	Verifier::new()
+		.run(&graph, omega)
+		.map_err(|error| error.to_string())
+}
+
+#[test]
+fn well_formed() {
+	assert_eq!(verify(LOOP), Ok(()));
+}
+
+#[test]
+fn link_into_region() {
+	let source = LOOP.replace(RESULT, "12 = lambda_out @3 (10:0, 11:3)");
+
+	assert_eq!(
+		verify(&source),
+		Err("node 12: references node 10 from outside of its region".into())
+	);
+}
+
+#[test]
+fn missing_port() {
+	let source = LOOP.replace(RESULT, "12 = lambda_out @3 (11:7, 11:3)");
+
+	assert_eq!(
+		verify(&source),
+		Err("node 12: references port 7 of node 11, which has 4".into())
+	);
+}
@@ -6,16 +6,17 @@ use luau_tree::{
 	statement::{
 		Assign, AssignAll, Call, DataDrop, ElementsDrop, FastDefine, GlobalSet, Match, MemoryCopy,
 		MemoryFill, MemoryInit, MemoryStore, Repeat, Sequence, SlowDefine, Statement, TableCopy,
-		TableFill, TableInit, TableSet,
+		TableFill, TableInit, TableSet, Throw,
 	},
 };
 
 use super::data_handler::DataHandler;
 
 pub struct CodeHandler {
-	scopes: Vec<Vec<Statement>>,
+	scopes: Vec<Sequence>,
 
-	list: Vec<Statement>,
+	sequence: Sequence,
+	position: Option<u32>,
 }
 
 impl CodeHandler {
@@ -23,23 +24,46 @@ impl CodeHandler {
 		Self {
 			scopes: Vec::new(),
 
-			list: Vec::new(),
+			sequence: Sequence {
+				list: Vec::new(),
+				positions: Vec::new(),
+			},
+			position: None,
 		}
 	}
 
 	pub fn pop_scope(&mut self) -> Sequence {
 		let parent = self.scopes.pop().unwrap_or_default();
-		let list = core::mem::replace(&mut self.list, parent);
 
-		Sequence { list }
+		core::mem::replace(&mut self.sequence, parent)
 	}
 
 	pub fn push_scope(&mut self) {
-		let parent = core::mem::take(&mut self.list);
+		let parent = core::mem::take(&mut self.sequence);
 
 		self.scopes.push(parent);
 	}
 
+	pub const fn set_position(&mut self, position: Option<u32>) {
+		self.position = position;
+	}
+
+	// Only changes in position are recorded, so each entry covers
+	// every statement up until the next one.
+	fn push(&mut self, statement: Statement) {
+		let Sequence { list, positions } = &mut self.sequence;
+
+		let offset = self
+			.position
+			.filter(|&offset| positions.last().is_none_or(|&(_, last)| last != offset));
+
+		if let Some(offset) = offset {
+			positions.push((list.len(), offset));
+		}
+
+		list.push(statement);
+	}
+
 	pub fn do_match(&mut self, branches: Vec<Sequence>, condition: Expression) {
 		let r#match = Statement::Match(
 			Match {
@@ -49,7 +73,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(r#match);
+		self.push(r#match);
 	}
 
 	pub fn do_repeat(&mut self, code: Sequence, post: AssignAll, condition: Expression) {
@@ -62,19 +86,19 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(repeat);
+		self.push(repeat);
 	}
 
 	pub fn do_fast_define(&mut self, name: Name, source: Expression) {
 		let define = Statement::FastDefine(FastDefine { name, source }.into());
 
-		self.list.push(define);
+		self.push(define);
 	}
 
 	pub fn do_slow_define(&mut self, name: Name, len: u32) {
 		let define = Statement::SlowDefine(SlowDefine { name, len }.into());
 
-		self.list.push(define);
+		self.push(define);
 	}
 
 	pub fn do_assign(&mut self, local: Local, source: Expression) {
@@ -86,7 +110,7 @@ impl CodeHandler {
 
 		let assign = Statement::Assign(Assign { local, source }.into());
 
-		self.list.push(assign);
+		self.push(assign);
 	}
 
 	pub fn do_assign_all(&mut self, assignments: Vec<(Local, Local)>) {
@@ -96,7 +120,7 @@ impl CodeHandler {
 
 		let assign_all = Statement::AssignAll(AssignAll { assignments }.into());
 
-		self.list.push(assign_all);
+		self.push(assign_all);
 	}
 
 	pub fn do_call(
@@ -111,11 +135,13 @@ impl CodeHandler {
 				function: data_handler.load(call.function).unwrap(),
 				arguments: data_handler.load_sources(&call.arguments[..end]),
 				results,
+				tail: call.tail,
+				catch: call.catch,
 			}
 			.into(),
 		);
 
-		self.list.push(call);
+		self.push(call);
 	}
 
 	pub fn do_global_set(&mut self, global_set: mvp::GlobalSet, data_handler: &mut DataHandler) {
@@ -127,7 +153,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(global_set);
+		self.push(global_set);
 	}
 
 	pub fn do_table_set(&mut self, table_set: mvp::TableSet, data_handler: &mut DataHandler) {
@@ -139,7 +165,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(table_set);
+		self.push(table_set);
 	}
 
 	pub fn do_table_fill(&mut self, table_fill: mvp::TableFill, data_handler: &mut DataHandler) {
@@ -152,7 +178,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(table_fill);
+		self.push(table_fill);
 	}
 
 	pub fn do_table_copy(&mut self, table_copy: mvp::TableCopy, data_handler: &mut DataHandler) {
@@ -165,7 +191,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(table_copy);
+		self.push(table_copy);
 	}
 
 	pub fn do_table_init(&mut self, table_init: mvp::TableInit, data_handler: &mut DataHandler) {
@@ -178,7 +204,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(table_init);
+		self.push(table_init);
 	}
 
 	pub fn do_elements_drop(
@@ -193,7 +219,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(elements_drop);
+		self.push(elements_drop);
 	}
 
 	pub fn do_memory_store(
@@ -210,7 +236,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(memory_store);
+		self.push(memory_store);
 	}
 
 	pub fn do_memory_fill(&mut self, memory_fill: mvp::MemoryFill, data_handler: &mut DataHandler) {
@@ -223,7 +249,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(memory_fill);
+		self.push(memory_fill);
 	}
 
 	pub fn do_memory_copy(&mut self, memory_copy: mvp::MemoryCopy, data_handler: &mut DataHandler) {
@@ -236,7 +262,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(memory_copy);
+		self.push(memory_copy);
 	}
 
 	pub fn do_memory_init(&mut self, memory_init: mvp::MemoryInit, data_handler: &mut DataHandler) {
@@ -249,7 +275,7 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(memory_init);
+		self.push(memory_init);
 	}
 
 	pub fn do_data_drop(&mut self, data_drop: mvp::DataDrop, data_handler: &mut DataHandler) {
@@ -260,6 +286,17 @@ impl CodeHandler {
 			.into(),
 		);
 
-		self.list.push(data_drop);
+		self.push(data_drop);
+	}
+
+	pub fn do_throw(&mut self, throw: &mvp::Throw, data_handler: &mut DataHandler) {
+		let throw = Statement::Throw(
+			Throw {
+				source: data_handler.load(throw.source).unwrap(),
+			}
+			.into(),
+		);
+
+		self.push(throw);
 	}
 }
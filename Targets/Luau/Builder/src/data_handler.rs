@@ -3,15 +3,17 @@ use data_flow_graph::{Link, mvp, nested};
 use hashbrown::HashMap;
 use luau_tree::{
 	expression::{
-		Call, ElementsNew, Expression, Function, GlobalGet, GlobalNew, Import,
-		IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
-		IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Local,
-		Location, Match, MemoryGrow, MemoryLoad, MemorySize, Name, NumberBinaryOperation,
-		NumberCompareOperation, NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger,
-		NumberUnaryOperation, NumberWiden, RefIsNull, Scoped, TableGet, TableGrow, TableNew,
-		TableSize,
+		Call, ElementsNew, ExceptionGet, ExceptionNew, ExceptionTest, Expression, Function,
+		FunctionCheck, FunctionNew, GlobalGet, GlobalNew, Import, IntegerBinaryOperation,
+		IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend, IntegerNarrow,
+		IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Local, Location, Match,
+		MemoryGrow, MemoryLoad, MemorySize, Name, NumberBinaryOperation, NumberCompareOperation,
+		NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation,
+		NumberWiden, RefIsNull, Scoped, Signature, TableGet, TableGrow, TableNew, TableSize,
+		V128BinaryOperation, V128BitSelect, V128ExtractLane, V128ReduceOperation, V128ReplaceLane,
+		V128Shuffle, V128Splat, V128UnaryOperation,
 	},
-	statement::{Export, FastDefine, Sequence},
+	statement::{Export, ExportType, Sequence, Statement},
 };
 
 pub struct DataHandler {
@@ -96,20 +98,29 @@ impl DataHandler {
 	}
 
 	pub fn load_scoped(
-		locals: Vec<FastDefine>,
+		locals: Vec<Statement>,
 		arguments: Vec<Name>,
 		code: Sequence,
 		returns: Vec<Local>,
+		signature: Signature,
+		tail: bool,
 	) -> Expression {
 		let function = Function {
 			arguments,
 			code,
 			returns,
+			signature,
+			tail,
 		};
 
 		if locals.is_empty() {
 			Expression::Function(function.into())
 		} else {
+			let locals = Sequence {
+				list: locals,
+				positions: Vec::new(),
+			};
+
 			let scoped = Scoped { locals, function };
 
 			Expression::Scoped(scoped.into())
@@ -151,20 +162,23 @@ impl DataHandler {
 		}
 	}
 
-	pub fn load_import(&mut self, import: &nested::Import) -> Expression {
+	pub fn load_import(&mut self, import: &nested::Import, r#type: ExportType) -> Expression {
 		let import = Import {
 			environment: self.load(import.environment).unwrap(),
 			namespace: import.namespace.clone(),
 			identifier: import.identifier.clone(),
+			r#type,
 		};
 
 		Expression::Import(import.into())
 	}
 
-	pub fn load_export(&mut self, export: &nested::Export) -> Export {
+	pub fn load_export(&mut self, export: &nested::Export, r#type: ExportType) -> Export {
 		Export {
+			instance: export.instance.clone(),
 			identifier: export.identifier.clone(),
 			source: self.load(export.reference).unwrap(),
+			r#type,
 		}
 	}
 
@@ -197,6 +211,24 @@ impl DataHandler {
 		Expression::RefIsNull(operation.into())
 	}
 
+	pub fn load_function_new(&mut self, function_new: &mvp::FunctionNew) -> Expression {
+		let function_new = FunctionNew {
+			source: self.load(function_new.source).unwrap(),
+			signature: function_new.signature.clone(),
+		};
+
+		Expression::FunctionNew(function_new.into())
+	}
+
+	pub fn load_function_check(&mut self, function_check: &mvp::FunctionCheck) -> Expression {
+		let function_check = FunctionCheck {
+			source: self.load(function_check.source).unwrap(),
+			signature: function_check.signature.clone(),
+		};
+
+		Expression::FunctionCheck(function_check.into())
+	}
+
 	pub fn load_integer_unary_operation(
 		&mut self,
 		operation: mvp::IntegerUnaryOperation,
@@ -373,7 +405,101 @@ impl DataHandler {
 		Expression::NumberTransmuteToInteger(operation.into())
 	}
 
-	pub fn load_global_new(&mut self, global_new: mvp::GlobalNew) -> Expression {
+	pub fn load_v128_unary_operation(
+		&mut self,
+		v128_unary_operation: mvp::V128UnaryOperation,
+	) -> Expression {
+		let v128_unary_operation = V128UnaryOperation {
+			source: self.load(v128_unary_operation.source).unwrap(),
+			operator: v128_unary_operation.operator,
+		};
+
+		Expression::V128UnaryOperation(v128_unary_operation.into())
+	}
+
+	pub fn load_v128_binary_operation(
+		&mut self,
+		v128_binary_operation: mvp::V128BinaryOperation,
+	) -> Expression {
+		let v128_binary_operation = V128BinaryOperation {
+			lhs: self.load(v128_binary_operation.lhs).unwrap(),
+			rhs: self.load(v128_binary_operation.rhs).unwrap(),
+			operator: v128_binary_operation.operator,
+		};
+
+		Expression::V128BinaryOperation(v128_binary_operation.into())
+	}
+
+	pub fn load_v128_reduce_operation(
+		&mut self,
+		v128_reduce_operation: mvp::V128ReduceOperation,
+	) -> Expression {
+		let v128_reduce_operation = V128ReduceOperation {
+			source: self.load(v128_reduce_operation.source).unwrap(),
+			operator: v128_reduce_operation.operator,
+		};
+
+		Expression::V128ReduceOperation(v128_reduce_operation.into())
+	}
+
+	pub fn load_v128_splat(&mut self, v128_splat: mvp::V128Splat) -> Expression {
+		let v128_splat = V128Splat {
+			source: self.load(v128_splat.source).unwrap(),
+			r#type: v128_splat.r#type,
+		};
+
+		Expression::V128Splat(v128_splat.into())
+	}
+
+	pub fn load_v128_extract_lane(
+		&mut self,
+		v128_extract_lane: mvp::V128ExtractLane,
+	) -> Expression {
+		let v128_extract_lane = V128ExtractLane {
+			source: self.load(v128_extract_lane.source).unwrap(),
+			r#type: v128_extract_lane.r#type,
+			lane: v128_extract_lane.lane,
+			signed: v128_extract_lane.signed,
+		};
+
+		Expression::V128ExtractLane(v128_extract_lane.into())
+	}
+
+	pub fn load_v128_replace_lane(
+		&mut self,
+		v128_replace_lane: mvp::V128ReplaceLane,
+	) -> Expression {
+		let v128_replace_lane = V128ReplaceLane {
+			lhs: self.load(v128_replace_lane.lhs).unwrap(),
+			rhs: self.load(v128_replace_lane.rhs).unwrap(),
+			r#type: v128_replace_lane.r#type,
+			lane: v128_replace_lane.lane,
+		};
+
+		Expression::V128ReplaceLane(v128_replace_lane.into())
+	}
+
+	pub fn load_v128_shuffle(&mut self, v128_shuffle: mvp::V128Shuffle) -> Expression {
+		let v128_shuffle = V128Shuffle {
+			lhs: self.load(v128_shuffle.lhs).unwrap(),
+			rhs: self.load(v128_shuffle.rhs).unwrap(),
+			lanes: v128_shuffle.lanes,
+		};
+
+		Expression::V128Shuffle(v128_shuffle.into())
+	}
+
+	pub fn load_v128_bit_select(&mut self, v128_bit_select: mvp::V128BitSelect) -> Expression {
+		let v128_bit_select = V128BitSelect {
+			lhs: self.load(v128_bit_select.lhs).unwrap(),
+			rhs: self.load(v128_bit_select.rhs).unwrap(),
+			condition: self.load(v128_bit_select.condition).unwrap(),
+		};
+
+		Expression::V128BitSelect(v128_bit_select.into())
+	}
+
+	pub fn load_global_new(&mut self, global_new: &mvp::GlobalNew) -> Expression {
 		let global_new = GlobalNew {
 			initializer: self.load(global_new.initializer).unwrap(),
 		};
@@ -458,4 +584,31 @@ impl DataHandler {
 
 		Expression::MemoryGrow(memory_grow.into())
 	}
+
+	pub fn load_exception_new(&mut self, exception_new: &mvp::ExceptionNew) -> Expression {
+		let exception_new = ExceptionNew {
+			tag: self.load(exception_new.tag).unwrap(),
+			arguments: self.load_sources(&exception_new.arguments),
+		};
+
+		Expression::ExceptionNew(exception_new.into())
+	}
+
+	pub fn load_exception_test(&mut self, exception_test: mvp::ExceptionTest) -> Expression {
+		let exception_test = ExceptionTest {
+			source: self.load(exception_test.source).unwrap(),
+			tag: self.load(exception_test.tag).unwrap(),
+		};
+
+		Expression::ExceptionTest(exception_test.into())
+	}
+
+	pub fn load_exception_get(&mut self, exception_get: mvp::ExceptionGet) -> Expression {
+		let exception_get = ExceptionGet {
+			source: self.load(exception_get.source).unwrap(),
+			index: exception_get.index,
+		};
+
+		Expression::ExceptionGet(exception_get.into())
+	}
 }
@@ -0,0 +1,177 @@
+use alloc::vec::Vec;
+use data_flow_graph::{
+	DataFlowGraph, Link, Node,
+	nested::{GammaIn, GammaOut, RegionOut},
+};
+use hashbrown::HashSet;
+use luau_tree::{
+	expression::{Signature, ValueType},
+	statement::ExportType,
+};
+
+use crate::reference_finder::ReferenceFinder;
+
+fn load_value_type(word: &str) -> ValueType {
+	match word {
+		"i32" => ValueType::I32,
+		"i64" => ValueType::I64,
+		"f32" => ValueType::F32,
+		"f64" => ValueType::F64,
+		"v128" => ValueType::V128,
+		_ => ValueType::Reference,
+	}
+}
+
+// Reference types may be written with spaces inside of parentheses,
+// so only those at the top of the list separate the types.
+fn load_value_types(list: &str) -> Vec<ValueType> {
+	let mut types = Vec::new();
+	let mut depth = 0_usize;
+	let mut start = 0;
+
+	for (index, character) in list.char_indices() {
+		match character {
+			'(' => depth += 1,
+			')' => depth -= 1,
+			' ' if depth == 0 => {
+				types.push(load_value_type(&list[start..index]));
+
+				start = index + 1;
+			}
+			_ => {}
+		}
+	}
+
+	if start != list.len() {
+		types.push(load_value_type(&list[start..]));
+	}
+
+	types
+}
+
+// Signatures are written by the data flow builder as `(i32 i64) -> (f32)`.
+fn load_signature(signature: &str) -> Signature {
+	let (arguments, results) = signature
+		.strip_prefix('(')
+		.and_then(|signature| signature.strip_suffix(')'))
+		.and_then(|signature| signature.split_once(") -> ("))
+		.unwrap_or_default();
+
+	Signature {
+		arguments: load_value_types(arguments),
+		results: load_value_types(results),
+	}
+}
+
+// Imports carry no type of their own, so it is found from the first use
+// that needs a certain kind, following them into the regions they are
+// passed to.
+pub struct ImportFinder {
+	links: Vec<Link>,
+	seen: HashSet<Link>,
+}
+
+impl ImportFinder {
+	pub fn new() -> Self {
+		Self {
+			links: Vec::new(),
+			seen: HashSet::new(),
+		}
+	}
+
+	fn add_link(&mut self, link: Link) {
+		if self.seen.insert(link) {
+			self.links.push(link);
+		}
+	}
+
+	fn add_arguments(&mut self, arguments: &[Link], link: Link, id: u32) {
+		for (_, port) in arguments
+			.iter()
+			.zip(0..)
+			.filter(|&(&argument, _)| argument == link)
+		{
+			self.add_link(Link(id, port));
+		}
+	}
+
+	fn add_gamma_in(&mut self, graph: &DataFlowGraph, gamma_in: &GammaIn, link: Link) {
+		let GammaOut { regions, .. } = graph.get(gamma_in.output).as_gamma_out().unwrap();
+
+		for &region in regions {
+			let RegionOut { input, .. } = graph.get(region).as_region_out().unwrap();
+
+			self.add_arguments(&gamma_in.arguments, link, *input);
+		}
+	}
+
+	fn find_use_type(&mut self, graph: &DataFlowGraph, id: u32, link: Link) -> Option<ExportType> {
+		let r#type = match graph.get(id) {
+			Node::LambdaIn(lambda_in) => {
+				self.add_arguments(&lambda_in.dependencies, link, id);
+
+				return None;
+			}
+			Node::GammaIn(gamma_in) => {
+				self.add_gamma_in(graph, gamma_in, link);
+
+				return None;
+			}
+			Node::ThetaIn(theta_in) => {
+				self.add_arguments(&theta_in.arguments, link, id);
+
+				return None;
+			}
+			Node::Identity(_) => {
+				self.add_link(Link(id, 0));
+
+				return None;
+			}
+			Node::FunctionNew(function_new) => {
+				ExportType::Function(load_signature(&function_new.signature))
+			}
+			Node::GlobalGet(_) | Node::GlobalSet(_) => ExportType::Global(None),
+			Node::TableGet(_)
+			| Node::TableSet(_)
+			| Node::TableSize(_)
+			| Node::TableGrow(_)
+			| Node::TableFill(_)
+			| Node::TableCopy(_)
+			| Node::TableInit(_) => ExportType::Table,
+			Node::MemoryLoad(_)
+			| Node::MemoryStore(_)
+			| Node::MemorySize(_)
+			| Node::MemoryGrow(_)
+			| Node::MemoryFill(_)
+			| Node::MemoryCopy(_)
+			| Node::MemoryInit(_) => ExportType::Memory,
+			_ => return None,
+		};
+
+		Some(r#type)
+	}
+
+	/// Returns the kind of the import at `id`, or `ExportType::Unknown` if
+	/// it is never used in a way that needs one.
+	pub fn run(
+		&mut self,
+		graph: &DataFlowGraph,
+		reference_finder: &ReferenceFinder,
+		id: u32,
+	) -> ExportType {
+		self.links.clear();
+		self.seen.clear();
+
+		self.add_link(Link(id, 0));
+
+		while let Some(link) = self.links.pop() {
+			for user in reference_finder.find_uses(link.0, link.1) {
+				if let Some(r#type) = self.find_use_type(graph, user, link) {
+					return r#type;
+				}
+			}
+		}
+
+		ExportType::Unknown
+	}
+}
@@ -1,42 +1,50 @@
 #![no_std]
 
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 use data_flow_graph::{
 	DataFlowGraph, Link, Node,
 	mvp::{
-		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, GlobalGet, GlobalNew, GlobalSet, Host,
-		Identity, IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber,
-		IntegerExtend, IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation,
-		IntegerWiden, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemoryNew,
-		MemorySize, MemoryStore, Merge, NumberBinaryOperation, NumberCompareOperation,
-		NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger, NumberUnaryOperation,
-		NumberWiden, RefIsNull, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableNew,
-		TableSet, TableSize,
+		Call, DataDrop, DataNew, ElementsDrop, ElementsNew, ExceptionGet, ExceptionNew,
+		ExceptionTest, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, GlobalSet, Host, Identity,
+		IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
+		IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, MemoryCopy,
+		MemoryFill, MemoryGrow, MemoryInit, MemoryLoad, MemoryNew, MemorySize, MemoryStore, Merge,
+		NumberBinaryOperation, NumberCompareOperation, NumberNarrow, NumberTransmuteToInteger,
+		NumberTruncateToInteger, NumberUnaryOperation, NumberWiden, RefIsNull, TableCopy,
+		TableFill, TableGet, TableGrow, TableInit, TableNew, TableSet, TableSize, Throw,
+		V128BinaryOperation, V128BitSelect, V128ExtractLane, V128ReduceOperation, V128ReplaceLane,
+		V128Shuffle, V128Splat, V128UnaryOperation,
 	},
 	nested::{
-		GammaIn, GammaOut, Import, LambdaIn, LambdaOut, OmegaIn, OmegaOut, RegionOut, ThetaIn,
-		ThetaOut,
+		FunctionType, GammaIn, GammaOut, Import, LambdaIn, LambdaOut, OmegaIn, OmegaOut, RegionOut,
+		ThetaIn, ThetaOut,
 	},
 };
 use hashbrown::HashMap;
 use luau_tree::{
 	LuauTree,
-	expression::{Expression, Local, Name},
-	statement::{AssignAll, Export, FastDefine, Sequence},
+	expression::{Expression, Local, Name, Signature, ValueType},
+	names_finder::NamesFinder,
+	statement::{
+		Assign, AssignAll, Export, ExportType, FastDefine, Sequence, SlowDefine, Statement,
+	},
 };
 
 use self::{
 	code_handler::CodeHandler,
 	data_handler::DataHandler,
+	import_finder::ImportFinder,
 	local_allocator::LocalAllocator,
 	place::{Place, Table},
 	reference_finder::ReferenceFinder,
+	scoped_provider::MAX_UPVALUES,
 };
 
 extern crate alloc;
 
 mod code_handler;
 mod data_handler;
+mod import_finder;
 mod local_allocator;
 mod place;
 mod reference_finder;
@@ -44,6 +52,7 @@ mod scoped_provider;
 
 pub struct LuauBuilder {
 	reference_finder: ReferenceFinder,
+	import_finder: ImportFinder,
 	local_allocator: LocalAllocator,
 	locals: HashMap<Link, Place>,
 	tables: HashMap<u32, Table>,
@@ -53,8 +62,11 @@ pub struct LuauBuilder {
 
 	environment: Name,
 	exports: Vec<Export>,
+	names: Vec<(Name, Arc<str>)>,
 
 	regions: HashMap<u32, Sequence>,
+	tail_calls: Vec<bool>,
+	upvalues_exceeded: bool,
 }
 
 impl LuauBuilder {
@@ -62,6 +74,7 @@ impl LuauBuilder {
 	pub fn new() -> Self {
 		Self {
 			reference_finder: ReferenceFinder::new(),
+			import_finder: ImportFinder::new(),
 			local_allocator: LocalAllocator::new(),
 			locals: HashMap::new(),
 			tables: HashMap::new(),
@@ -71,8 +84,11 @@ impl LuauBuilder {
 
 			environment: Name { id: 0 },
 			exports: Vec::new(),
+			names: Vec::new(),
 
 			regions: HashMap::new(),
+			tail_calls: Vec::new(),
+			upvalues_exceeded: false,
 		}
 	}
 
@@ -136,11 +152,19 @@ impl LuauBuilder {
 
 		self.data_handler.push_scope();
 		self.code_handler.push_scope();
+		self.tail_calls.push(false);
 
+		// Dependencies may have spilled, but arguments are always defined.
 		for link in lambda_in.output_ports().map(|port| Link(id, port)) {
-			let name = self.locals[&link].into_definition();
+			let local = self.locals[&link].into_local();
+
+			if let Local::Fast { name } = local
+				&& let Some(Some(identifier)) = lambda_in.names.get(usize::from(link.1))
+			{
+				self.names.push((name, identifier.clone()));
+			}
 
-			self.data_handler.alias(link, Local::Fast { name });
+			self.data_handler.alias(link, local);
 		}
 
 		let trap = lambda_in.output_ports().end;
@@ -150,13 +174,48 @@ impl LuauBuilder {
 		self.handle_table_spill(id);
 	}
 
+	// Dependencies past the upvalue limit are assigned into the table
+	// that the allocator created for them, which is captured instead.
+	fn load_dependencies(&mut self, input: u32, lambda_in: &LambdaIn) -> Vec<Statement> {
+		let sources = self.data_handler.load_sources(&lambda_in.dependencies);
+		let mut list = Vec::with_capacity(sources.len() + 1);
+
+		if let Some(&Table { name, len }) = self.tables.get(&lambda_in.output) {
+			list.push(Statement::SlowDefine(SlowDefine { name, len }.into()));
+		}
+
+		for (port, source) in lambda_in.dependency_ports().zip(sources) {
+			let statement = match self.locals[&Link(input, port)] {
+				Place::Definition { name } => {
+					Statement::FastDefine(FastDefine { name, source }.into())
+				}
+				Place::Overflow { table, index } => {
+					let local = Local::Slow { table, index };
+
+					Statement::Assign(Assign { local, source }.into())
+				}
+				Place::Assignment { .. } => unreachable!("dependencies should be defined"),
+			};
+
+			list.push(statement);
+		}
+
+		list
+	}
+
+	fn count_runtime_names(code: &Sequence) -> usize {
+		let mut names = Vec::new();
+
+		NamesFinder::new(&mut names).run_partial(&code.list, &[]);
+
+		names.sort_unstable();
+		names.dedup();
+		names.len()
+	}
+
 	fn handle_lambda_out(&mut self, graph: &DataFlowGraph, id: u32, lambda_out: &LambdaOut) {
 		let LambdaOut { results, input } = lambda_out;
-		let lambda_in @ LambdaIn {
-			r#type,
-			dependencies,
-			..
-		} = graph.get(*input).as_lambda_in().unwrap();
+		let lambda_in @ LambdaIn { r#type, .. } = graph.get(*input).as_lambda_in().unwrap();
 
 		let arguments = lambda_in
 			.argument_ports()
@@ -172,18 +231,16 @@ impl LuauBuilder {
 		self.data_handler.pop_scope();
 
 		let code = self.code_handler.pop_scope();
+		let tail = self.tail_calls.pop().unwrap();
+		let names = Self::count_runtime_names(&code);
 
-		let dependencies = self.data_handler.load_sources(dependencies);
-		let dependencies = lambda_in
-			.dependency_ports()
-			.map(|port| Link(*input, port))
-			.map(|link| self.locals[&link])
-			.map(Place::into_definition)
-			.zip(dependencies)
-			.map(|(name, source)| FastDefine { name, source })
-			.collect();
+		self.upvalues_exceeded |= lambda_in.dependencies.len() + names > MAX_UPVALUES;
+		self.local_allocator.set_runtime_names(*input, names);
 
-		let function = DataHandler::load_scoped(dependencies, arguments, code, returns);
+		let signature = Self::load_signature(r#type);
+		let dependencies = self.load_dependencies(*input, lambda_in);
+		let function =
+			DataHandler::load_scoped(dependencies, arguments, code, returns, signature, tail);
 
 		self.do_set_expression(id, function);
 	}
@@ -332,17 +389,58 @@ impl LuauBuilder {
 		self.handle_table_spill(id);
 	}
 
-	fn handle_omega_out(&mut self, omega_out: &OmegaOut) {
-		let exports = omega_out
-			.exports
-			.iter()
-			.map(|export| self.data_handler.load_export(export));
+	fn load_signature(r#type: &FunctionType) -> Signature {
+		Signature {
+			arguments: r#type.arguments.iter().copied().collect(),
+			results: r#type.results.iter().copied().collect(),
+		}
+	}
+
+	fn find_value_type(node: &Node) -> Option<ValueType> {
+		let r#type = match node {
+			Node::I32(_) => ValueType::I32,
+			Node::I64(_) => ValueType::I64,
+			Node::F32(_) => ValueType::F32,
+			Node::F64(_) => ValueType::F64,
+			Node::V128(_) => ValueType::V128,
+			Node::Null | Node::FunctionNew(_) => ValueType::Reference,
+			_ => return None,
+		};
+
+		Some(r#type)
+	}
+
+	// Exports are typed after the node that defines them, which is only
+	// known for those that are not passed through from imports.
+	fn find_export_type(graph: &DataFlowGraph, link: Link) -> ExportType {
+		match graph.get(link.0) {
+			Node::LambdaOut(lambda_out) => {
+				let LambdaIn { r#type, .. } = graph.get(lambda_out.input).as_lambda_in().unwrap();
+
+				ExportType::Function(Self::load_signature(r#type))
+			}
+			Node::GlobalNew(global_new) => {
+				ExportType::Global(Self::find_value_type(graph.get(global_new.initializer.0)))
+			}
+			Node::TableNew(_) => ExportType::Table,
+			Node::MemoryNew(_) => ExportType::Memory,
+			_ => ExportType::Unknown,
+		}
+	}
+
+	fn handle_omega_out(&mut self, graph: &DataFlowGraph, omega_out: &OmegaOut) {
+		let exports = omega_out.exports.iter().map(|export| {
+			let r#type = Self::find_export_type(graph, export.reference);
+
+			self.data_handler.load_export(export, r#type)
+		});
 
 		self.exports.extend(exports);
 	}
 
-	fn handle_import(&mut self, id: u32, import: &Import) {
-		let import = self.data_handler.load_import(import);
+	fn handle_import(&mut self, graph: &DataFlowGraph, id: u32, import: &Import) {
+		let r#type = self.import_finder.run(graph, &self.reference_finder, id);
+		let import = self.data_handler.load_import(import, r#type);
 
 		self.do_set_expression(id, import);
 	}
@@ -382,6 +480,10 @@ impl LuauBuilder {
 		self.do_set_expression(id, Expression::F64(value));
 	}
 
+	fn handle_v128_const(&mut self, id: u32, value: u128) {
+		self.do_set_expression(id, Expression::V128(value));
+	}
+
 	fn handle_call_statement(&mut self, id: u32, call: &Call) {
 		let results = (0..call.results)
 			.map(|port| Link(id, port))
@@ -402,7 +504,11 @@ impl LuauBuilder {
 	}
 
 	fn handle_call(&mut self, id: u32, call: &Call) {
-		if call.results == 0 || self.locals.contains_key(&Link(id, 0)) {
+		if call.tail {
+			*self.tail_calls.last_mut().unwrap() = true;
+		}
+
+		if call.tail || call.catch || call.results == 0 || self.locals.contains_key(&Link(id, 0)) {
 			self.handle_call_statement(id, call);
 		} else {
 			self.handle_call_expression(id, call);
@@ -434,6 +540,18 @@ impl LuauBuilder {
 		self.do_set_expression(id, ref_is_null);
 	}
 
+	fn handle_function_new(&mut self, id: u32, function_new: &FunctionNew) {
+		let function_new = self.data_handler.load_function_new(function_new);
+
+		self.do_set_expression(id, function_new);
+	}
+
+	fn handle_function_check(&mut self, id: u32, function_check: &FunctionCheck) {
+		let function_check = self.data_handler.load_function_check(function_check);
+
+		self.do_set_expression(id, function_check);
+	}
+
 	fn handle_integer_unary_operation(&mut self, id: u32, operation: IntegerUnaryOperation) {
 		let operation = self.data_handler.load_integer_unary_operation(operation);
 
@@ -528,7 +646,75 @@ impl LuauBuilder {
 		self.do_set_expression(id, operation);
 	}
 
-	fn handle_global_new(&mut self, id: u32, global_new: GlobalNew) {
+	fn handle_v128_unary_operation(&mut self, id: u32, v128_unary_operation: V128UnaryOperation) {
+		let v128_unary_operation = self
+			.data_handler
+			.load_v128_unary_operation(v128_unary_operation);
+
+		self.do_set_expression(id, v128_unary_operation);
+	}
+
+	fn handle_v128_binary_operation(
+		&mut self,
+		id: u32,
+		v128_binary_operation: V128BinaryOperation,
+	) {
+		let v128_binary_operation = self
+			.data_handler
+			.load_v128_binary_operation(v128_binary_operation);
+
+		self.do_set_expression(id, v128_binary_operation);
+	}
+
+	fn handle_v128_reduce_operation(
+		&mut self,
+		id: u32,
+		v128_reduce_operation: V128ReduceOperation,
+	) {
+		let v128_reduce_operation = self
+			.data_handler
+			.load_v128_reduce_operation(v128_reduce_operation);
+
+		self.do_set_expression(id, v128_reduce_operation);
+	}
+
+	fn handle_v128_splat(&mut self, id: u32, v128_splat: V128Splat) {
+		let v128_splat = self.data_handler.load_v128_splat(v128_splat);
+
+		self.do_set_expression(id, v128_splat);
+	}
+
+	fn handle_v128_extract_lane(&mut self, id: u32, v128_extract_lane: V128ExtractLane) {
+		let v128_extract_lane = self.data_handler.load_v128_extract_lane(v128_extract_lane);
+
+		self.do_set_expression(id, v128_extract_lane);
+	}
+
+	fn handle_v128_replace_lane(&mut self, id: u32, v128_replace_lane: V128ReplaceLane) {
+		let v128_replace_lane = self.data_handler.load_v128_replace_lane(v128_replace_lane);
+
+		self.do_set_expression(id, v128_replace_lane);
+	}
+
+	fn handle_v128_shuffle(&mut self, id: u32, v128_shuffle: V128Shuffle) {
+		let v128_shuffle = self.data_handler.load_v128_shuffle(v128_shuffle);
+
+		self.do_set_expression(id, v128_shuffle);
+	}
+
+	fn handle_v128_bit_select(&mut self, id: u32, v128_bit_select: V128BitSelect) {
+		let v128_bit_select = self.data_handler.load_v128_bit_select(v128_bit_select);
+
+		self.do_set_expression(id, v128_bit_select);
+	}
+
+	fn handle_global_new(&mut self, id: u32, global_new: &GlobalNew) {
+		let place = self.locals.get(&Link(id, 0));
+
+		if let (Some(&Place::Definition { name }), Some(identifier)) = (place, &global_new.name) {
+			self.names.push((name, identifier.clone()));
+		}
+
 		let global_new = self.data_handler.load_global_new(global_new);
 
 		self.do_set_expression(id, global_new);
@@ -734,6 +920,36 @@ impl LuauBuilder {
 		self.do_rename(data_drop.source, Link(id, DataDrop::STATE_PORT));
 	}
 
+	fn handle_tag_new(&mut self, id: u32) {
+		self.do_set_expression(id, Expression::TagNew);
+	}
+
+	fn handle_throw(&mut self, id: u32, throw: &Throw) {
+		self.code_handler.do_throw(throw, &mut self.data_handler);
+
+		for (&state, port) in throw.states.iter().zip(0..) {
+			self.do_rename(state, Link(id, port));
+		}
+	}
+
+	fn handle_exception_new(&mut self, id: u32, exception_new: &ExceptionNew) {
+		let exception_new = self.data_handler.load_exception_new(exception_new);
+
+		self.do_set_expression(id, exception_new);
+	}
+
+	fn handle_exception_test(&mut self, id: u32, exception_test: ExceptionTest) {
+		let exception_test = self.data_handler.load_exception_test(exception_test);
+
+		self.do_set_expression(id, exception_test);
+	}
+
+	fn handle_exception_get(&mut self, id: u32, exception_get: ExceptionGet) {
+		let exception_get = self.data_handler.load_exception_get(exception_get);
+
+		self.do_set_expression(id, exception_get);
+	}
+
 	fn handle_node(&mut self, graph: &DataFlowGraph, id: u32, node: &Node) {
 		match *node {
 			Node::LambdaIn(ref lambda_in) => self.handle_lambda_in(id, lambda_in),
@@ -745,9 +961,9 @@ impl LuauBuilder {
 			Node::ThetaIn(ref theta_in) => self.handle_theta_in(id, theta_in),
 			Node::ThetaOut(ref theta_out) => self.handle_theta_out(id, theta_out),
 			Node::OmegaIn(_) => self.handle_omega_in(id),
-			Node::OmegaOut(ref omega_out) => self.handle_omega_out(omega_out),
+			Node::OmegaOut(ref omega_out) => self.handle_omega_out(graph, omega_out),
 
-			Node::Import(ref import) => self.handle_import(id, import),
+			Node::Import(ref import) => self.handle_import(graph, id, import),
 			Node::Host(ref host) => self.handle_host(id, host.as_ref()),
 			Node::Trap => self.handle_trap(id),
 			Node::Null => self.handle_null(id),
@@ -756,10 +972,15 @@ impl LuauBuilder {
 			Node::I64(i64) => self.handle_i64_const(id, i64),
 			Node::F32(f32) => self.handle_f32_const(id, f32),
 			Node::F64(f64) => self.handle_f64_const(id, f64),
+			Node::V128(v128) => self.handle_v128_const(id, v128),
 
 			Node::Call(ref call) => self.handle_call(id, call),
 			Node::Merge(ref merge) => self.handle_merge(merge),
 			Node::RefIsNull(ref_is_null) => self.handle_ref_is_null(id, ref_is_null),
+			Node::FunctionNew(ref function_new) => self.handle_function_new(id, function_new),
+			Node::FunctionCheck(ref function_check) => {
+				self.handle_function_check(id, function_check);
+			}
 			Node::IntegerUnaryOperation(integer_unary_operation) => {
 				self.handle_integer_unary_operation(id, integer_unary_operation);
 			}
@@ -795,7 +1016,27 @@ impl LuauBuilder {
 			Node::NumberTransmuteToInteger(number_transmute_to_integer) => {
 				self.handle_number_transmute_to_integer(id, number_transmute_to_integer);
 			}
-			Node::GlobalNew(global_new) => self.handle_global_new(id, global_new),
+			Node::V128UnaryOperation(v128_unary_operation) => {
+				self.handle_v128_unary_operation(id, v128_unary_operation);
+			}
+			Node::V128BinaryOperation(v128_binary_operation) => {
+				self.handle_v128_binary_operation(id, v128_binary_operation);
+			}
+			Node::V128ReduceOperation(v128_reduce_operation) => {
+				self.handle_v128_reduce_operation(id, v128_reduce_operation);
+			}
+			Node::V128Splat(v128_splat) => self.handle_v128_splat(id, v128_splat),
+			Node::V128ExtractLane(v128_extract_lane) => {
+				self.handle_v128_extract_lane(id, v128_extract_lane);
+			}
+			Node::V128ReplaceLane(v128_replace_lane) => {
+				self.handle_v128_replace_lane(id, v128_replace_lane);
+			}
+			Node::V128Shuffle(v128_shuffle) => self.handle_v128_shuffle(id, v128_shuffle),
+			Node::V128BitSelect(v128_bit_select) => {
+				self.handle_v128_bit_select(id, v128_bit_select);
+			}
+			Node::GlobalNew(ref global_new) => self.handle_global_new(id, global_new),
 			Node::GlobalGet(global_get) => self.handle_global_get(id, global_get),
 			Node::GlobalSet(global_set) => self.handle_global_set(id, global_set),
 			Node::TableNew(table_new) => self.handle_table_new(id, table_new),
@@ -818,11 +1059,25 @@ impl LuauBuilder {
 			Node::MemoryInit(memory_init) => self.handle_memory_init(id, memory_init),
 			Node::DataNew(ref data_new) => self.handle_data_new(id, data_new),
 			Node::DataDrop(data_drop) => self.handle_data_drop(id, data_drop),
+			Node::TagNew => self.handle_tag_new(id),
+			Node::Throw(ref throw) => self.handle_throw(id, throw),
+			Node::ExceptionNew(ref exception_new) => {
+				self.handle_exception_new(id, exception_new);
+			}
+			Node::ExceptionTest(exception_test) => {
+				self.handle_exception_test(id, exception_test);
+			}
+			Node::ExceptionGet(exception_get) => self.handle_exception_get(id, exception_get),
 		}
 	}
 
-	pub fn run(&mut self, graph: &DataFlowGraph) -> LuauTree {
-		self.reference_finder.run(graph);
+	fn build(&mut self, graph: &DataFlowGraph) -> LuauTree {
+		self.code_handler = CodeHandler::new();
+		self.data_handler = DataHandler::new();
+		self.regions.clear();
+		self.tail_calls.clear();
+		self.upvalues_exceeded = false;
+
 		self.local_allocator.run(
 			&mut self.tables,
 			&mut self.locals,
@@ -831,6 +1086,7 @@ impl LuauBuilder {
 		);
 
 		for (node, id) in graph.nodes().zip(0..) {
+			self.code_handler.set_position(graph.get_position(id));
 			self.handle_node(graph, id, node);
 		}
 
@@ -840,6 +1096,23 @@ impl LuauBuilder {
 			environment: self.environment,
 			code: self.code_handler.pop_scope(),
 			exports: core::mem::take(&mut self.exports),
+			names: core::mem::take(&mut self.names),
+		}
+	}
+
+	// The names of the runtime that a function uses take up its upvalues
+	// too, but they are only known once its code is built, so functions
+	// with too many dependencies for them are built again.
+	pub fn run(&mut self, graph: &DataFlowGraph) -> LuauTree {
+		self.reference_finder.run(graph);
+		self.local_allocator.clear_runtime_names();
+
+		let tree = self.build(graph);
+
+		if self.upvalues_exceeded {
+			self.build(graph)
+		} else {
+			tree
 		}
 	}
 }
@@ -4,7 +4,7 @@ use data_flow_graph::{
 	mvp::{
 		Call, GlobalGet, GlobalSet, MemoryCopy, MemoryFill, MemoryGrow, MemoryInit, MemoryLoad,
 		MemorySize, MemoryStore, TableCopy, TableFill, TableGet, TableGrow, TableInit, TableSet,
-		TableSize,
+		TableSize, Throw,
 	},
 };
 use hashbrown::HashMap;
@@ -24,7 +24,8 @@ fn has_local_result(node: &Node, id: u32, port: u16, locals: &[u32]) -> bool {
 		| Node::TableNew(_)
 		| Node::ElementsNew(_)
 		| Node::MemoryNew(_)
-		| Node::DataNew(_) => true,
+		| Node::DataNew(_)
+		| Node::TagNew => true,
 
 		Node::LambdaOut(_)
 		| Node::Import(_)
@@ -35,7 +36,10 @@ fn has_local_result(node: &Node, id: u32, port: u16, locals: &[u32]) -> bool {
 		| Node::I64(_)
 		| Node::F32(_)
 		| Node::F64(_)
+		| Node::V128(_)
 		| Node::RefIsNull(_)
+		| Node::FunctionNew(_)
+		| Node::FunctionCheck(_)
 		| Node::IntegerUnaryOperation(_)
 		| Node::IntegerBinaryOperation(_)
 		| Node::IntegerCompareOperation(_)
@@ -50,7 +54,18 @@ fn has_local_result(node: &Node, id: u32, port: u16, locals: &[u32]) -> bool {
 		| Node::NumberNarrow(_)
 		| Node::NumberWiden(_)
 		| Node::NumberTruncateToInteger(_)
-		| Node::NumberTransmuteToInteger(_) => locals.binary_search(&id).is_ok(),
+		| Node::NumberTransmuteToInteger(_)
+		| Node::V128UnaryOperation(_)
+		| Node::V128BinaryOperation(_)
+		| Node::V128ReduceOperation(_)
+		| Node::V128Splat(_)
+		| Node::V128ExtractLane(_)
+		| Node::V128ReplaceLane(_)
+		| Node::V128Shuffle(_)
+		| Node::V128BitSelect(_)
+		| Node::ExceptionNew(_)
+		| Node::ExceptionTest(_)
+		| Node::ExceptionGet(_) => locals.binary_search(&id).is_ok(),
 
 		Node::Host(host) => panic!("unknown host operation {id} `{}`", host.identifier()),
 
@@ -66,7 +81,8 @@ fn has_local_result(node: &Node, id: u32, port: u16, locals: &[u32]) -> bool {
 		| Node::MemoryFill(_)
 		| Node::MemoryCopy(_)
 		| Node::MemoryInit(_)
-		| Node::DataDrop(_) => false,
+		| Node::DataDrop(_)
+		| Node::Throw(_) => false,
 
 		Node::GlobalGet(_) => port == GlobalGet::RESULT_PORT && locals.binary_search(&id).is_ok(),
 		Node::TableGet(_) => port == TableGet::RESULT_PORT && locals.binary_search(&id).is_ok(),
@@ -113,17 +129,21 @@ impl LifetimeFinder {
 			| Node::I64(_)
 			| Node::F32(_)
 			| Node::F64(_)
+			| Node::V128(_)
 			| Node::Merge(_)
 			| Node::GlobalGet(_)
 			| Node::TableSize(_)
 			| Node::ElementsDrop(_)
 			| Node::MemorySize(_)
 			| Node::DataNew(_)
-			| Node::DataDrop(_) => {}
+			| Node::DataDrop(_)
+			| Node::TagNew => {}
 
 			Node::Import(_)
 			| Node::Identity(_)
 			| Node::RefIsNull(_)
+			| Node::FunctionNew(_)
+			| Node::FunctionCheck(_)
 			| Node::IntegerUnaryOperation(_)
 			| Node::IntegerBinaryOperation(_)
 			| Node::IntegerCompareOperation(_)
@@ -139,10 +159,21 @@ impl LifetimeFinder {
 			| Node::NumberWiden(_)
 			| Node::NumberTruncateToInteger(_)
 			| Node::NumberTransmuteToInteger(_)
+			| Node::V128UnaryOperation(_)
+			| Node::V128BinaryOperation(_)
+			| Node::V128ReduceOperation(_)
+			| Node::V128Splat(_)
+			| Node::V128ExtractLane(_)
+			| Node::V128ReplaceLane(_)
+			| Node::V128Shuffle(_)
+			| Node::V128BitSelect(_)
 			| Node::GlobalNew(_)
 			| Node::TableNew(_)
 			| Node::ElementsNew(_)
-			| Node::MemoryNew(_) => {
+			| Node::MemoryNew(_)
+			| Node::ExceptionNew(_)
+			| Node::ExceptionTest(_)
+			| Node::ExceptionGet(_) => {
 				if locals.binary_search(&id).is_ok() {
 					node.for_each_argument(|link| self.stack.push(link));
 				}
@@ -164,7 +195,9 @@ impl LifetimeFinder {
 				}
 			}
 
-			Node::GlobalSet(GlobalSet { source, .. }) => self.stack.push(source),
+			Node::GlobalSet(GlobalSet { source, .. }) | Node::Throw(Throw { source, .. }) => {
+				self.stack.push(source);
+			}
 			Node::TableGet(TableGet { source }) | Node::MemoryLoad(MemoryLoad { source, .. }) => {
 				if locals.binary_search(&id).is_ok() {
 					self.stack.push(source.offset);
@@ -261,17 +294,21 @@ impl LifetimeFinder {
 			| Node::I64(_)
 			| Node::F32(_)
 			| Node::F64(_)
+			| Node::V128(_)
 			| Node::GlobalNew(_)
 			| Node::GlobalGet(_)
 			| Node::TableNew(_)
 			| Node::ElementsNew(_)
 			| Node::MemoryNew(_)
-			| Node::DataNew(_) => {}
+			| Node::DataNew(_)
+			| Node::TagNew => {}
 
 			Node::Import(_)
 			| Node::Identity(_)
 			| Node::Merge(_)
 			| Node::RefIsNull(_)
+			| Node::FunctionNew(_)
+			| Node::FunctionCheck(_)
 			| Node::IntegerUnaryOperation(_)
 			| Node::IntegerBinaryOperation(_)
 			| Node::IntegerCompareOperation(_)
@@ -287,8 +324,19 @@ impl LifetimeFinder {
 			| Node::NumberWiden(_)
 			| Node::NumberTruncateToInteger(_)
 			| Node::NumberTransmuteToInteger(_)
+			| Node::V128UnaryOperation(_)
+			| Node::V128BinaryOperation(_)
+			| Node::V128ReduceOperation(_)
+			| Node::V128Splat(_)
+			| Node::V128ExtractLane(_)
+			| Node::V128ReplaceLane(_)
+			| Node::V128Shuffle(_)
+			| Node::V128BitSelect(_)
 			| Node::ElementsDrop(_)
-			| Node::DataDrop(_) => node.for_each_argument(|link| self.stack.push(link)),
+			| Node::DataDrop(_)
+			| Node::ExceptionNew(_)
+			| Node::ExceptionTest(_)
+			| Node::ExceptionGet(_) => node.for_each_argument(|link| self.stack.push(link)),
 
 			Node::Host(ref host) => panic!("unknown host operation `{}`", host.identifier()),
 
@@ -309,6 +357,7 @@ impl LifetimeFinder {
 				}
 			}
 
+			Node::Throw(Throw { ref states, .. }) => self.stack.push(states[usize::from(port)]),
 			Node::GlobalSet(GlobalSet { destination, .. }) => self.stack.push(destination),
 			Node::TableGet(TableGet { source }) => {
 				if port == TableGet::RESULT_PORT {
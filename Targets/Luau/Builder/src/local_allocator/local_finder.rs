@@ -4,7 +4,7 @@ use data_flow_graph::{
 	mvp::{
 		Call, DataDrop, ElementsDrop, GlobalGet, GlobalSet, MemoryCopy, MemoryFill, MemoryGrow,
 		MemoryInit, MemoryLoad, MemorySize, MemoryStore, Merge, TableCopy, TableFill, TableGet,
-		TableGrow, TableInit, TableSet, TableSize,
+		TableGrow, TableInit, TableSet, TableSize, Throw,
 	},
 	nested::{GammaIn, GammaOut, LambdaOut, RegionOut, ThetaIn, ThetaOut},
 };
@@ -122,7 +122,10 @@ fn localize_state_producer(locals: &mut Vec<u32>, graph: &DataFlowGraph, node: &
 		| Node::I64(_)
 		| Node::F32(_)
 		| Node::F64(_)
+		| Node::V128(_)
 		| Node::RefIsNull(_)
+		| Node::FunctionNew(_)
+		| Node::FunctionCheck(_)
 		| Node::IntegerUnaryOperation(_)
 		| Node::IntegerBinaryOperation(_)
 		| Node::IntegerCompareOperation(_)
@@ -138,11 +141,23 @@ fn localize_state_producer(locals: &mut Vec<u32>, graph: &DataFlowGraph, node: &
 		| Node::NumberWiden(_)
 		| Node::NumberTruncateToInteger(_)
 		| Node::NumberTransmuteToInteger(_)
+		| Node::V128UnaryOperation(_)
+		| Node::V128BinaryOperation(_)
+		| Node::V128ReduceOperation(_)
+		| Node::V128Splat(_)
+		| Node::V128ExtractLane(_)
+		| Node::V128ReplaceLane(_)
+		| Node::V128Shuffle(_)
+		| Node::V128BitSelect(_)
 		| Node::GlobalNew(_)
 		| Node::TableNew(_)
 		| Node::ElementsNew(_)
 		| Node::MemoryNew(_)
-		| Node::DataNew(_) => {}
+		| Node::DataNew(_)
+		| Node::TagNew
+		| Node::ExceptionNew(_)
+		| Node::ExceptionTest(_)
+		| Node::ExceptionGet(_) => {}
 
 		Node::Call(Call {
 			ref arguments,
@@ -153,7 +168,7 @@ fn localize_state_producer(locals: &mut Vec<u32>, graph: &DataFlowGraph, node: &
 				handle_state_producer(locals, graph, argument);
 			}
 		}
-		Node::Merge(Merge { ref states }) => {
+		Node::Merge(Merge { ref states }) | Node::Throw(Throw { ref states, .. }) => {
 			for &state in states {
 				handle_state_producer(locals, graph, state);
 			}
@@ -211,9 +226,18 @@ fn should_localize(reference_finder: &ReferenceFinder, id: u32, node: &Node) ->
 	let local = match *node {
 		Node::Trap => true,
 
-		Node::Call(Call { results, .. }) => results
-			.checked_sub(1)
-			.is_some_and(|port| !reference_finder.has_result_at(id, port)),
+		// Tail and catching calls are always statements, so their results need places.
+		Node::Call(Call {
+			results,
+			tail,
+			catch,
+			..
+		}) => {
+			tail || catch
+				|| results
+					.checked_sub(1)
+					.is_some_and(|port| !reference_finder.has_result_at(id, port))
+		}
 
 		Node::TableGrow(_) => !reference_finder.has_result_at(id, TableGrow::RESULT_PORT),
 		Node::MemoryGrow(_) => !reference_finder.has_result_at(id, MemoryGrow::RESULT_PORT),
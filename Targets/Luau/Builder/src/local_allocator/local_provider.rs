@@ -41,6 +41,10 @@ impl LocalProvider {
 		self.provider.push_function_scope();
 	}
 
+	pub fn push_upvalue_scope(&mut self, names: usize) {
+		self.provider.push_upvalue_scope(names);
+	}
+
 	fn get_lifetime(&self, link: Link) -> u32 {
 		self.lifetimes.get(&link).copied().unwrap_or(link.0 + 1)
 	}
@@ -51,8 +55,11 @@ impl LocalProvider {
 		self.provider.pull(until)
 	}
 
-	pub fn pull_all_into(&mut self, from: Range<u16>, to: u32, locals: &mut HashMap<Link, Place>) {
-		let iter = from.map(|port| Link(to, port)).map(|link| {
+	pub fn pull_all_into<I>(&mut self, from: I, to: u32, locals: &mut HashMap<Link, Place>)
+	where
+		I: IntoIterator<Item = u16>,
+	{
+		let iter = from.into_iter().map(|port| Link(to, port)).map(|link| {
 			let place = self.pull(link);
 
 			(link, place)
@@ -1,4 +1,5 @@
 use alloc::vec::Vec;
+use core::cmp::Reverse;
 use data_flow_graph::{
 	DataFlowGraph, Link, Node,
 	nested::{
@@ -21,6 +22,7 @@ mod local_provider;
 // FIXME: This needs to be rewritten to be a backwards pass instead.
 pub struct LocalAllocator {
 	locals: Vec<u32>,
+	runtime_names: HashMap<u32, usize>,
 
 	finder: LifetimeFinder,
 	provider: LocalProvider,
@@ -30,12 +32,23 @@ impl LocalAllocator {
 	pub fn new() -> Self {
 		Self {
 			locals: Vec::new(),
+			runtime_names: HashMap::new(),
 
 			finder: LifetimeFinder::new(),
 			provider: LocalProvider::new(),
 		}
 	}
 
+	/// Sets how many names of the runtime the function of `lambda_in` uses,
+	/// which its dependencies leave room for among its upvalues.
+	pub fn set_runtime_names(&mut self, lambda_in: u32, names: usize) {
+		self.runtime_names.insert(lambda_in, names);
+	}
+
+	pub fn clear_runtime_names(&mut self) {
+		self.runtime_names.clear();
+	}
+
 	fn handle_operation(&mut self, node: &Node, id: u32, locals: &mut HashMap<Link, Place>) {
 		let results = result_count_of(node);
 
@@ -52,22 +65,29 @@ impl LocalAllocator {
 		tables.insert(input, Table { name, len });
 	}
 
+	// Dependencies used the most are pulled first so that they stay direct
+	// upvalues, while the rest are spilled to a table keyed by the output.
 	fn handle_lambda_in(
 		&mut self,
 		id: u32,
 		lambda_in: &LambdaIn,
+		reference_finder: &ReferenceFinder,
+		tables: &mut HashMap<u32, Table>,
 		locals: &mut HashMap<Link, Place>,
 	) {
-		self.provider.push_function_scope();
+		let uses = reference_finder.count_uses(id, lambda_in.dependency_ports());
+		let mut dependencies: Vec<_> = lambda_in.dependency_ports().collect();
 
-		let dependencies = lambda_in.dependency_ports();
+		dependencies.sort_by_key(|&port| Reverse(uses[usize::from(port)]));
 
+		let names = self.runtime_names.get(&id).copied().unwrap_or_default();
+
+		self.provider.push_upvalue_scope(names);
 		self.provider.pull_all_into(dependencies, id, locals);
 
-		assert!(
-			self.provider.pop_function_scope().is_none(),
-			"node {id} has too many upvalues"
-		);
+		if let Some((name, len)) = self.provider.pop_function_scope() {
+			tables.insert(lambda_in.output, Table { name, len });
+		}
 
 		self.provider.push_function_scope();
 
@@ -162,11 +182,14 @@ impl LocalAllocator {
 		graph: &DataFlowGraph,
 		id: u32,
 		node: &Node,
+		reference_finder: &ReferenceFinder,
 		tables: &mut HashMap<u32, Table>,
 		locals: &mut HashMap<Link, Place>,
 	) {
 		match node {
-			Node::LambdaIn(lambda_in) => self.handle_lambda_in(id, lambda_in, locals),
+			Node::LambdaIn(lambda_in) => {
+				self.handle_lambda_in(id, lambda_in, reference_finder, tables, locals);
+			}
 			Node::LambdaOut(lambda_out) => self.handle_lambda_out(id, lambda_out, tables, locals),
 			Node::RegionIn(region_in) => self.handle_region_in(graph, id, region_in, locals),
 			Node::RegionOut(_) => self.handle_region_out(),
@@ -201,7 +224,7 @@ impl LocalAllocator {
 		for (node, id) in graph.nodes().zip(0..) {
 			self.provider.push_until(id);
 
-			self.handle_node(graph, id, node, tables, locals);
+			self.handle_node(graph, id, node, reference_finder, tables, locals);
 		}
 
 		assert!(
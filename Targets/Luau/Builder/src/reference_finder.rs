@@ -1,3 +1,6 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
 use data_flow_graph::{DataFlowGraph, Node};
 use data_flow_visitor::successor_finder::SuccessorFinder;
 
@@ -23,7 +26,8 @@ pub fn result_count_of(node: &Node) -> u16 {
 		| Node::MemoryFill(_)
 		| Node::MemoryCopy(_)
 		| Node::MemoryInit(_)
-		| Node::DataDrop(_) => 0,
+		| Node::DataDrop(_)
+		| Node::Throw(_) => 0,
 
 		Node::Host(_host) => 0,
 
@@ -37,7 +41,10 @@ pub fn result_count_of(node: &Node) -> u16 {
 		| Node::I64(_)
 		| Node::F32(_)
 		| Node::F64(_)
+		| Node::V128(_)
 		| Node::RefIsNull(_)
+		| Node::FunctionNew(_)
+		| Node::FunctionCheck(_)
 		| Node::IntegerUnaryOperation(_)
 		| Node::IntegerBinaryOperation(_)
 		| Node::IntegerCompareOperation(_)
@@ -53,6 +60,14 @@ pub fn result_count_of(node: &Node) -> u16 {
 		| Node::NumberWiden(_)
 		| Node::NumberTruncateToInteger(_)
 		| Node::NumberTransmuteToInteger(_)
+		| Node::V128UnaryOperation(_)
+		| Node::V128BinaryOperation(_)
+		| Node::V128ReduceOperation(_)
+		| Node::V128Splat(_)
+		| Node::V128ExtractLane(_)
+		| Node::V128ReplaceLane(_)
+		| Node::V128Shuffle(_)
+		| Node::V128BitSelect(_)
 		| Node::GlobalNew(_)
 		| Node::GlobalGet(_)
 		| Node::TableNew(_)
@@ -64,7 +79,11 @@ pub fn result_count_of(node: &Node) -> u16 {
 		| Node::MemoryLoad(_)
 		| Node::MemorySize(_)
 		| Node::MemoryGrow(_)
-		| Node::DataNew(_) => 1,
+		| Node::DataNew(_)
+		| Node::TagNew
+		| Node::ExceptionNew(_)
+		| Node::ExceptionTest(_)
+		| Node::ExceptionGet(_) => 1,
 
 		Node::Call(call) => call.results,
 	}
@@ -100,6 +119,27 @@ impl ReferenceFinder {
 			.any(|item| item.port > 0 && item.port < result_count_of(node))
 	}
 
+	/// Returns how many times each of the `ports` of `id` is used.
+	pub fn count_uses(&self, id: u32, ports: Range<u16>) -> Vec<u32> {
+		let mut counts = vec![0; ports.len()];
+
+		for item in self.successor_finder.at(id) {
+			if ports.contains(&item.port) {
+				counts[usize::from(item.port - ports.start)] += 1;
+			}
+		}
+
+		counts
+	}
+
+	/// Returns every node that uses the `port` of `id`.
+	pub fn find_uses(&self, id: u32, port: u16) -> impl Iterator<Item = u32> {
+		self.successor_finder
+			.at(id)
+			.filter(move |item| item.port == port)
+			.map(|item| item.to)
+	}
+
 	pub fn run(&mut self, graph: &DataFlowGraph) {
 		self.successor_finder.run(graph);
 	}
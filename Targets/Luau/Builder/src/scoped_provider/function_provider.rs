@@ -4,20 +4,20 @@ use crate::place::Place;
 
 use super::{index_provider::IndexProvider, table_provider::TableProvider};
 
-// Luau has a max amount of local variables and no register allocator, so we must
-// decide to spill to our own table per function after this count is reached.
-const MAX_LOCAL_VARIABLES: usize = 199;
-
 pub struct FunctionProvider {
 	pub local_provider: IndexProvider,
 	pub table_provider: TableProvider,
+
+	limit: usize,
 }
 
 impl FunctionProvider {
-	pub const fn new() -> Self {
+	pub const fn new(limit: usize) -> Self {
 		Self {
 			local_provider: IndexProvider::new(),
 			table_provider: TableProvider::Nothing,
+
+			limit,
 		}
 	}
 
@@ -39,7 +39,7 @@ impl FunctionProvider {
 	}
 
 	pub fn pull(&mut self, until: u32) -> Place {
-		if self.local_provider.should_exceed(MAX_LOCAL_VARIABLES) {
+		if self.local_provider.should_exceed(self.limit) {
 			self.pull_slow(until)
 		} else {
 			self.pull_fast(until)
@@ -9,6 +9,14 @@ mod function_provider;
 mod index_provider;
 mod table_provider;
 
+// Luau has a max amount of local variables and no register allocator, so we must
+// decide to spill to our own table per function after this count is reached.
+const MAX_LOCAL_VARIABLES: usize = 199;
+
+// Luau also has a max amount of upvalues, which includes the runtime functions
+// used by the body, so those are taken out of this count per function.
+pub const MAX_UPVALUES: usize = 199;
+
 pub struct ScopedProvider {
 	function_providers: Vec<FunctionProvider>,
 	first_names: Vec<u32>,
@@ -38,6 +46,7 @@ impl ScopedProvider {
 		let FunctionProvider {
 			local_provider,
 			table_provider,
+			..
 		} = self.function_providers.pop().unwrap();
 
 		if let Some(function_provider) = self.function_providers.last_mut() {
@@ -49,8 +58,8 @@ impl ScopedProvider {
 		table_provider.try_into_created()
 	}
 
-	pub fn push_function_scope(&mut self) {
-		let mut function_provider = FunctionProvider::new();
+	fn push_scope_with_limit(&mut self, limit: usize) {
+		let mut function_provider = FunctionProvider::new(limit);
 
 		if let Some(FunctionProvider { local_provider, .. }) = self.function_providers.last() {
 			function_provider
@@ -61,6 +70,17 @@ impl ScopedProvider {
 		self.function_providers.push(function_provider);
 	}
 
+	pub fn push_function_scope(&mut self) {
+		self.push_scope_with_limit(MAX_LOCAL_VARIABLES);
+	}
+
+	// Dependencies of a function are defined in a scope of their own and
+	// then captured, so this scope spills once upvalues would run out with
+	// the `names` of the runtime that the function uses.
+	pub fn push_upvalue_scope(&mut self, names: usize) {
+		self.push_scope_with_limit(MAX_UPVALUES.saturating_sub(names));
+	}
+
 	pub fn pull(&mut self, until: u32) -> Place {
 		let function_provider = self.function_providers.last_mut().unwrap();
 
@@ -0,0 +1,494 @@
+use control_flow_graph::ControlFlowGraph;
+use data_flow_builder::DataFlowBuilder;
+use data_flow_graph::{DataFlowGraph, nested::OmegaOut};
+use data_flow_visitor::{
+	pass_manager::{Pipeline, Registry},
+	region_identity,
+	topological_normalizer::TopologicalNormalizer,
+	verifier::Verifier,
+};
+use luau_builder::LuauBuilder;
+use luau_printer::{
+	LuauPrinter,
+	library::{LibraryPrinter, LibrarySections, NamesFinder},
+};
+use luau_tree::{
+	LuauTree,
+	statement::{Export, Statement},
+};
+
+pub use data_flow_builder::{CompileError, Instance};
+
+/// The results of compiling a single module or a linked bundle.
+pub struct Output {
+	/// The data flow graph after all passes have run.
+	pub graph: DataFlowGraph,
+
+	/// The Luau tree built from the `graph`.
+	pub tree: LuauTree,
+
+	/// The runtime sections needed by the `module`, or the bindings to a
+	/// shared runtime if one is used.
+	pub library: String,
+
+	/// The module source, which must be placed after the `library`.
+	pub module: String,
+
+	/// The sources of the chunks that the `module` loads in order, if it
+	/// was split, each with its own bindings to the shared runtime.
+	pub chunks: Vec<String>,
+
+	/// Pairs of lines in the `module` and the code offsets they came from,
+	/// which are relative to the start of their own module.
+	pub positions: Vec<(usize, u32)>,
+
+	/// The control flow graph of each function body, if they were kept.
+	pub control_flow_graphs: Vec<ControlFlowGraph>,
+}
+
+/// Compiles WebAssembly modules into Luau source.
+pub struct Compiler {
+	sections: LibrarySections,
+	pipeline: Pipeline,
+	verifier: Verifier,
+	shared_runtime: Option<String>,
+	split_size: Option<usize>,
+	chunk_source: String,
+	only_exports: Option<Vec<String>>,
+
+	optimize: bool,
+	fixed_point: bool,
+	debug: bool,
+	strict: bool,
+	keep_control_flow: bool,
+}
+
+impl Compiler {
+	/// The passes run by `optimize` unless others are set. Folding expects
+	/// inputs to come before their users, so the nodes that inlining and
+	/// pruning add are sorted first.
+	pub const DEFAULT_PASSES: &str = "inline,prune,normalize,fold,gvn,licm,fallthrough,dce";
+
+	/// The expression that chunks are loaded with unless another is set.
+	pub const DEFAULT_CHUNK_SOURCE: &str = "require(\"./chunk_{}\")";
+
+	// Annotations may name the types of the runtime even where none of
+	// its functions that return them are used.
+	const TYPE_SECTIONS: [&str; 4] = ["memory_type", "data_type", "elements_type", "table_type"];
+
+	/// # Panics
+	///
+	/// Panics if the `DEFAULT_PASSES` are not all built in.
+	#[must_use]
+	pub fn new() -> Self {
+		let registry = Registry::with_built_ins();
+
+		Self {
+			sections: LibrarySections::with_built_ins(),
+			pipeline: Pipeline::parse(Self::DEFAULT_PASSES, &registry)
+				.expect("default passes should be built in"),
+			verifier: Verifier::new(),
+			shared_runtime: None,
+			split_size: None,
+			chunk_source: Self::DEFAULT_CHUNK_SOURCE.into(),
+			only_exports: None,
+
+			optimize: false,
+			fixed_point: false,
+			debug: false,
+			strict: false,
+			keep_control_flow: false,
+		}
+	}
+
+	/// Sets whether all optimization passes are run on code.
+	#[must_use]
+	pub const fn optimize(mut self, optimize: bool) -> Self {
+		self.optimize = optimize;
+		self
+	}
+
+	/// Sets the passes that are run when optimizing.
+	#[must_use]
+	pub fn passes(mut self, pipeline: Pipeline) -> Self {
+		self.pipeline = pipeline;
+		self
+	}
+
+	/// Sets whether the passes are repeated until they stop changing the graph.
+	#[must_use]
+	pub const fn fixed_point(mut self, fixed_point: bool) -> Self {
+		self.fixed_point = fixed_point;
+		self
+	}
+
+	/// Sets whether debug information is embedded if present.
+	#[must_use]
+	pub const fn debug(mut self, debug: bool) -> Self {
+		self.debug = debug;
+		self
+	}
+
+	/// Sets whether the output is annotated with types and checked by Luau
+	/// in `--!strict` mode.
+	#[must_use]
+	pub const fn strict(mut self, strict: bool) -> Self {
+		self.strict = strict;
+		self
+	}
+
+	/// Sets whether the control flow graph of each function body is kept.
+	#[must_use]
+	pub const fn keep_control_flow(mut self, keep_control_flow: bool) -> Self {
+		self.keep_control_flow = keep_control_flow;
+		self
+	}
+
+	/// Sets the Luau expression that modules read a shared runtime from,
+	/// such as a `require` call, instead of containing the runtime.
+	#[must_use]
+	pub fn shared_runtime(mut self, source: Option<String>) -> Self {
+		self.shared_runtime = source;
+		self
+	}
+
+	/// Splits the module into chunks of about `size` bytes of source when
+	/// set, which keeps each of them within the limits of the Luau VM. The
+	/// chunks should use a `shared_runtime` so that they share its state.
+	#[must_use]
+	pub const fn split_size(mut self, size: Option<usize>) -> Self {
+		self.split_size = size;
+		self
+	}
+
+	/// Sets the Luau expression that the module loads each chunk with,
+	/// where `{}` is replaced by the number of the chunk from 1.
+	#[must_use]
+	pub fn chunk_source(mut self, source: String) -> Self {
+		self.chunk_source = source;
+		self
+	}
+
+	/// Keeps only the exports with these `names` when set, written as
+	/// `instance.name` for those of a linked bundle. Items that only the
+	/// others used are removed by the `prune` pass, and names that match no
+	/// export fail to compile.
+	#[must_use]
+	pub fn only_exports(mut self, names: Option<Vec<String>>) -> Self {
+		self.only_exports = names;
+		self
+	}
+
+	/// Returns the optimization passes along with the time spent in them.
+	#[must_use]
+	pub const fn pipeline(&self) -> &Pipeline {
+		&self.pipeline
+	}
+
+	fn run_optimizations(&mut self, graph: &mut DataFlowGraph, omega: u32) -> u32 {
+		if self.fixed_point {
+			self.pipeline.run_to_fixed_point(graph, omega)
+		} else {
+			self.pipeline.run(graph, omega)
+		}
+	}
+
+	// Names that match no export are reported rather than ignored, as a
+	// misspelled one would otherwise drop the export it was meant to keep.
+	fn filter_exports(&self, graph: &mut DataFlowGraph, omega: u32) -> Result<(), CompileError> {
+		let Some(names) = &self.only_exports else {
+			return Ok(());
+		};
+
+		let OmegaOut { exports, .. } = graph.get_mut(omega).as_mut_omega_out().unwrap();
+		let mut found = vec![false; names.len()];
+
+		exports.retain(|export| {
+			let name = match &export.instance {
+				Some(instance) => format!("{instance}.{}", export.identifier),
+				None => export.identifier.to_string(),
+			};
+
+			let mut keep = false;
+
+			for (found, other) in found.iter_mut().zip(names) {
+				if *other == name {
+					*found = true;
+					keep = true;
+				}
+			}
+
+			keep
+		});
+
+		let unknown: Vec<_> = names
+			.iter()
+			.zip(found)
+			.filter(|&(_, found)| !found)
+			.map(|(name, _)| name.clone())
+			.collect();
+
+		if unknown.is_empty() {
+			Ok(())
+		} else {
+			Err(CompileError::UnknownExports { names: unknown })
+		}
+	}
+
+	fn run_post_process(graph: &mut DataFlowGraph, omega: u32) -> u32 {
+		let mut topological_normalizer = TopologicalNormalizer::new();
+
+		region_identity::insert(graph);
+
+		topological_normalizer.run(graph, omega)
+	}
+
+	// Passes verify the graph themselves, so we only need to check
+	// what goes into and comes out of them.
+	fn verify(&mut self, graph: &DataFlowGraph, omega: u32, stage: &str) {
+		if !cfg!(debug_assertions) {
+			return;
+		}
+
+		if let Err(error) = self.verifier.run(graph, omega) {
+			panic!("graph should be well formed after {stage}, {error}");
+		}
+	}
+
+	fn build_data_flow_graph<H>(
+		&mut self,
+		builder: &mut DataFlowBuilder,
+		build: H,
+	) -> Result<DataFlowGraph, CompileError>
+	where
+		H: FnOnce(&mut DataFlowBuilder, &mut DataFlowGraph) -> Result<u32, CompileError>,
+	{
+		let mut graph = DataFlowGraph::new();
+
+		builder.set_keep_control_flow(self.keep_control_flow);
+
+		let omega = build(builder, &mut graph)?;
+
+		self.verify(&graph, omega, "building");
+		self.filter_exports(&mut graph, omega)?;
+
+		let omega = if self.optimize {
+			self.run_optimizations(&mut graph, omega)
+		} else {
+			omega
+		};
+
+		let omega = Self::run_post_process(&mut graph, omega);
+
+		self.verify(&graph, omega, "post processing");
+
+		Ok(graph)
+	}
+
+	fn print_library(&self, references: &[&'static str]) -> String {
+		let mut printer = LibraryPrinter::new();
+		let mut library = Vec::new();
+		let mut references = references.to_vec();
+
+		if self.strict {
+			library.extend_from_slice(b"--!strict\n");
+
+			references.extend(Self::TYPE_SECTIONS);
+		}
+
+		if let Some(source) = &self.shared_runtime {
+			printer
+				.print_bindings(&references, source, &self.sections, &mut library)
+				.expect("library should print");
+		} else {
+			printer.resolve(&references, &self.sections);
+			printer
+				.print(&self.sections, &mut library)
+				.expect("library should print");
+		}
+
+		String::from_utf8(library).expect("library should be UTF-8")
+	}
+
+	/// Prints the runtime as a standalone module returning a table of its
+	/// `rt_` functions, for modules compiled with a `shared_runtime`. Only
+	/// the sections needed by the `tree` are kept if one is given.
+	#[must_use]
+	pub fn print_runtime(&self, tree: Option<&LuauTree>) -> String {
+		let mut printer = LibraryPrinter::new();
+		let mut runtime = Vec::new();
+
+		if self.strict {
+			runtime.extend_from_slice(b"--!strict\n");
+		}
+
+		if let Some(tree) = tree {
+			let mut references = Vec::new();
+
+			NamesFinder::new(&mut references).run(tree);
+			printer.resolve(&references, &self.sections);
+		} else {
+			printer.resolve_all(&self.sections);
+		}
+
+		printer
+			.print_module(&self.sections, &mut runtime)
+			.expect("runtime should print");
+
+		String::from_utf8(runtime).expect("runtime should be UTF-8")
+	}
+
+	/// Prints the types of the environment that the `tree` needs and of
+	/// its exports, preceded by the runtime types they name, so that code
+	/// instantiating the module can be checked against them.
+	#[must_use]
+	pub fn print_interface(&self, tree: &LuauTree) -> String {
+		let mut library = LibraryPrinter::new();
+		let mut interface = b"--!strict\n".to_vec();
+
+		library.resolve(&Self::TYPE_SECTIONS, &self.sections);
+		library
+			.print(&self.sections, &mut interface)
+			.expect("library should print");
+
+		LuauPrinter::new()
+			.print_interface(tree, &mut interface)
+			.expect("interface should print");
+
+		String::from_utf8(interface).expect("interface should be UTF-8")
+	}
+
+	fn find_references(statements: &[Statement], exports: &[Export]) -> Vec<&'static str> {
+		let mut references = Vec::new();
+
+		NamesFinder::new(&mut references).run_partial(statements, exports);
+
+		references
+	}
+
+	fn load_printer(&self, tree: &LuauTree) -> LuauPrinter {
+		let mut printer = LuauPrinter::new();
+
+		if self.debug {
+			for (name, identifier) in &tree.names {
+				printer.set_name(*name, identifier);
+			}
+		}
+
+		if self.strict {
+			printer.annotate(tree);
+		}
+
+		printer
+	}
+
+	fn print_module(&self, tree: &LuauTree) -> (String, Vec<(usize, u32)>) {
+		let mut printer = self.load_printer(tree);
+		let mut module = Vec::new();
+
+		printer
+			.print(tree, &mut module)
+			.expect("module should print");
+
+		let module = String::from_utf8(module).expect("module should be UTF-8");
+
+		(module, printer.positions().to_vec())
+	}
+
+	fn print_chunks(&self, tree: &LuauTree, size: usize) -> (String, Vec<String>) {
+		let mut printer = self.load_printer(tree);
+		let mut sources = Vec::new();
+		let mut chunks = Vec::new();
+
+		for (chunk, index) in printer.split(tree, size).iter().zip(1_usize..) {
+			let statements = &tree.code.list[chunk.range.clone()];
+			let mut source = self
+				.print_library(&Self::find_references(statements, &[]))
+				.into_bytes();
+
+			printer
+				.print_chunk(tree, chunk, &mut source)
+				.expect("chunk should print");
+
+			sources.push(self.chunk_source.replace("{}", &index.to_string()));
+			chunks.push(String::from_utf8(source).expect("chunk should be UTF-8"));
+		}
+
+		let mut module = Vec::new();
+
+		printer
+			.print_entry(tree, &sources, &mut module)
+			.expect("module should print");
+
+		let module = String::from_utf8(module).expect("module should be UTF-8");
+
+		(module, chunks)
+	}
+
+	fn compile_with<H>(&mut self, build: H) -> Result<Output, CompileError>
+	where
+		H: FnOnce(&mut DataFlowBuilder, &mut DataFlowGraph) -> Result<u32, CompileError>,
+	{
+		let mut builder = DataFlowBuilder::new();
+
+		let graph = self.build_data_flow_graph(&mut builder, build)?;
+		let tree = LuauBuilder::new().run(&graph);
+		let (library, module, positions, chunks) = if let Some(size) = self.split_size {
+			let library = self.print_library(&Self::find_references(&[], &tree.exports));
+			let (module, chunks) = self.print_chunks(&tree, size);
+
+			(library, module, Vec::new(), chunks)
+		} else {
+			let library =
+				self.print_library(&Self::find_references(&tree.code.list, &tree.exports));
+			let (module, positions) = self.print_module(&tree);
+
+			(library, module, positions, Vec::new())
+		};
+
+		Ok(Output {
+			graph,
+			tree,
+			library,
+			module,
+			chunks,
+			positions,
+			control_flow_graphs: builder.take_control_flow_graphs(),
+		})
+	}
+
+	/// Validates and compiles the WebAssembly module in `data`.
+	///
+	/// # Errors
+	///
+	/// Returns an error if `data` is not a valid WebAssembly binary or
+	/// cannot be compiled by this implementation.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if the data flow graph is found to be malformed.
+	pub fn compile(&mut self, data: &[u8]) -> Result<Output, CompileError> {
+		self.compile_with(|builder, graph| builder.run(graph, data))
+	}
+
+	/// Validates, compiles and links the `instances` in order into a single
+	/// bundle. Its exports are grouped into a table per instance.
+	///
+	/// # Errors
+	///
+	/// Returns an error if any module cannot be compiled, or if an import
+	/// does not match the export of an earlier instance that it names.
+	///
+	/// # Panics
+	///
+	/// Panics in debug builds if the data flow graph is found to be malformed.
+	pub fn link(&mut self, instances: &[Instance]) -> Result<Output, CompileError> {
+		self.compile_with(|builder, graph| builder.run_linked(graph, instances))
+	}
+}
+
+impl Default for Compiler {
+	fn default() -> Self {
+		Self::new()
+	}
+}
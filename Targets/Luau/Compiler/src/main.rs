@@ -0,0 +1,445 @@
+use std::{
+	fs::File,
+	io::{BufWriter, Read, Write},
+	path::{Path, PathBuf},
+	process::ExitCode,
+};
+
+use clap::{ArgGroup, Parser, ValueEnum};
+use data_flow_visitor::{
+	inliner::Inliner,
+	pass_manager::{Pipeline, Registry},
+};
+use luau_compiler::{Compiler, Instance, Output};
+use luau_printer::SourceMap;
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Emit {
+	/// The runtime library followed by the module
+	Luau,
+
+	/// Only the runtime library
+	RuntimeOnly,
+
+	/// Only the module
+	ModuleOnly,
+
+	/// The runtime library as a standalone module for `--shared-runtime`
+	SharedRuntime,
+
+	/// The Luau types of the environment that the module imports from and
+	/// of its exports
+	Imports,
+
+	/// The control flow graph of every function in DOT format
+	CfgDot,
+
+	/// The data flow graph in DOT format
+	DfgDot,
+
+	/// The data flow graph in its textual format
+	DfgText,
+
+	/// The Luau tree in its debug format
+	LuauTree,
+}
+
+#[derive(Parser)]
+#[command(version)]
+#[command(group(ArgGroup::new("optimization").args(["optimize", "passes"]).multiple(true)))]
+struct Arguments {
+	/// The WebAssembly files for processing, or `-` for standard input.
+	/// Several files, or any written as `instance=file`, are linked into
+	/// one bundle with instances named after their files by default
+	#[arg(required_unless_present = "full_runtime")]
+	files: Vec<String>,
+
+	/// The file to write to instead of standard output
+	#[arg(long, short)]
+	output: Option<String>,
+
+	/// The kind of output to produce
+	#[arg(long, value_enum, default_value_t = Emit::Luau)]
+	emit: Emit,
+
+	/// Read the runtime library from this Luau expression, such as a
+	/// `require` call or `...`, instead of placing it before the module
+	#[arg(long)]
+	shared_runtime: Option<String>,
+
+	/// Keep every section when emitting a shared runtime, which then
+	/// needs no input files
+	#[arg(long)]
+	full_runtime: bool,
+
+	/// Split the module into chunks of about this many bytes of source,
+	/// which are written next to the output as `{name}_{number}.luau`
+	#[arg(long, value_name = "BYTES", requires = "output")]
+	split_size: Option<usize>,
+
+	/// Load each chunk with this Luau expression instead, where `{}` is
+	/// the number of the chunk, such as `require(script.Parent.chunk_{})`
+	#[arg(long, requires = "split_size")]
+	chunk_source: Option<String>,
+
+	/// Annotate the output with types and mark it for `--!strict` checking
+	#[arg(long)]
+	strict: bool,
+
+	/// Embed debug information if present
+	#[arg(long, short)]
+	debug: bool,
+
+	/// Run all optimization passes on code
+	#[arg(long, short = 'O')]
+	optimize: bool,
+
+	/// Run these comma separated passes instead, implying `--optimize`
+	#[arg(long)]
+	passes: Option<String>,
+
+	/// Keep only the exports with these names, written as `instance.name`
+	/// when linking, so that optimizing removes what only the others use
+	#[arg(long, visible_alias = "keep-export", value_name = "NAME")]
+	only_export: Vec<String>,
+
+	/// Inline functions of at most this many nodes into their callers,
+	/// which needs `--optimize` or `--passes`
+	#[arg(long, value_name = "NODES", requires = "optimization")]
+	inline_threshold: Option<usize>,
+
+	/// Repeat the passes until they stop changing the graph, which needs
+	/// `--optimize` or `--passes`
+	#[arg(long, requires = "optimization")]
+	fixed_point: bool,
+
+	/// Print the time spent in each pass
+	#[arg(long)]
+	time_passes: bool,
+
+	/// Write a map from output lines to code offsets
+	#[arg(long)]
+	source_map: Option<String>,
+}
+
+fn read_input(file: &str) -> std::io::Result<Vec<u8>> {
+	if file == "-" {
+		let mut data = Vec::new();
+
+		std::io::stdin().lock().read_to_end(&mut data)?;
+
+		Ok(data)
+	} else {
+		std::fs::read(file)
+	}
+}
+
+// Splits `instance=file` into its parts, naming the instance after the
+// file itself when no name is given.
+fn split_instance(file: &str) -> (&str, &str) {
+	file.split_once('=').unwrap_or_else(|| {
+		let name = Path::new(file)
+			.file_stem()
+			.and_then(|name| name.to_str())
+			.unwrap_or(file);
+
+		(name, file)
+	})
+}
+
+fn is_linked(files: &[String]) -> bool {
+	files.len() != 1 || files[0].contains('=')
+}
+
+fn read_inputs(files: &[String]) -> Result<Vec<(&str, Vec<u8>)>, String> {
+	files
+		.iter()
+		.map(|file| {
+			let (name, path) = split_instance(file);
+
+			read_input(path)
+				.map(|data| (name, data))
+				.map_err(|error| format!("could not read `{path}`: {error}"))
+		})
+		.collect()
+}
+
+fn run_compiler(
+	compiler: &mut Compiler,
+	files: &[String],
+	inputs: &[(&str, Vec<u8>)],
+) -> Result<Output, String> {
+	if !is_linked(files) {
+		return compiler
+			.compile(&inputs[0].1)
+			.map_err(|error| format!("could not compile `{}`: {error}", files[0]));
+	}
+
+	let instances: Vec<_> = inputs
+		.iter()
+		.map(|&(name, ref data)| Instance { name, data })
+		.collect();
+
+	compiler
+		.link(&instances)
+		.map_err(|error| format!("could not link: {error}"))
+}
+
+fn open_output(output: Option<&str>) -> std::io::Result<Box<dyn Write>> {
+	const DEFAULT_BUF_SIZE: usize = 1024 * 1024;
+
+	let output: Box<dyn Write> = match output {
+		Some(path) => Box::new(BufWriter::with_capacity(
+			DEFAULT_BUF_SIZE,
+			File::create(path)?,
+		)),
+		None => Box::new(BufWriter::with_capacity(
+			DEFAULT_BUF_SIZE,
+			std::io::stdout().lock(),
+		)),
+	};
+
+	Ok(output)
+}
+
+fn write_output(
+	compiler: &Compiler,
+	output: &Output,
+	arguments: &Arguments,
+	out: &mut dyn Write,
+) -> std::io::Result<()> {
+	match arguments.emit {
+		Emit::Luau => {
+			out.write_all(output.library.as_bytes())?;
+			out.write_all(output.module.as_bytes())?;
+		}
+		Emit::RuntimeOnly => out.write_all(output.library.as_bytes())?,
+		Emit::ModuleOnly => out.write_all(output.module.as_bytes())?,
+		Emit::SharedRuntime => {
+			let tree = (!arguments.full_runtime).then_some(&output.tree);
+
+			out.write_all(compiler.print_runtime(tree).as_bytes())?;
+		}
+		Emit::Imports => out.write_all(compiler.print_interface(&output.tree).as_bytes())?,
+		Emit::CfgDot => {
+			for graph in &output.control_flow_graphs {
+				write!(out, "{}", control_flow_graph::Dot::new(graph))?;
+			}
+		}
+		Emit::DfgDot => write!(out, "{}", data_flow_graph::Dot::new(&output.graph))?,
+		Emit::DfgText => write!(out, "{}", data_flow_graph::Text::new(&output.graph))?,
+		Emit::LuauTree => writeln!(out, "{:#?}", output.tree)?,
+	}
+
+	out.flush()
+}
+
+fn write_source_map(output: &Output, emit: Emit, data: &[u8], path: &str) -> std::io::Result<()> {
+	let source_map = SourceMap::new(data);
+	let lines = if emit == Emit::Luau {
+		output.library.lines().count()
+	} else {
+		0
+	};
+
+	let positions: Vec<_> = output
+		.positions
+		.iter()
+		.map(|&(line, offset)| (lines + line, offset))
+		.collect();
+
+	let mut file = BufWriter::new(File::create(path)?);
+
+	source_map.write(&positions, &mut file)?;
+	file.flush()
+}
+
+// Chunks are named after the output file and written next to it.
+fn get_chunk_stem(output: &str) -> &str {
+	Path::new(output)
+		.file_stem()
+		.and_then(|stem| stem.to_str())
+		.unwrap_or("chunk")
+}
+
+fn get_chunk_path(output: &str, index: usize) -> PathBuf {
+	let stem = get_chunk_stem(output);
+
+	Path::new(output).with_file_name(format!("{stem}_{index}.luau"))
+}
+
+fn get_chunk_source(output: &str) -> String {
+	let stem = get_chunk_stem(output);
+
+	format!("require(\"./{stem}_{{}}\")")
+}
+
+fn write_chunks(output: &Output, path: &str) -> std::io::Result<()> {
+	for (chunk, index) in output.chunks.iter().zip(1..) {
+		let mut file = BufWriter::new(File::create(get_chunk_path(path, index))?);
+
+		file.write_all(chunk.as_bytes())?;
+		file.flush()?;
+	}
+
+	Ok(())
+}
+
+fn write_full_runtime(compiler: &Compiler, output: Option<&str>) -> std::io::Result<()> {
+	let mut out = open_output(output)?;
+
+	out.write_all(compiler.print_runtime(None).as_bytes())?;
+	out.flush()
+}
+
+fn load_compiler(arguments: &Arguments) -> Result<Compiler, String> {
+	if arguments.files.is_empty() && arguments.emit != Emit::SharedRuntime {
+		return Err("input files are needed unless emitting the full shared runtime".into());
+	}
+
+	if arguments.source_map.is_some() && is_linked(&arguments.files) {
+		return Err("`--source-map` needs a single module that is not linked".into());
+	}
+
+	if arguments.split_size.is_some() {
+		if arguments.shared_runtime.is_none() {
+			return Err("`--split-size` needs a `--shared-runtime` for the chunks to share".into());
+		}
+
+		if arguments.emit != Emit::Luau || arguments.source_map.is_some() {
+			return Err("`--split-size` only works with `--emit luau` and no source map".into());
+		}
+	}
+
+	let mut compiler = Compiler::new()
+		.optimize(arguments.optimize)
+		.fixed_point(arguments.fixed_point)
+		.debug(arguments.debug)
+		.strict(arguments.strict)
+		.shared_runtime(arguments.shared_runtime.clone())
+		.split_size(arguments.split_size)
+		.only_exports((!arguments.only_export.is_empty()).then(|| arguments.only_export.clone()))
+		.keep_control_flow(arguments.emit == Emit::CfgDot);
+
+	if let Some(output) = &arguments.output
+		&& arguments.split_size.is_some()
+	{
+		let source = arguments
+			.chunk_source
+			.clone()
+			.unwrap_or_else(|| get_chunk_source(output));
+
+		compiler = compiler.chunk_source(source);
+	}
+
+	if arguments.passes.is_some() || arguments.inline_threshold.is_some() {
+		let mut registry = Registry::with_built_ins();
+
+		if let Some(threshold) = arguments.inline_threshold {
+			registry.add("inline", move || {
+				Box::new(Inliner::with_threshold(threshold))
+			});
+		}
+
+		let passes = arguments
+			.passes
+			.as_deref()
+			.unwrap_or(Compiler::DEFAULT_PASSES);
+
+		let pipeline = Pipeline::parse(passes, &registry).map_err(|error| {
+			let names: Vec<_> = registry.names().collect();
+
+			format!("{error}, expected one of: {}", names.join(", "))
+		})?;
+
+		compiler = compiler.passes(pipeline);
+	}
+
+	if arguments.passes.is_some() {
+		compiler = compiler.optimize(true);
+	}
+
+	Ok(compiler)
+}
+
+fn print_timings(pipeline: &Pipeline) {
+	for (name, elapsed) in pipeline.timings() {
+		eprintln!("{name:>16}: {elapsed:?}");
+	}
+}
+
+fn main() -> ExitCode {
+	let arguments = Arguments::parse();
+	let inputs = match read_inputs(&arguments.files) {
+		Ok(inputs) => inputs,
+		Err(error) => {
+			eprintln!("error: {error}");
+
+			return ExitCode::FAILURE;
+		}
+	};
+
+	let mut compiler = match load_compiler(&arguments) {
+		Ok(compiler) => compiler,
+		Err(error) => {
+			eprintln!("error: {error}");
+
+			return ExitCode::FAILURE;
+		}
+	};
+
+	if arguments.files.is_empty() {
+		if let Err(error) = write_full_runtime(&compiler, arguments.output.as_deref()) {
+			eprintln!("error: could not write output: {error}");
+
+			return ExitCode::FAILURE;
+		}
+
+		return ExitCode::SUCCESS;
+	}
+
+	let output = match run_compiler(&mut compiler, &arguments.files, &inputs) {
+		Ok(output) => output,
+		Err(error) => {
+			eprintln!("error: {error}");
+
+			return ExitCode::FAILURE;
+		}
+	};
+
+	if arguments.time_passes {
+		print_timings(compiler.pipeline());
+	}
+
+	let result = open_output(arguments.output.as_deref())
+		.and_then(|mut out| write_output(&compiler, &output, &arguments, &mut out));
+
+	if let Err(error) = result {
+		eprintln!("error: could not write output: {error}");
+
+		return ExitCode::FAILURE;
+	}
+
+	let result = arguments
+		.output
+		.as_deref()
+		.map_or(Ok(()), |path| write_chunks(&output, path));
+
+	if let Err(error) = result {
+		eprintln!("error: could not write chunks: {error}");
+
+		return ExitCode::FAILURE;
+	}
+
+	let result = arguments.source_map.as_deref().map_or(Ok(()), |path| {
+		write_source_map(&output, arguments.emit, &inputs[0].1, path)
+	});
+
+	if let Err(error) = result {
+		eprintln!("error: could not write source map: {error}");
+
+		return ExitCode::FAILURE;
+	}
+
+	ExitCode::SUCCESS
+}
@@ -1,13 +1,19 @@
 use std::io::{Result, Write};
 
-use luau_tree::expression::{
-	Call, DataNew, ElementsNew, Expression, Function, GlobalGet, GlobalNew, Import,
-	IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
-	IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Local, Location,
-	Match, MemoryGrow, MemoryLoad, MemoryNew, MemorySize, Name, NumberBinaryOperation,
-	NumberBinaryOperator, NumberCompareOperation, NumberCompareOperator, NumberNarrow,
-	NumberTransmuteToInteger, NumberTruncateToInteger, NumberType, NumberUnaryOperation,
-	NumberUnaryOperator, NumberWiden, RefIsNull, Scoped, TableGet, TableGrow, TableNew, TableSize,
+use luau_tree::{
+	expression::{
+		Call, DataNew, ElementsNew, ExceptionGet, ExceptionNew, ExceptionTest, Expression,
+		Function, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, Import, IntegerBinaryOperation,
+		IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend, IntegerNarrow,
+		IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Local, Location, Match,
+		MemoryGrow, MemoryLoad, MemoryNew, MemorySize, Name, NumberBinaryOperation,
+		NumberBinaryOperator, NumberCompareOperation, NumberCompareOperator, NumberNarrow,
+		NumberTransmuteToInteger, NumberTruncateToInteger, NumberType, NumberUnaryOperation,
+		NumberUnaryOperator, NumberWiden, RefIsNull, Scoped, TableGet, TableGrow, TableNew,
+		TableSize, V128BinaryOperation, V128BitSelect, V128ExtractLane, V128ReduceOperation,
+		V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
+	},
+	statement::ExportType,
 };
 
 use crate::{LuauPrinter, library::NeedsName, print::Print};
@@ -59,13 +65,35 @@ impl Print for Function {
 			arguments,
 			code,
 			returns,
+			signature,
+			tail,
 		} = self;
 
+		if *tail {
+			let intrinsic = self.needs_name();
+
+			write!(out, "rt_{intrinsic}")?;
+		}
+
 		write!(out, "(function(")?;
 
-		fmt_delimited(arguments, printer, out)?;
+		for (index, (&name, &r#type)) in arguments.iter().zip(&signature.arguments).enumerate() {
+			if index != 0 {
+				write!(out, ", ")?;
+			}
+
+			name.print(printer, out)?;
+			printer.fmt_argument_type(name, r#type, out)?;
+		}
 
-		writeln!(out, ")")?;
+		write!(out, ")")?;
+
+		// Tail functions may return the next call instead of their results.
+		if !*tail {
+			printer.fmt_results_type(&signature.results, out)?;
+		}
+
+		writeln!(out)?;
 
 		printer.indent();
 		code.print(printer, out)?;
@@ -90,17 +118,14 @@ impl Print for Scoped {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
 		let Self { locals, function } = self;
 
-		if locals.is_empty() {
+		if locals.list.is_empty() {
 			return function.print(printer, out);
 		}
 
 		writeln!(out, "(function()")?;
 
 		printer.indent();
-
-		for local in locals {
-			local.print(printer, out)?;
-		}
+		locals.print(printer, out)?;
 
 		printer.tab(out)?;
 		write!(out, "return ")?;
@@ -163,25 +188,34 @@ impl Print for Match {
 	}
 }
 
+const fn get_kind_name(r#type: &ExportType) -> &'static str {
+	match r#type {
+		ExportType::Function(_) => "function",
+		ExportType::Global(_) => "global",
+		ExportType::Table => "table",
+		ExportType::Memory => "memory",
+		ExportType::Unknown => "any",
+	}
+}
+
 impl Print for Import {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
 		let Self {
 			environment,
 			namespace,
 			identifier,
+			r#type,
 		} = self;
 
-		write!(out, "assert(")?;
+		write!(out, "rt_import(")?;
 
 		environment.print(printer, out)?;
 
 		let namespace = namespace.as_bytes().escape_ascii();
 		let identifier = identifier.as_bytes().escape_ascii();
+		let kind = get_kind_name(r#type);
 
-		write!(
-			out,
-			"[\"{namespace}\"][\"{identifier}\"], '`{namespace}.{identifier}` should be present')"
-		)
+		write!(out, ", \"{namespace}\", \"{identifier}\", \"{kind}\")")
 	}
 }
 
@@ -237,6 +271,20 @@ impl Print for f64 {
 	}
 }
 
+impl Print for u128 {
+	fn print(&self, _printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let [source_1, source_2, source_3, source_4] =
+			[0, 32, 64, 96].map(|shift| u32::try_from((self >> shift) & 0xFFFF_FFFF).unwrap());
+
+		let intrinsic = self.needs_name();
+
+		write!(
+			out,
+			"rt_{intrinsic}(0x{source_1:08X}, 0x{source_2:08X}, 0x{source_3:08X}, 0x{source_4:08X})"
+		)
+	}
+}
+
 impl Print for Call {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
 		let Self {
@@ -266,6 +314,38 @@ impl Print for RefIsNull {
 	}
 }
 
+impl Print for FunctionNew {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { source, signature } = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		source.print(printer, out)?;
+
+		let signature = signature.as_bytes().escape_ascii();
+
+		write!(out, ", \"{signature}\")")
+	}
+}
+
+impl Print for FunctionCheck {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { source, signature } = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		source.print(printer, out)?;
+
+		let signature = signature.as_bytes().escape_ascii();
+
+		write!(out, ", \"{signature}\")")
+	}
+}
+
 impl Print for IntegerUnaryOperation {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
 		let Self {
@@ -599,6 +679,168 @@ impl Print for NumberTransmuteToInteger {
 	}
 }
 
+impl Print for V128UnaryOperation {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		source.print(printer, out)?;
+
+		write!(out, ")")
+	}
+}
+
+impl Print for V128BinaryOperation {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self {
+			lhs,
+			rhs,
+			operator: _,
+		} = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		lhs.print(printer, out)?;
+
+		write!(out, ", ")?;
+
+		rhs.print(printer, out)?;
+
+		write!(out, ")")
+	}
+}
+
+impl Print for V128ReduceOperation {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		source.print(printer, out)?;
+
+		write!(out, ")")
+	}
+}
+
+impl Print for V128Splat {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { source, r#type: _ } = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		source.print(printer, out)?;
+
+		write!(out, ")")
+	}
+}
+
+impl Print for V128ExtractLane {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self {
+			source,
+			r#type: _,
+			lane,
+			signed: _,
+		} = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		source.print(printer, out)?;
+
+		write!(out, ", {lane})")
+	}
+}
+
+impl Print for V128ReplaceLane {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self {
+			lhs,
+			rhs,
+			r#type: _,
+			lane,
+		} = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		lhs.print(printer, out)?;
+
+		write!(out, ", ")?;
+
+		rhs.print(printer, out)?;
+
+		write!(out, ", {lane})")
+	}
+}
+
+impl Print for V128Shuffle {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { lhs, rhs, lanes } = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		lhs.print(printer, out)?;
+
+		write!(out, ", ")?;
+
+		rhs.print(printer, out)?;
+
+		write!(out, ", \"")?;
+
+		for lane in lanes {
+			write!(out, "\\{lane}")?;
+		}
+
+		write!(out, "\")")
+	}
+}
+
+impl Print for V128BitSelect {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self {
+			lhs,
+			rhs,
+			condition,
+		} = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		lhs.print(printer, out)?;
+
+		write!(out, ", ")?;
+
+		rhs.print(printer, out)?;
+
+		write!(out, ", ")?;
+
+		condition.print(printer, out)?;
+
+		write!(out, ")")
+	}
+}
+
 impl Print for Location {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
 		let Self { reference, offset } = self;
@@ -787,6 +1029,52 @@ impl Print for DataNew {
 	}
 }
 
+impl Print for ExceptionNew {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { tag, arguments } = self;
+
+		let intrinsic = self.needs_name();
+
+		write!(out, "rt_{intrinsic}(")?;
+
+		tag.print(printer, out)?;
+
+		if !arguments.is_empty() {
+			write!(out, ", ")?;
+
+			fmt_delimited(arguments, printer, out)?;
+		}
+
+		write!(out, ")")
+	}
+}
+
+impl Print for ExceptionTest {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { source, tag } = self;
+
+		write!(out, "(if ")?;
+
+		source.print(printer, out)?;
+
+		write!(out, ".tag == ")?;
+
+		tag.print(printer, out)?;
+
+		write!(out, " then 1 else 0)")
+	}
+}
+
+impl Print for ExceptionGet {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { source, index } = self;
+
+		source.print(printer, out)?;
+
+		write!(out, "[{}]", index + 1)
+	}
+}
+
 impl Print for Expression {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
 		match self {
@@ -801,8 +1089,11 @@ impl Print for Expression {
 			Self::I64(i64) => i64.print(printer, out),
 			Self::F32(f32) => f32.print(printer, out),
 			Self::F64(f64) => f64.print(printer, out),
+			Self::V128(v128) => v128.print(printer, out),
 			Self::Call(call) => call.print(printer, out),
 			Self::RefIsNull(ref_is_null) => ref_is_null.print(printer, out),
+			Self::FunctionNew(function_new) => function_new.print(printer, out),
+			Self::FunctionCheck(function_check) => function_check.print(printer, out),
 			Self::IntegerUnaryOperation(integer_unary_operation) => {
 				integer_unary_operation.print(printer, out)
 			}
@@ -838,6 +1129,20 @@ impl Print for Expression {
 			Self::NumberTransmuteToInteger(number_transmute_to_integer) => {
 				number_transmute_to_integer.print(printer, out)
 			}
+			Self::V128UnaryOperation(v128_unary_operation) => {
+				v128_unary_operation.print(printer, out)
+			}
+			Self::V128BinaryOperation(v128_binary_operation) => {
+				v128_binary_operation.print(printer, out)
+			}
+			Self::V128ReduceOperation(v128_reduce_operation) => {
+				v128_reduce_operation.print(printer, out)
+			}
+			Self::V128Splat(v128_splat) => v128_splat.print(printer, out),
+			Self::V128ExtractLane(v128_extract_lane) => v128_extract_lane.print(printer, out),
+			Self::V128ReplaceLane(v128_replace_lane) => v128_replace_lane.print(printer, out),
+			Self::V128Shuffle(v128_shuffle) => v128_shuffle.print(printer, out),
+			Self::V128BitSelect(v128_bit_select) => v128_bit_select.print(printer, out),
 			Self::GlobalNew(global_new) => global_new.print(printer, out),
 			Self::GlobalGet(global_get) => global_get.print(printer, out),
 			Self::TableNew(table_new) => table_new.print(printer, out),
@@ -850,6 +1155,10 @@ impl Print for Expression {
 			Self::MemorySize(memory_size) => memory_size.print(printer, out),
 			Self::MemoryGrow(memory_grow) => memory_grow.print(printer, out),
 			Self::DataNew(data_new) => data_new.print(printer, out),
+			Self::TagNew => write!(out, "{{}}"),
+			Self::ExceptionNew(exception_new) => exception_new.print(printer, out),
+			Self::ExceptionTest(exception_test) => exception_test.print(printer, out),
+			Self::ExceptionGet(exception_get) => exception_get.print(printer, out),
 		}
 	}
 }
@@ -0,0 +1,160 @@
+use std::{
+	collections::BTreeMap,
+	io::{Result, Write},
+	ops::ControlFlow,
+	sync::Arc,
+};
+
+use luau_tree::{
+	LuauTree,
+	expression::Expression,
+	statement::{ExportType, Statement},
+	visitor::Visitor,
+};
+
+use crate::{LuauPrinter, print::Print};
+
+type Imports = BTreeMap<Arc<str>, BTreeMap<Arc<str>, ExportType>>;
+
+// Imports grouped by namespace, where the same one may be read more than
+// once if several linked instances share it.
+struct ImportFinder<'imports> {
+	imports: &'imports mut Imports,
+}
+
+impl<'imports> ImportFinder<'imports> {
+	const fn new(imports: &'imports mut Imports) -> Self {
+		Self { imports }
+	}
+
+	fn run(&mut self, tree: &LuauTree) {
+		tree.accept(self)
+			.continue_value()
+			.expect("import finder must not fail");
+	}
+}
+
+impl Visitor for ImportFinder<'_> {
+	type Output = ();
+
+	fn visit_expression(&mut self, expression: &Expression) -> ControlFlow<Self::Output> {
+		if let Expression::Import(import) = expression {
+			let r#type = self
+				.imports
+				.entry(import.namespace.clone())
+				.or_default()
+				.entry(import.identifier.clone())
+				.or_insert(ExportType::Unknown);
+
+			if matches!(r#type, ExportType::Unknown) {
+				*r#type = import.r#type.clone();
+			}
+		}
+
+		ControlFlow::Continue(())
+	}
+
+	fn visit_statement(&mut self, _statement: &Statement) -> ControlFlow<Self::Output> {
+		ControlFlow::Continue(())
+	}
+}
+
+impl LuauPrinter {
+	fn fmt_field_open(&mut self, name: &str, out: &mut dyn Write) -> Result<()> {
+		self.tab(out)?;
+		write!(out, "[\"{}\"]: ", name.as_bytes().escape_ascii())
+	}
+
+	fn fmt_group_open(&mut self, name: &str, out: &mut dyn Write) -> Result<()> {
+		self.fmt_field_open(name, out)?;
+
+		writeln!(out, "{{")?;
+
+		self.indent();
+
+		Ok(())
+	}
+
+	fn fmt_group_close(&mut self, out: &mut dyn Write) -> Result<()> {
+		self.outdent();
+		self.tab(out)?;
+
+		writeln!(out, "}},")
+	}
+
+	fn fmt_field(&mut self, name: &str, r#type: &ExportType, out: &mut dyn Write) -> Result<()> {
+		self.fmt_field_open(name, out)?;
+
+		r#type.print(self, out)?;
+
+		writeln!(out, ",")
+	}
+
+	fn fmt_environment_type(&mut self, tree: &LuauTree, out: &mut dyn Write) -> Result<()> {
+		let mut imports = Imports::new();
+
+		ImportFinder::new(&mut imports).run(tree);
+
+		writeln!(out, "export type Environment = {{")?;
+
+		self.indent();
+
+		for (namespace, items) in &imports {
+			self.fmt_group_open(namespace, out)?;
+
+			for (identifier, r#type) in items {
+				self.fmt_field(identifier, r#type, out)?;
+			}
+
+			self.fmt_group_close(out)?;
+		}
+
+		self.outdent();
+
+		writeln!(out, "}}")
+	}
+
+	// Exports of linked instances are grouped in the same way as they
+	// are returned by the module function.
+	fn fmt_exports_type(&mut self, tree: &LuauTree, out: &mut dyn Write) -> Result<()> {
+		writeln!(out, "export type Exports = {{")?;
+
+		self.indent();
+
+		for group in tree
+			.exports
+			.chunk_by(|lhs, rhs| lhs.instance == rhs.instance)
+		{
+			if let Some(instance) = &group[0].instance {
+				self.fmt_group_open(instance, out)?;
+			}
+
+			for export in group {
+				self.fmt_field(&export.identifier, &export.r#type, out)?;
+			}
+
+			if group[0].instance.is_some() {
+				self.fmt_group_close(out)?;
+			}
+		}
+
+		self.outdent();
+
+		writeln!(out, "}}")
+	}
+
+	/// Prints the types of the environment that the `tree` needs and of
+	/// the exports that it returns. They name the types of the runtime,
+	/// which must be defined before them.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors that the `out` produces during the process.
+	pub fn print_interface(&mut self, tree: &LuauTree, out: &mut dyn Write) -> Result<()> {
+		self.fmt_environment_type(tree, out)?;
+
+		writeln!(out)?;
+
+		self.fmt_exports_type(tree, out)
+	}
+}
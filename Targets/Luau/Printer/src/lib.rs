@@ -1,22 +1,35 @@
 mod expression;
+mod interface;
+mod line_counter;
 mod print;
+mod source_map;
+mod split;
 mod statement;
+mod types;
 
 pub mod library;
 
 use std::{
+	cell::Cell,
 	io::{Result, Write},
+	rc::Rc,
 	sync::Arc,
 };
 
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use luau_tree::{LuauTree, expression::Name};
 
-use self::print::Print;
+use self::{line_counter::LineCounter, print::Print};
+
+pub use self::{source_map::SourceMap, split::Chunk};
 
 pub struct LuauPrinter {
 	names: HashMap<Name, Arc<str>>,
+	reassigned: HashSet<Name>,
+	positions: Vec<(usize, u32)>,
+	line: Rc<Cell<usize>>,
 	depth: u16,
+	typed: bool,
 }
 
 impl LuauPrinter {
@@ -24,7 +37,11 @@ impl LuauPrinter {
 	pub fn new() -> Self {
 		Self {
 			names: HashMap::new(),
+			reassigned: HashSet::new(),
+			positions: Vec::new(),
+			line: Rc::new(Cell::new(1)),
 			depth: 0,
+			typed: false,
 		}
 	}
 
@@ -36,6 +53,52 @@ impl LuauPrinter {
 		self.names.get(&name).map(Arc::as_ref)
 	}
 
+	// Names are printed with a `_{id}_` suffix, so the result is already
+	// unique and can never collide with a keyword once it is a valid identifier.
+	fn sanitize_name(identifier: &str) -> Option<Arc<str>> {
+		let mut result = String::with_capacity(identifier.len());
+
+		if identifier.starts_with(|character: char| character.is_ascii_digit()) {
+			result.push('_');
+		}
+
+		for character in identifier.chars() {
+			if character.is_ascii_alphanumeric() {
+				result.push(character);
+			} else if !result.ends_with('_') {
+				result.push('_');
+			}
+		}
+
+		let result = result.trim_end_matches('_');
+
+		(!result.is_empty()).then(|| result.into())
+	}
+
+	/// Sets the prefix used when printing `name`, replacing any characters
+	/// that are not valid in a Luau identifier.
+	pub fn set_name(&mut self, name: Name, identifier: &str) {
+		if let Some(identifier) = Self::sanitize_name(identifier) {
+			self.names.insert(name, identifier);
+		}
+	}
+
+	/// Sets the line number that printing starts at, for when other
+	/// text has been written before the tree.
+	pub fn set_line(&mut self, line: usize) {
+		self.line.set(line);
+	}
+
+	pub(crate) fn add_position(&mut self, offset: u32) {
+		self.positions.push((self.line.get(), offset));
+	}
+
+	/// Returns the line and code offset pairs recorded while printing.
+	#[must_use]
+	pub fn positions(&self) -> &[(usize, u32)] {
+		&self.positions
+	}
+
 	pub const fn indent(&mut self) {
 		self.depth = self.depth.wrapping_add(1);
 	}
@@ -48,7 +111,9 @@ impl LuauPrinter {
 	///
 	/// Returns any IO errors that the `out` produces during the process.
 	pub fn print(&mut self, tree: &LuauTree, out: &mut dyn Write) -> Result<()> {
-		tree.print(self, out)
+		let mut out = LineCounter::new(out, Rc::clone(&self.line));
+
+		tree.print(self, &mut out)
 	}
 }
 
@@ -1,9 +1,6 @@
-mod names_finder;
 mod printer;
 mod sections;
 
-pub use self::{
-	names_finder::{NamesFinder, NeedsName},
-	printer::Printer as LibraryPrinter,
-	sections::Sections as LibrarySections,
-};
+pub use luau_tree::names_finder::{NamesFinder, NeedsName};
+
+pub use self::{printer::Printer as LibraryPrinter, sections::Sections as LibrarySections};
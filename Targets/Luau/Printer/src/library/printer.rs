@@ -4,6 +4,12 @@ use hashbrown::HashSet;
 
 use super::sections::{Section, Sections};
 
+// Luau allows at most 200 locals in a function, which the full runtime
+// would exceed, so a standalone runtime is split into blocks of fewer.
+const BLOCK_LOCALS: usize = 150;
+
+const VERSION_MESSAGE: &str = "runtime should come from the same compiler as the module";
+
 pub struct Printer {
 	references: Vec<&'static str>,
 	expanded: HashSet<&'static str>,
@@ -39,6 +45,13 @@ impl Printer {
 		}
 	}
 
+	/// Resolves every section instead of only those that are needed.
+	pub fn resolve_all(&mut self, sections: &Sections) {
+		let names: Vec<_> = sections.iter().map(|section| section.name).collect();
+
+		self.resolve(&names, sections);
+	}
+
 	pub fn print(&self, sections: &Sections, out: &mut dyn Write) -> Result<()> {
 		self.references.iter().try_for_each(|&name| {
 			let Section { contents, .. } = sections.find(name);
@@ -46,6 +59,135 @@ impl Printer {
 			writeln!(out, "{contents}\n")
 		})
 	}
+
+	// Locals of earlier blocks are read back from the `RUNTIME` table.
+	fn find_block_imports(block: &[&'static str], sections: &Sections) -> Vec<&'static str> {
+		let mut imports = Vec::new();
+
+		for &name in block {
+			for &reference in &sections.find(name).references {
+				let Some(local) = sections.find(reference).local else {
+					continue;
+				};
+
+				if !block.contains(&reference) && !imports.contains(&local) {
+					imports.push(local);
+				}
+			}
+		}
+
+		imports
+	}
+
+	fn print_block(block: &[&'static str], sections: &Sections, out: &mut dyn Write) -> Result<()> {
+		writeln!(out, "do")?;
+
+		for local in Self::find_block_imports(block, sections) {
+			writeln!(out, "local {local} = RUNTIME.{local}")?;
+		}
+
+		writeln!(out)?;
+
+		for &name in block {
+			writeln!(out, "{}\n", sections.find(name).contents)?;
+		}
+
+		for local in block.iter().filter_map(|&name| sections.find(name).local) {
+			writeln!(out, "RUNTIME.{local} = {local}")?;
+		}
+
+		writeln!(out, "end\n")
+	}
+
+	/// Prints the resolved sections as a module of their own, which returns
+	/// a table of every `rt_` function along with the `version` of the
+	/// sections it was printed from.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors that the `out` produces during the process.
+	pub fn print_module(&self, sections: &Sections, out: &mut dyn Write) -> Result<()> {
+		let (locals, types): (Vec<_>, Vec<_>) = self
+			.references
+			.iter()
+			.copied()
+			.partition(|&name| sections.find(name).local.is_some());
+
+		writeln!(out, "local RUNTIME: {{ [string]: any }} = {{}}\n")?;
+
+		for name in types {
+			writeln!(out, "{}\n", sections.find(name).contents)?;
+		}
+
+		let mut block = Vec::new();
+
+		for name in locals {
+			block.push(name);
+
+			if block.len() + Self::find_block_imports(&block, sections).len() > BLOCK_LOCALS {
+				block.pop();
+
+				Self::print_block(&block, sections, out)?;
+
+				block.clear();
+				block.push(name);
+			}
+		}
+
+		Self::print_block(&block, sections, out)?;
+
+		writeln!(out, "return {{")?;
+		writeln!(out, "\tversion = \"{}\",", sections.version())?;
+
+		for &name in &self.references {
+			if sections.find(name).is_intrinsic() {
+				writeln!(out, "\trt_{name} = RUNTIME.rt_{name},")?;
+			}
+		}
+
+		writeln!(out, "}}")
+	}
+
+	/// Prints what goes before a module that reads the runtime from the
+	/// Luau expression in `source`, such as a `require` call, instead of
+	/// containing it. Every `rt_` function in `names` is bound to a local
+	/// and any other sections are resolved and printed as usual.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors that the `out` produces during the process.
+	pub fn print_bindings(
+		&mut self,
+		names: &[&'static str],
+		source: &str,
+		sections: &Sections,
+		out: &mut dyn Write,
+	) -> Result<()> {
+		let (mut intrinsics, others): (Vec<_>, Vec<_>) = names
+			.iter()
+			.copied()
+			.partition(|&name| sections.find(name).is_intrinsic());
+
+		intrinsics.sort_unstable();
+		intrinsics.dedup();
+
+		let version = sections.version();
+
+		writeln!(out, "local RUNTIME = {source}\n")?;
+		writeln!(
+			out,
+			"assert(RUNTIME.version == \"{version}\", \"{VERSION_MESSAGE}\")\n"
+		)?;
+
+		for name in intrinsics {
+			writeln!(out, "local rt_{name} = RUNTIME.rt_{name}")?;
+		}
+
+		writeln!(out)?;
+
+		self.resolve(&others, sections);
+		self.print(sections, out)
+	}
 }
 
 impl Default for Printer {
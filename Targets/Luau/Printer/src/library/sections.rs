@@ -2,6 +2,9 @@ pub struct Section {
 	pub references: Box<[&'static str]>,
 	pub name: &'static str,
 	pub contents: &'static str,
+
+	/// The local defined by the section, if it is not only a type.
+	pub local: Option<&'static str>,
 }
 
 impl Section {
@@ -42,6 +45,28 @@ impl Section {
 		(content.trim(), source)
 	}
 
+	fn find_local(contents: &'static str) -> Option<&'static str> {
+		let line = contents
+			.lines()
+			.find_map(|line| line.strip_prefix("local "))?;
+
+		let line = line.strip_prefix("function ").unwrap_or(line);
+		let end = line
+			.find(|character: char| !character.is_ascii_alphanumeric() && character != '_')
+			.unwrap_or(line.len());
+
+		Some(&line[..end])
+	}
+
+	/// Returns whether the section defines a `rt_` function of the same
+	/// name, which generated code may call.
+	#[must_use]
+	pub fn is_intrinsic(&self) -> bool {
+		self.local
+			.and_then(|local| local.strip_prefix("rt_"))
+			.is_some_and(|local| local == self.name)
+	}
+
 	pub fn try_parse(source: &'static str) -> Option<(Self, &'static str)> {
 		let (name, source) = Self::try_parse_header(source, Self::SECTION_HEADER)?;
 		let (references, source) = Self::parse_references(source);
@@ -57,6 +82,7 @@ impl Section {
 				references,
 				name,
 				contents,
+				local: Self::find_local(contents),
 			},
 			source,
 		))
@@ -75,6 +101,8 @@ impl Sections {
 	pub const F64_SOURCE: &str = include_str!("../../runtime/f64.luau");
 	pub const TABLE_SOURCE: &str = include_str!("../../runtime/table.luau");
 	pub const MEMORY_SOURCE: &str = include_str!("../../runtime/memory.luau");
+	pub const EXCEPTION_SOURCE: &str = include_str!("../../runtime/exception.luau");
+	pub const V128_SOURCE: &str = include_str!("../../runtime/v128.luau");
 
 	#[must_use]
 	pub fn with_built_ins() -> Self {
@@ -87,6 +115,8 @@ impl Sections {
 		sections.parse_from(Self::F64_SOURCE);
 		sections.parse_from(Self::TABLE_SOURCE);
 		sections.parse_from(Self::MEMORY_SOURCE);
+		sections.parse_from(Self::EXCEPTION_SOURCE);
+		sections.parse_from(Self::V128_SOURCE);
 		sections.resolve();
 
 		sections
@@ -113,6 +143,29 @@ impl Sections {
 		}
 	}
 
+	pub fn iter(&self) -> core::slice::Iter<'_, Section> {
+		self.list.iter()
+	}
+
+	/// Returns an identifier for the compiler and the contents of every
+	/// section, which a shared runtime and its modules must agree on.
+	#[must_use]
+	pub fn version(&self) -> String {
+		const FNV_OFFSET: u64 = 0xCBF2_9CE4_8422_2325;
+		const FNV_PRIME: u64 = 0x0100_0000_01B3;
+
+		let hash = self
+			.list
+			.iter()
+			.flat_map(|section| [section.name, section.contents])
+			.flat_map(str::bytes)
+			.fold(FNV_OFFSET, |hash, byte| {
+				(hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+			});
+
+		format!("{}-{hash:016x}", env!("CARGO_PKG_VERSION"))
+	}
+
 	pub fn find(&self, name: &'static str) -> &Section {
 		let position = self
 			.list
@@ -0,0 +1,33 @@
+use std::{
+	cell::Cell,
+	io::{Result, Write},
+	rc::Rc,
+};
+
+// Counts the lines written so far, so that positions can be
+// recorded while printing through a `dyn Write`.
+pub struct LineCounter<'out> {
+	out: &'out mut dyn Write,
+	line: Rc<Cell<usize>>,
+}
+
+impl<'out> LineCounter<'out> {
+	pub const fn new(out: &'out mut dyn Write, line: Rc<Cell<usize>>) -> Self {
+		Self { out, line }
+	}
+}
+
+impl Write for LineCounter<'_> {
+	fn write(&mut self, buf: &[u8]) -> Result<usize> {
+		let len = self.out.write(buf)?;
+		let lines = buf[..len].iter().filter(|&&byte| byte == b'\n').count();
+
+		self.line.set(self.line.get() + lines);
+
+		Ok(len)
+	}
+
+	fn flush(&mut self) -> Result<()> {
+		self.out.flush()
+	}
+}
@@ -0,0 +1,93 @@
+use std::{
+	io::{self, Write},
+	ops::Range,
+};
+
+use wasmparser::{Parser, Payload, TypeRef};
+
+/// Relates printed lines back to the function and code section
+/// offset that they were generated from.
+pub struct SourceMap {
+	functions: Vec<Range<usize>>,
+	imports: usize,
+	code: usize,
+}
+
+impl SourceMap {
+	/// # Panics
+	///
+	/// Panics if `data` is not a valid WebAssembly binary.
+	#[must_use]
+	pub fn new(data: &[u8]) -> Self {
+		let mut functions = Vec::new();
+		let mut imports = 0;
+		let mut code = 0;
+
+		for payload in Parser::new(0).parse_all(data).map(Result::unwrap) {
+			match payload {
+				Payload::ImportSection(section) => {
+					imports = section
+						.into_iter()
+						.map(Result::unwrap)
+						.filter(|import| matches!(import.ty, TypeRef::Func(_)))
+						.count();
+				}
+				Payload::CodeSectionStart { range, .. } => code = range.start,
+				Payload::CodeSectionEntry(function_body) => functions.push(function_body.range()),
+
+				_ => {}
+			}
+		}
+
+		Self {
+			functions,
+			imports,
+			code,
+		}
+	}
+
+	fn find_function(&self, offset: usize) -> Option<usize> {
+		let index = self.functions.partition_point(|range| range.end <= offset);
+
+		self.functions
+			.get(index)
+			.filter(|range| range.contains(&offset))
+			.map(|_| self.imports + index)
+	}
+
+	/// Writes the `positions` as a JSON document, skipping any that
+	/// do not belong to a function body.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors that the `out` produces during the process.
+	pub fn write(&self, positions: &[(usize, u32)], out: &mut dyn Write) -> io::Result<()> {
+		let mut mappings = positions.iter().filter_map(|&(line, offset)| {
+			let offset = usize::try_from(offset).unwrap();
+
+			self.find_function(offset)
+				.map(|function| (line, function, offset - self.code))
+		});
+
+		writeln!(out, "{{")?;
+		writeln!(out, "\t\"version\": 1,")?;
+		write!(out, "\t\"mappings\": [")?;
+
+		if let Some((line, function, offset)) = mappings.next() {
+			write!(
+				out,
+				"\n\t\t{{ \"line\": {line}, \"function\": {function}, \"offset\": {offset} }}"
+			)?;
+		}
+
+		for (line, function, offset) in mappings {
+			write!(
+				out,
+				",\n\t\t{{ \"line\": {line}, \"function\": {function}, \"offset\": {offset} }}"
+			)?;
+		}
+
+		writeln!(out, "\n\t]")?;
+		writeln!(out, "}}")
+	}
+}
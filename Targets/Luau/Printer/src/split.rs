@@ -0,0 +1,343 @@
+use std::{
+	collections::BTreeSet,
+	io::{Result, Write},
+	ops::{ControlFlow, Range},
+};
+
+use luau_tree::{
+	LuauTree,
+	expression::{Expression, Local, Name},
+	statement::{AssignAll, Export, Statement},
+	visitor::Visitor,
+};
+
+use crate::{LuauPrinter, print::Print, statement::fmt_export_list};
+
+// Every name of the module function that a statement reads or writes,
+// including those within the bodies of nested blocks and functions.
+#[derive(Default)]
+struct Usage {
+	read: BTreeSet<Name>,
+	written: BTreeSet<Name>,
+}
+
+impl Usage {
+	fn add_read(&mut self, local: Local) {
+		match local {
+			Local::Fast { name } | Local::Slow { table: name, .. } => {
+				self.read.insert(name);
+			}
+		}
+	}
+
+	fn add_write(&mut self, local: Local) {
+		match local {
+			Local::Fast { name } => {
+				self.written.insert(name);
+			}
+			Local::Slow { table, .. } => {
+				self.read.insert(table);
+			}
+		}
+	}
+
+	fn add_assign_all(&mut self, assign_all: &AssignAll) {
+		for &(destination, source) in &assign_all.assignments {
+			self.add_write(destination);
+			self.add_read(source);
+		}
+	}
+
+	fn run(&mut self, statements: &[Statement]) {
+		statements
+			.iter()
+			.try_for_each(|statement| statement.accept(self))
+			.continue_value()
+			.expect("usage finder must not fail");
+	}
+
+	fn run_exports(&mut self, exports: &[Export]) {
+		exports
+			.iter()
+			.try_for_each(|export| export.accept(self))
+			.continue_value()
+			.expect("usage finder must not fail");
+	}
+
+	fn contains(&self, name: Name) -> bool {
+		self.read.contains(&name) || self.written.contains(&name)
+	}
+}
+
+impl Visitor for Usage {
+	type Output = ();
+
+	fn visit_expression(&mut self, expression: &Expression) -> ControlFlow<Self::Output> {
+		if let Expression::Local(local) = *expression {
+			self.add_read(local);
+		}
+
+		ControlFlow::Continue(())
+	}
+
+	fn visit_statement(&mut self, statement: &Statement) -> ControlFlow<Self::Output> {
+		match statement {
+			Statement::FastDefine(fast_define) => {
+				self.written.insert(fast_define.name);
+			}
+			Statement::SlowDefine(slow_define) => {
+				self.written.insert(slow_define.name);
+			}
+			Statement::Assign(assign) => self.add_write(assign.local),
+			Statement::AssignAll(assign_all) => self.add_assign_all(assign_all),
+			Statement::Repeat(repeat) => self.add_assign_all(&repeat.post),
+			Statement::Call(call) => {
+				call.results
+					.iter()
+					.for_each(|&result| self.add_write(result));
+			}
+			_ => {}
+		}
+
+		ControlFlow::Continue(())
+	}
+}
+
+/// A range of the statements at the top of a module which is printed as
+/// a file of its own. The locals it shares with other chunks are passed
+/// through a state table.
+pub struct Chunk {
+	pub range: Range<usize>,
+
+	loads: Vec<Name>,
+	stores: Vec<Name>,
+}
+
+impl LuauPrinter {
+	fn measure(&self, statement: &Statement) -> usize {
+		let mut printer = Self {
+			names: self.names.clone(),
+			..Self::new()
+		};
+
+		let mut out = Vec::new();
+
+		statement
+			.print(&mut printer, &mut out)
+			.expect("statement should print");
+
+		out.len()
+	}
+
+	// Statements are added to a chunk in order until it would grow past
+	// the `size`, so a single statement may still be larger on its own.
+	fn find_ranges(&self, statements: &[Statement], size: usize) -> Vec<Range<usize>> {
+		let mut ranges = Vec::new();
+		let mut start = 0;
+		let mut total = 0;
+
+		for (index, statement) in statements.iter().enumerate() {
+			let len = self.measure(statement);
+
+			if total != 0 && total + len > size {
+				ranges.push(start..index);
+
+				start = index;
+				total = 0;
+			}
+
+			total += len;
+		}
+
+		if start != statements.len() {
+			ranges.push(start..statements.len());
+		}
+
+		ranges
+	}
+
+	fn find_defined(statements: &[Statement]) -> impl Iterator<Item = Name> {
+		statements.iter().filter_map(|statement| match statement {
+			Statement::FastDefine(fast_define) => Some(fast_define.name),
+			Statement::SlowDefine(slow_define) => Some(slow_define.name),
+			_ => None,
+		})
+	}
+
+	// Names defined at the top of the module are the only ones that can
+	// be used by more than one chunk, as every statement is kept whole.
+	fn find_shared(tree: &LuauTree) -> BTreeSet<Name> {
+		Self::find_defined(&tree.code.list)
+			.chain([tree.environment])
+			.collect()
+	}
+
+	/// Splits the statements at the top of the `tree` into chunks whose
+	/// printed sources are at most `size` bytes where possible. The result
+	/// only depends on the `tree` and the `size`.
+	#[must_use]
+	pub fn split(&self, tree: &LuauTree, size: usize) -> Vec<Chunk> {
+		let statements = tree.code.list.as_slice();
+		let shared = Self::find_shared(tree);
+		let ranges = self.find_ranges(statements, size);
+
+		let usages: Vec<_> = ranges
+			.iter()
+			.map(|range| {
+				let mut usage = Usage::default();
+
+				usage.run(&statements[range.clone()]);
+				usage
+			})
+			.collect();
+
+		let mut later = Usage::default();
+
+		later.run_exports(&tree.exports);
+
+		let mut chunks: Vec<_> = ranges
+			.into_iter()
+			.zip(&usages)
+			.rev()
+			.map(|(range, usage)| {
+				let defined: BTreeSet<_> = Self::find_defined(&statements[range.clone()]).collect();
+
+				let loads = shared
+					.iter()
+					.copied()
+					.filter(|&name| usage.contains(name) && !defined.contains(&name))
+					.collect();
+
+				let stores = usage
+					.written
+					.iter()
+					.copied()
+					.filter(|&name| shared.contains(&name) && later.contains(name))
+					.collect();
+
+				later.read.extend(&usage.read);
+				later.written.extend(&usage.written);
+
+				Chunk {
+					range,
+					loads,
+					stores,
+				}
+			})
+			.collect();
+
+		chunks.reverse();
+		chunks
+	}
+
+	/// Prints the statements of the `chunk` as a function taking the state
+	/// table shared by every chunk of the `tree`.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors that the `out` produces during the process.
+	pub fn print_chunk(
+		&mut self,
+		tree: &LuauTree,
+		chunk: &Chunk,
+		out: &mut dyn Write,
+	) -> Result<()> {
+		let Chunk {
+			range,
+			loads,
+			stores,
+		} = chunk;
+
+		writeln!(out, "return function(STATE)")?;
+
+		self.indent();
+
+		for name in loads {
+			self.tab(out)?;
+			write!(out, "local ")?;
+			name.print(self, out)?;
+			write!(out, " = STATE.")?;
+			name.print(self, out)?;
+			writeln!(out, ";")?;
+		}
+
+		for statement in &tree.code.list[range.clone()] {
+			statement.print(self, out)?;
+		}
+
+		for name in stores {
+			self.tab(out)?;
+			write!(out, "STATE.")?;
+			name.print(self, out)?;
+			write!(out, " = ")?;
+			name.print(self, out)?;
+			writeln!(out, ";")?;
+		}
+
+		self.outdent();
+
+		writeln!(out, "end")
+	}
+
+	/// Prints the module function of a split `tree`, which runs every chunk
+	/// in order and then returns the exports. Each of the `sources` is a
+	/// Luau prefix expression, such as a `require` call, that results in the
+	/// function printed for the chunk of the same index.
+	///
+	/// # Errors
+	///
+	/// Returns any IO errors that the `out` produces during the process.
+	pub fn print_entry(
+		&mut self,
+		tree: &LuauTree,
+		sources: &[String],
+		out: &mut dyn Write,
+	) -> Result<()> {
+		let shared = Self::find_shared(tree);
+		let mut usage = Usage::default();
+
+		usage.run_exports(&tree.exports);
+
+		write!(out, "local function module(")?;
+		tree.environment.print(self, out)?;
+
+		if self.typed {
+			write!(out, ": any")?;
+		}
+
+		writeln!(out, ")")?;
+
+		self.indent();
+
+		self.tab(out)?;
+		write!(out, "local STATE = {{ ")?;
+		tree.environment.print(self, out)?;
+		write!(out, " = ")?;
+		tree.environment.print(self, out)?;
+		writeln!(out, " }};")?;
+
+		for source in sources {
+			self.tab(out)?;
+			writeln!(out, "{source}(STATE);")?;
+		}
+
+		// The environment is already a parameter of the module function.
+		for &name in shared
+			.iter()
+			.filter(|&&name| name != tree.environment && usage.contains(name))
+		{
+			self.tab(out)?;
+			write!(out, "local ")?;
+			name.print(self, out)?;
+			write!(out, " = STATE.")?;
+			name.print(self, out)?;
+			writeln!(out, ";")?;
+		}
+
+		fmt_export_list(&tree.exports, self, out)?;
+
+		self.outdent();
+
+		writeln!(out, "end")
+	}
+}
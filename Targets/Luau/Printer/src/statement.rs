@@ -6,7 +6,7 @@ use luau_tree::{
 	statement::{
 		Assign, AssignAll, Call, DataDrop, ElementsDrop, Export, FastDefine, GlobalSet, Match,
 		MemoryCopy, MemoryFill, MemoryInit, MemoryStore, Repeat, Sequence, SlowDefine, Statement,
-		TableCopy, TableFill, TableInit, TableSet,
+		TableCopy, TableFill, TableInit, TableSet, Throw,
 	},
 };
 
@@ -133,6 +133,7 @@ impl Print for FastDefine {
 		write!(out, "local ")?;
 
 		name.print(printer, out)?;
+		printer.fmt_define_type(*name, source, out)?;
 
 		write!(out, " = ")?;
 
@@ -151,6 +152,10 @@ impl Print for SlowDefine {
 
 		name.print(printer, out)?;
 
+		if printer.typed {
+			write!(out, ": {{ any }}")?;
+		}
+
 		writeln!(out, " = table.create({len});")
 	}
 }
@@ -198,10 +203,54 @@ impl Print for Call {
 			function,
 			results,
 			arguments,
+			tail,
+			catch,
 		} = self;
 
 		printer.tab(out)?;
 
+		if *tail {
+			let intrinsic = self.needs_name();
+
+			write!(out, "do return rt_{intrinsic}(")?;
+
+			function.print(printer, out)?;
+
+			if !arguments.is_empty() {
+				write!(out, ", ")?;
+
+				fmt_delimited(arguments, printer, out)?;
+			}
+
+			return writeln!(out, ") end");
+		}
+
+		// The exception is returned first as the results are variadic.
+		if *catch {
+			let intrinsic = self.needs_name();
+			let (exception, results) = results.split_last().unwrap();
+
+			exception.print(printer, out)?;
+
+			for result in results {
+				write!(out, ", ")?;
+
+				result.print(printer, out)?;
+			}
+
+			write!(out, " = rt_{intrinsic}(")?;
+
+			function.print(printer, out)?;
+
+			if !arguments.is_empty() {
+				write!(out, ", ")?;
+
+				fmt_delimited(arguments, printer, out)?;
+			}
+
+			return writeln!(out, ")");
+		}
+
 		if !results.is_empty() {
 			fmt_delimited(results, printer, out)?;
 
@@ -473,6 +522,21 @@ impl Print for DataDrop {
 	}
 }
 
+impl Print for Throw {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { source } = self;
+
+		let intrinsic = self.needs_name();
+
+		printer.tab(out)?;
+		write!(out, "rt_{intrinsic}(")?;
+
+		source.print(printer, out)?;
+
+		writeln!(out, ")")
+	}
+}
+
 impl Print for Statement {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
 		match self {
@@ -494,29 +558,56 @@ impl Print for Statement {
 			Self::MemoryCopy(memory_copy) => memory_copy.print(printer, out),
 			Self::MemoryInit(memory_init) => memory_init.print(printer, out),
 			Self::DataDrop(data_drop) => data_drop.print(printer, out),
+			Self::Throw(throw) => throw.print(printer, out),
 		}
 	}
 }
 
 impl Print for Sequence {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
-		self.list
-			.iter()
-			.try_for_each(|statement| statement.print(printer, out))
+		let Self { list, positions } = self;
+		let mut positions = positions.iter().peekable();
+
+		for (index, statement) in list.iter().enumerate() {
+			if let Some(&(_, offset)) = positions.next_if(|&&(start, _)| start == index) {
+				printer.add_position(offset);
+			}
+
+			statement.print(printer, out)?;
+		}
+
+		Ok(())
 	}
 }
 
 impl Print for Export {
 	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
-		let Self { identifier, source } = self;
+		let Self {
+			instance: _,
+			identifier,
+			source,
+			r#type,
+		} = self;
 
 		write!(out, "[\"{}\"] = ", identifier.as_bytes().escape_ascii())?;
 
-		source.print(printer, out)
+		source.print(printer, out)?;
+		printer.fmt_export_type(r#type, out)
 	}
 }
 
-fn fmt_export_list(
+fn fmt_exports(exports: &[Export], printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+	exports.iter().try_for_each(|export| {
+		printer.tab(out)?;
+		export.print(printer, out)?;
+
+		writeln!(out, ",")
+	})
+}
+
+// Exports of linked modules are grouped into a table per instance,
+// which gives the result the same shape as an environment.
+pub fn fmt_export_list(
 	exports: &[Export],
 	printer: &mut LuauPrinter,
 	out: &mut dyn Write,
@@ -526,12 +617,23 @@ fn fmt_export_list(
 
 	printer.indent();
 
-	exports.iter().try_for_each(|export| {
+	for group in exports.chunk_by(|lhs, rhs| lhs.instance == rhs.instance) {
+		let Some(instance) = &group[0].instance else {
+			fmt_exports(group, printer, out)?;
+
+			continue;
+		};
+
 		printer.tab(out)?;
-		export.print(printer, out)?;
+		writeln!(out, "[\"{}\"] = {{", instance.as_bytes().escape_ascii())?;
 
-		writeln!(out, ",")
-	})?;
+		printer.indent();
+		fmt_exports(group, printer, out)?;
+		printer.outdent();
+
+		printer.tab(out)?;
+		writeln!(out, "}},")?;
+	}
 
 	printer.outdent();
 
@@ -545,11 +647,17 @@ impl Print for LuauTree {
 			environment,
 			code,
 			exports,
+			names: _,
 		} = self;
 
 		printer.tab(out)?;
 		write!(out, "local function module(")?;
 		environment.print(printer, out)?;
+
+		if printer.typed {
+			write!(out, ": any")?;
+		}
+
 		writeln!(out, ")")?;
 
 		printer.indent();
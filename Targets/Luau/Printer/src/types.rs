@@ -0,0 +1,227 @@
+use std::{
+	io::{Result, Write},
+	ops::ControlFlow,
+};
+
+use hashbrown::HashSet;
+use luau_tree::{
+	LuauTree,
+	expression::{Expression, Local, Name, Signature, ValueType},
+	statement::{AssignAll, ExportType, Statement},
+	visitor::Visitor,
+};
+
+use crate::{LuauPrinter, expression::fmt_delimited, print::Print};
+
+// Locals are reused for values of different types once they are free, so
+// only those that are never assigned after their definition can be typed.
+struct ReassignFinder<'names> {
+	names: &'names mut HashSet<Name>,
+}
+
+impl<'names> ReassignFinder<'names> {
+	const fn new(names: &'names mut HashSet<Name>) -> Self {
+		Self { names }
+	}
+
+	fn add_local(&mut self, local: Local) {
+		if let Local::Fast { name } = local {
+			self.names.insert(name);
+		}
+	}
+
+	fn add_assign_all(&mut self, assign_all: &AssignAll) {
+		for &(destination, _) in &assign_all.assignments {
+			self.add_local(destination);
+		}
+	}
+
+	fn run(&mut self, tree: &LuauTree) {
+		tree.accept(self)
+			.continue_value()
+			.expect("reassign finder must not fail");
+	}
+}
+
+impl Visitor for ReassignFinder<'_> {
+	type Output = ();
+
+	fn visit_expression(&mut self, _expression: &Expression) -> ControlFlow<Self::Output> {
+		ControlFlow::Continue(())
+	}
+
+	fn visit_statement(&mut self, statement: &Statement) -> ControlFlow<Self::Output> {
+		match statement {
+			Statement::Assign(assign) => self.add_local(assign.local),
+			Statement::AssignAll(assign_all) => self.add_assign_all(assign_all),
+			Statement::Repeat(repeat) => self.add_assign_all(&repeat.post),
+			Statement::Call(call) => {
+				call.results
+					.iter()
+					.for_each(|&result| self.add_local(result));
+			}
+			_ => {}
+		}
+
+		ControlFlow::Continue(())
+	}
+}
+
+impl Print for ValueType {
+	fn print(&self, _printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let name = match self {
+			Self::I32 | Self::I64 | Self::F64 => "number",
+			Self::F32 => "vector",
+			Self::V128 => "buffer",
+			Self::Reference => "any",
+		};
+
+		write!(out, "{name}")
+	}
+}
+
+fn fmt_value_types(
+	types: &[ValueType],
+	printer: &mut LuauPrinter,
+	out: &mut dyn Write,
+) -> Result<()> {
+	write!(out, "(")?;
+
+	fmt_delimited(types, printer, out)?;
+
+	write!(out, ")")
+}
+
+impl Print for Signature {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		let Self { arguments, results } = self;
+
+		fmt_value_types(arguments, printer, out)?;
+
+		write!(out, " -> ")?;
+
+		fmt_value_types(results, printer, out)
+	}
+}
+
+impl Print for ExportType {
+	fn print(&self, printer: &mut LuauPrinter, out: &mut dyn Write) -> Result<()> {
+		match self {
+			Self::Function(signature) => signature.print(printer, out),
+			Self::Global(Some(r#type)) => {
+				write!(out, "{{ ")?;
+
+				r#type.print(printer, out)?;
+
+				write!(out, " }}")
+			}
+			Self::Global(None) => write!(out, "{{ any }}"),
+			Self::Table => write!(out, "Table<any>"),
+			Self::Memory => write!(out, "Memory"),
+			Self::Unknown => write!(out, "any"),
+		}
+	}
+}
+
+impl LuauPrinter {
+	/// Prints type annotations from here on, leaving out the types of any
+	/// locals that the `tree` assigns to after their definition.
+	pub fn annotate(&mut self, tree: &LuauTree) {
+		self.typed = true;
+		self.reassigned.clear();
+
+		ReassignFinder::new(&mut self.reassigned).run(tree);
+	}
+
+	fn is_reassigned(&self, name: Name) -> bool {
+		self.reassigned.contains(&name)
+	}
+
+	// The value type of a global can only be known from constants.
+	fn fmt_global_type(initializer: &Expression, out: &mut dyn Write) -> Result<()> {
+		let name = match initializer {
+			Expression::I32(_) | Expression::I64(_) | Expression::F64(_) => "number",
+			Expression::F32(_) => "vector",
+			Expression::V128(_) => "buffer",
+			_ => "any",
+		};
+
+		write!(out, ": {{ {name} }}")
+	}
+
+	/// Writes the annotation of a local defined as `name = source`, if any
+	/// is needed. Module level globals, tables and memories are typed by
+	/// their runtime types and other locals are inferred by Luau.
+	pub(crate) fn fmt_define_type(
+		&self,
+		name: Name,
+		source: &Expression,
+		out: &mut dyn Write,
+	) -> Result<()> {
+		if !self.typed {
+			return Ok(());
+		}
+
+		if self.is_reassigned(name) {
+			return write!(out, ": any");
+		}
+
+		match source {
+			Expression::GlobalNew(global_new) => {
+				Self::fmt_global_type(&global_new.initializer, out)
+			}
+			Expression::TableNew(_) => write!(out, ": Table<any>"),
+			Expression::ElementsNew(_) => write!(out, ": Elements<any>"),
+			Expression::MemoryNew(_) => write!(out, ": Memory"),
+			Expression::DataNew(_) => write!(out, ": Data"),
+			_ => Ok(()),
+		}
+	}
+
+	pub(crate) fn fmt_argument_type(
+		&mut self,
+		name: Name,
+		r#type: ValueType,
+		out: &mut dyn Write,
+	) -> Result<()> {
+		if !self.typed {
+			return Ok(());
+		}
+
+		write!(out, ": ")?;
+
+		if self.is_reassigned(name) {
+			write!(out, "any")
+		} else {
+			r#type.print(self, out)
+		}
+	}
+
+	pub(crate) fn fmt_results_type(
+		&mut self,
+		results: &[ValueType],
+		out: &mut dyn Write,
+	) -> Result<()> {
+		if !self.typed {
+			return Ok(());
+		}
+
+		write!(out, ": ")?;
+
+		fmt_value_types(results, self, out)
+	}
+
+	pub(crate) fn fmt_export_type(
+		&mut self,
+		r#type: &ExportType,
+		out: &mut dyn Write,
+	) -> Result<()> {
+		if !self.typed || matches!(r#type, ExportType::Unknown) {
+			return Ok(());
+		}
+
+		write!(out, " :: ")?;
+
+		r#type.print(self, out)
+	}
+}
@@ -2,60 +2,91 @@ use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
 pub use data_flow_graph::mvp::{
 	DataNew, ExtendType, IntegerBinaryOperator, IntegerCompareOperator, IntegerType,
-	IntegerUnaryOperator, LoadType, MemoryNew, NumberBinaryOperator, NumberCompareOperator,
-	NumberType, NumberUnaryOperator,
+	IntegerUnaryOperator, LaneType, LoadType, MemoryNew, NumberBinaryOperator,
+	NumberCompareOperator, NumberType, NumberUnaryOperator, V128BinaryOperator, V128ReduceOperator,
+	V128UnaryOperator,
 };
+pub use data_flow_graph::nested::ValueType;
 
-use crate::statement::{FastDefine, Sequence};
+use crate::statement::{ExportType, Sequence};
 
+#[derive(Clone, Default, Debug)]
+pub struct Signature {
+	pub arguments: Vec<ValueType>,
+	pub results: Vec<ValueType>,
+}
+
+#[derive(Debug)]
 pub struct Function {
 	pub arguments: Vec<Name>,
 	pub code: Sequence,
 	pub returns: Vec<Local>,
+	pub signature: Signature,
+	pub tail: bool,
 }
 
+#[derive(Debug)]
 pub struct Scoped {
-	pub locals: Vec<FastDefine>,
+	pub locals: Sequence,
 	pub function: Function,
 }
 
+#[derive(Debug)]
 pub struct Match {
 	pub condition: Expression,
 	pub branches: Vec<Expression>,
 }
 
+#[derive(Debug)]
 pub struct Import {
 	pub environment: Expression,
 	pub namespace: Arc<str>,
 	pub identifier: Arc<str>,
+	pub r#type: ExportType,
 }
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct Name {
 	pub id: u32,
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum Local {
 	Fast { name: Name },
 	Slow { table: Name, index: u16 },
 }
 
+#[derive(Debug)]
 pub struct Call {
 	pub function: Expression,
 	pub arguments: Vec<Expression>,
 }
 
+#[derive(Debug)]
 pub struct RefIsNull {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
+pub struct FunctionNew {
+	pub source: Expression,
+	pub signature: Arc<str>,
+}
+
+#[derive(Debug)]
+pub struct FunctionCheck {
+	pub source: Expression,
+	pub signature: Arc<str>,
+}
+
+#[derive(Debug)]
 pub struct IntegerUnaryOperation {
 	pub source: Expression,
 	pub r#type: IntegerType,
 	pub operator: IntegerUnaryOperator,
 }
 
+#[derive(Debug)]
 pub struct IntegerBinaryOperation {
 	pub lhs: Expression,
 	pub rhs: Expression,
@@ -63,6 +94,7 @@ pub struct IntegerBinaryOperation {
 	pub operator: IntegerBinaryOperator,
 }
 
+#[derive(Debug)]
 pub struct IntegerCompareOperation {
 	pub lhs: Expression,
 	pub rhs: Expression,
@@ -70,19 +102,23 @@ pub struct IntegerCompareOperation {
 	pub operator: IntegerCompareOperator,
 }
 
+#[derive(Debug)]
 pub struct IntegerNarrow {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct IntegerWiden {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct IntegerExtend {
 	pub source: Expression,
 	pub r#type: ExtendType,
 }
 
+#[derive(Debug)]
 pub struct IntegerConvertToNumber {
 	pub source: Expression,
 	pub signed: bool,
@@ -90,17 +126,20 @@ pub struct IntegerConvertToNumber {
 	pub from: IntegerType,
 }
 
+#[derive(Debug)]
 pub struct IntegerTransmuteToNumber {
 	pub source: Expression,
 	pub from: IntegerType,
 }
 
+#[derive(Debug)]
 pub struct NumberUnaryOperation {
 	pub source: Expression,
 	pub r#type: NumberType,
 	pub operator: NumberUnaryOperator,
 }
 
+#[derive(Debug)]
 pub struct NumberBinaryOperation {
 	pub lhs: Expression,
 	pub rhs: Expression,
@@ -108,6 +147,7 @@ pub struct NumberBinaryOperation {
 	pub operator: NumberBinaryOperator,
 }
 
+#[derive(Debug)]
 pub struct NumberCompareOperation {
 	pub lhs: Expression,
 	pub rhs: Expression,
@@ -115,14 +155,17 @@ pub struct NumberCompareOperation {
 	pub operator: NumberCompareOperator,
 }
 
+#[derive(Debug)]
 pub struct NumberNarrow {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct NumberWiden {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct NumberTruncateToInteger {
 	pub source: Expression,
 	pub signed: bool,
@@ -131,62 +174,148 @@ pub struct NumberTruncateToInteger {
 	pub from: NumberType,
 }
 
+#[derive(Debug)]
 pub struct NumberTransmuteToInteger {
 	pub source: Expression,
 	pub from: NumberType,
 }
 
+#[derive(Debug)]
+pub struct V128UnaryOperation {
+	pub source: Expression,
+	pub operator: V128UnaryOperator,
+}
+
+#[derive(Debug)]
+pub struct V128BinaryOperation {
+	pub lhs: Expression,
+	pub rhs: Expression,
+	pub operator: V128BinaryOperator,
+}
+
+#[derive(Debug)]
+pub struct V128ReduceOperation {
+	pub source: Expression,
+	pub operator: V128ReduceOperator,
+}
+
+#[derive(Debug)]
+pub struct V128Splat {
+	pub source: Expression,
+	pub r#type: LaneType,
+}
+
+#[derive(Debug)]
+pub struct V128ExtractLane {
+	pub source: Expression,
+	pub r#type: LaneType,
+	pub lane: u8,
+	pub signed: bool,
+}
+
+#[derive(Debug)]
+pub struct V128ReplaceLane {
+	pub lhs: Expression,
+	pub rhs: Expression,
+	pub r#type: LaneType,
+	pub lane: u8,
+}
+
+#[derive(Debug)]
+pub struct V128Shuffle {
+	pub lhs: Expression,
+	pub rhs: Expression,
+	pub lanes: [u8; 16],
+}
+
+#[derive(Debug)]
+pub struct V128BitSelect {
+	pub lhs: Expression,
+	pub rhs: Expression,
+	pub condition: Expression,
+}
+
+#[derive(Debug)]
 pub struct Location {
 	pub reference: Expression,
 	pub offset: Expression,
 }
 
+#[derive(Debug)]
 pub struct GlobalNew {
 	pub initializer: Expression,
 }
 
+#[derive(Debug)]
 pub struct GlobalGet {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct TableNew {
 	pub initializer: Expression,
 	pub minimum: u32,
 	pub maximum: u32,
 }
 
+#[derive(Debug)]
 pub struct TableGet {
 	pub source: Location,
 }
 
+#[derive(Debug)]
 pub struct TableSize {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct TableGrow {
 	pub destination: Expression,
 	pub initializer: Expression,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
 pub struct ElementsNew {
 	pub content: Vec<Expression>,
 }
 
+#[derive(Debug)]
 pub struct MemoryLoad {
 	pub source: Location,
 	pub r#type: LoadType,
 }
 
+#[derive(Debug)]
 pub struct MemorySize {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct MemoryGrow {
 	pub destination: Expression,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
+pub struct ExceptionNew {
+	pub tag: Expression,
+	pub arguments: Vec<Expression>,
+}
+
+#[derive(Debug)]
+pub struct ExceptionTest {
+	pub source: Expression,
+	pub tag: Expression,
+}
+
+#[derive(Debug)]
+pub struct ExceptionGet {
+	pub source: Expression,
+	pub index: u16,
+}
+
+#[derive(Debug)]
 pub enum Expression {
 	Function(Box<Function>),
 	Scoped(Box<Scoped>),
@@ -202,10 +331,13 @@ pub enum Expression {
 	I64(i64),
 	F32(f32),
 	F64(f64),
+	V128(u128),
 
 	Call(Box<Call>),
 
 	RefIsNull(Box<RefIsNull>),
+	FunctionNew(Box<FunctionNew>),
+	FunctionCheck(Box<FunctionCheck>),
 
 	IntegerUnaryOperation(Box<IntegerUnaryOperation>),
 	IntegerBinaryOperation(Box<IntegerBinaryOperation>),
@@ -224,6 +356,15 @@ pub enum Expression {
 	NumberTruncateToInteger(Box<NumberTruncateToInteger>),
 	NumberTransmuteToInteger(Box<NumberTransmuteToInteger>),
 
+	V128UnaryOperation(Box<V128UnaryOperation>),
+	V128BinaryOperation(Box<V128BinaryOperation>),
+	V128ReduceOperation(Box<V128ReduceOperation>),
+	V128Splat(Box<V128Splat>),
+	V128ExtractLane(Box<V128ExtractLane>),
+	V128ReplaceLane(Box<V128ReplaceLane>),
+	V128Shuffle(Box<V128Shuffle>),
+	V128BitSelect(Box<V128BitSelect>),
+
 	GlobalNew(Box<GlobalNew>),
 	GlobalGet(Box<GlobalGet>),
 
@@ -240,4 +381,9 @@ pub enum Expression {
 	MemoryGrow(Box<MemoryGrow>),
 
 	DataNew(DataNew),
+
+	TagNew,
+	ExceptionNew(Box<ExceptionNew>),
+	ExceptionTest(Box<ExceptionTest>),
+	ExceptionGet(Box<ExceptionGet>),
 }
@@ -3,18 +3,21 @@
 extern crate alloc;
 
 pub mod expression;
+pub mod names_finder;
 pub mod statement;
 pub mod visitor;
 
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 
 use self::{
 	expression::Name,
 	statement::{Export, Sequence},
 };
 
+#[derive(Debug)]
 pub struct LuauTree {
 	pub environment: Name,
 	pub code: Sequence,
 	pub exports: Vec<Export>,
+	pub names: Vec<(Name, Arc<str>)>,
 }
@@ -0,0 +1,1397 @@
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+use crate::{
+	LuauTree,
+	expression::{
+		DataNew, ElementsNew, ExceptionGet, ExceptionNew, ExceptionTest, Expression, ExtendType,
+		Function, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, IntegerBinaryOperation,
+		IntegerBinaryOperator, IntegerCompareOperation, IntegerCompareOperator,
+		IntegerConvertToNumber, IntegerExtend, IntegerNarrow, IntegerTransmuteToNumber,
+		IntegerType, IntegerUnaryOperation, IntegerUnaryOperator, IntegerWiden, LaneType, LoadType,
+		MemoryGrow, MemoryLoad, MemoryNew, MemorySize, NumberBinaryOperation, NumberBinaryOperator,
+		NumberCompareOperation, NumberCompareOperator, NumberNarrow, NumberTransmuteToInteger,
+		NumberTruncateToInteger, NumberType, NumberUnaryOperation, NumberUnaryOperator,
+		NumberWiden, TableGet, TableGrow, TableNew, TableSize, V128BinaryOperation,
+		V128BinaryOperator, V128BitSelect, V128ExtractLane, V128ReduceOperation,
+		V128ReduceOperator, V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
+		V128UnaryOperator,
+	},
+	statement::{
+		Call, DataDrop, ElementsDrop, Export, GlobalSet, MemoryCopy, MemoryFill, MemoryInit,
+		MemoryStore, Statement, StoreType, TableCopy, TableFill, TableInit, TableSet, Throw,
+	},
+	visitor::Visitor,
+};
+
+pub trait NeedsName {
+	fn needs_name(&self) -> &'static str;
+}
+
+impl NeedsName for Function {
+	fn needs_name(&self) -> &'static str {
+		if self.tail { "tail_function" } else { "" }
+	}
+}
+
+impl NeedsName for i32 {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for i64 {
+	fn needs_name(&self) -> &'static str {
+		"create_i64_from_u32"
+	}
+}
+
+impl NeedsName for f32 {
+	fn needs_name(&self) -> &'static str {
+		if self.is_finite() {
+			"vector_create"
+		} else {
+			"transmute_i32_to_f32"
+		}
+	}
+}
+
+impl NeedsName for f64 {
+	fn needs_name(&self) -> &'static str {
+		if self.is_finite() {
+			""
+		} else {
+			"create_f64_from_u32"
+		}
+	}
+}
+
+impl NeedsName for u128 {
+	fn needs_name(&self) -> &'static str {
+		"create_v128_from_u32"
+	}
+}
+
+impl NeedsName for IntegerUnaryOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			r#type, operator, ..
+		} = *self;
+
+		match (r#type, operator) {
+			(IntegerType::I32, IntegerUnaryOperator::CountOnes) => "count_ones_i32",
+			(IntegerType::I32, IntegerUnaryOperator::LeadingZeroes) => "leading_zeroes_i32",
+			(IntegerType::I32, IntegerUnaryOperator::TrailingZeroes) => "trailing_zeroes_i32",
+			(IntegerType::I64, IntegerUnaryOperator::CountOnes) => "count_ones_i64",
+			(IntegerType::I64, IntegerUnaryOperator::LeadingZeroes) => "leading_zeroes_i64",
+			(IntegerType::I64, IntegerUnaryOperator::TrailingZeroes) => "trailing_zeroes_i64",
+		}
+	}
+}
+
+impl NeedsName for IntegerBinaryOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			r#type, operator, ..
+		} = *self;
+
+		match (r#type, operator) {
+			(IntegerType::I32, IntegerBinaryOperator::Add) => "add_i32",
+			(IntegerType::I32, IntegerBinaryOperator::Subtract) => "subtract_i32",
+			(IntegerType::I32, IntegerBinaryOperator::Multiply) => "multiply_i32",
+			(IntegerType::I32, IntegerBinaryOperator::Divide { signed: true }) => "divide_s32",
+			(IntegerType::I32, IntegerBinaryOperator::Divide { signed: false }) => "divide_u32",
+			(IntegerType::I32, IntegerBinaryOperator::Remainder { signed: true }) => {
+				"remainder_s32"
+			}
+			(IntegerType::I32, IntegerBinaryOperator::Remainder { signed: false }) => {
+				"remainder_u32"
+			}
+			(IntegerType::I32, IntegerBinaryOperator::And) => "and_i32",
+			(IntegerType::I32, IntegerBinaryOperator::Or) => "or_i32",
+			(IntegerType::I32, IntegerBinaryOperator::ExclusiveOr) => "exclusive_or_i32",
+			(IntegerType::I32, IntegerBinaryOperator::ShiftLeft) => "shift_left_i32",
+			(IntegerType::I32, IntegerBinaryOperator::ShiftRight { signed: true }) => {
+				"shift_right_s32"
+			}
+			(IntegerType::I32, IntegerBinaryOperator::ShiftRight { signed: false }) => {
+				"shift_right_u32"
+			}
+			(IntegerType::I32, IntegerBinaryOperator::RotateLeft) => "rotate_left_i32",
+			(IntegerType::I32, IntegerBinaryOperator::RotateRight) => "rotate_right_i32",
+			(IntegerType::I64, IntegerBinaryOperator::Add) => "add_i64",
+			(IntegerType::I64, IntegerBinaryOperator::Subtract) => "subtract_i64",
+			(IntegerType::I64, IntegerBinaryOperator::Multiply) => "multiply_i64",
+			(IntegerType::I64, IntegerBinaryOperator::Divide { signed: true }) => "divide_s64",
+			(IntegerType::I64, IntegerBinaryOperator::Divide { signed: false }) => "divide_u64",
+			(IntegerType::I64, IntegerBinaryOperator::Remainder { signed: true }) => {
+				"remainder_s64"
+			}
+			(IntegerType::I64, IntegerBinaryOperator::Remainder { signed: false }) => {
+				"remainder_u64"
+			}
+			(IntegerType::I64, IntegerBinaryOperator::And) => "and_i64",
+			(IntegerType::I64, IntegerBinaryOperator::Or) => "or_i64",
+			(IntegerType::I64, IntegerBinaryOperator::ExclusiveOr) => "exclusive_or_i64",
+			(IntegerType::I64, IntegerBinaryOperator::ShiftLeft) => "shift_left_i64",
+			(IntegerType::I64, IntegerBinaryOperator::ShiftRight { signed: true }) => {
+				"shift_right_s64"
+			}
+			(IntegerType::I64, IntegerBinaryOperator::ShiftRight { signed: false }) => {
+				"shift_right_u64"
+			}
+			(IntegerType::I64, IntegerBinaryOperator::RotateLeft) => "rotate_left_i64",
+			(IntegerType::I64, IntegerBinaryOperator::RotateRight) => "rotate_right_i64",
+		}
+	}
+}
+
+impl NeedsName for IntegerCompareOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			r#type, operator, ..
+		} = *self;
+
+		match (r#type, operator) {
+			(IntegerType::I32, IntegerCompareOperator::Equal) => "equal_i32",
+			(IntegerType::I32, IntegerCompareOperator::NotEqual) => "not_equal_i32",
+			(IntegerType::I32, IntegerCompareOperator::LessThan { signed: true }) => {
+				"less_than_s32"
+			}
+			(IntegerType::I32, IntegerCompareOperator::LessThan { signed: false }) => {
+				"less_than_u32"
+			}
+			(IntegerType::I32, IntegerCompareOperator::GreaterThan { signed: true }) => {
+				"greater_than_s32"
+			}
+			(IntegerType::I32, IntegerCompareOperator::GreaterThan { signed: false }) => {
+				"greater_than_u32"
+			}
+			(IntegerType::I32, IntegerCompareOperator::LessThanEqual { signed: true }) => {
+				"less_than_equal_s32"
+			}
+			(IntegerType::I32, IntegerCompareOperator::LessThanEqual { signed: false }) => {
+				"less_than_equal_u32"
+			}
+			(IntegerType::I32, IntegerCompareOperator::GreaterThanEqual { signed: true }) => {
+				"greater_than_equal_s32"
+			}
+			(IntegerType::I32, IntegerCompareOperator::GreaterThanEqual { signed: false }) => {
+				"greater_than_equal_u32"
+			}
+			(IntegerType::I64, IntegerCompareOperator::Equal) => "equal_i64",
+			(IntegerType::I64, IntegerCompareOperator::NotEqual) => "not_equal_i64",
+			(IntegerType::I64, IntegerCompareOperator::LessThan { signed: true }) => {
+				"less_than_s64"
+			}
+			(IntegerType::I64, IntegerCompareOperator::LessThan { signed: false }) => {
+				"less_than_u64"
+			}
+			(IntegerType::I64, IntegerCompareOperator::GreaterThan { signed: true }) => {
+				"greater_than_s64"
+			}
+			(IntegerType::I64, IntegerCompareOperator::GreaterThan { signed: false }) => {
+				"greater_than_u64"
+			}
+			(IntegerType::I64, IntegerCompareOperator::LessThanEqual { signed: true }) => {
+				"less_than_equal_s64"
+			}
+			(IntegerType::I64, IntegerCompareOperator::LessThanEqual { signed: false }) => {
+				"less_than_equal_u64"
+			}
+			(IntegerType::I64, IntegerCompareOperator::GreaterThanEqual { signed: true }) => {
+				"greater_than_equal_s64"
+			}
+			(IntegerType::I64, IntegerCompareOperator::GreaterThanEqual { signed: false }) => {
+				"greater_than_equal_u64"
+			}
+		}
+	}
+}
+
+impl NeedsName for IntegerNarrow {
+	fn needs_name(&self) -> &'static str {
+		"narrow_i64"
+	}
+}
+
+impl NeedsName for IntegerWiden {
+	fn needs_name(&self) -> &'static str {
+		"widen_i32"
+	}
+}
+
+impl NeedsName for IntegerExtend {
+	fn needs_name(&self) -> &'static str {
+		let Self { r#type, .. } = *self;
+
+		match r#type {
+			ExtendType::I32_S8 => "extend_s8_to_i32",
+			ExtendType::I32_S16 => "extend_s16_to_i32",
+			ExtendType::I64_S8 => "extend_s8_to_i64",
+			ExtendType::I64_S16 => "extend_s16_to_i64",
+			ExtendType::I64_S32 => "extend_s32_to_i64",
+		}
+	}
+}
+
+impl NeedsName for IntegerConvertToNumber {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			signed, to, from, ..
+		} = *self;
+
+		match (from, to, signed) {
+			(IntegerType::I32, NumberType::F32, true) => "convert_s32_to_f32",
+			(IntegerType::I32, NumberType::F32, false) => "convert_u32_to_f32",
+			(IntegerType::I32, NumberType::F64, true) => "convert_s32_to_f64",
+			(IntegerType::I32, NumberType::F64, false) => "convert_u32_to_f64",
+			(IntegerType::I64, NumberType::F32, true) => "convert_s64_to_f32",
+			(IntegerType::I64, NumberType::F32, false) => "convert_u64_to_f32",
+			(IntegerType::I64, NumberType::F64, true) => "convert_s64_to_f64",
+			(IntegerType::I64, NumberType::F64, false) => "convert_u64_to_f64",
+		}
+	}
+}
+
+impl NeedsName for IntegerTransmuteToNumber {
+	fn needs_name(&self) -> &'static str {
+		let Self { from, .. } = *self;
+
+		match from {
+			IntegerType::I32 => "transmute_i32_to_f32",
+			IntegerType::I64 => "transmute_i64_to_f64",
+		}
+	}
+}
+
+impl NeedsName for NumberUnaryOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			r#type, operator, ..
+		} = *self;
+
+		match (r#type, operator) {
+			(NumberType::F32, NumberUnaryOperator::Absolute) => "absolute_f32",
+			(NumberType::F32, NumberUnaryOperator::Negate) => "negate_f32",
+			(NumberType::F32, NumberUnaryOperator::SquareRoot) => "square_root_f32",
+			(NumberType::F32, NumberUnaryOperator::RoundUp) => "round_up_f32",
+			(NumberType::F32, NumberUnaryOperator::RoundDown) => "round_down_f32",
+			(NumberType::F32, NumberUnaryOperator::Truncate) => "truncate_f32",
+			(NumberType::F32, NumberUnaryOperator::Nearest) => "nearest_f32",
+			(NumberType::F64, NumberUnaryOperator::Absolute) => "absolute_f64",
+			(NumberType::F64, NumberUnaryOperator::Negate) => "negate_f64",
+			(NumberType::F64, NumberUnaryOperator::SquareRoot) => "square_root_f64",
+			(NumberType::F64, NumberUnaryOperator::RoundUp) => "round_up_f64",
+			(NumberType::F64, NumberUnaryOperator::RoundDown) => "round_down_f64",
+			(NumberType::F64, NumberUnaryOperator::Truncate) => "truncate_f64",
+			(NumberType::F64, NumberUnaryOperator::Nearest) => "nearest_f64",
+		}
+	}
+}
+
+impl NeedsName for NumberBinaryOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			r#type, operator, ..
+		} = *self;
+
+		match (r#type, operator) {
+			(NumberType::F32, NumberBinaryOperator::Add) => "add_f32",
+			(NumberType::F32, NumberBinaryOperator::Subtract) => "subtract_f32",
+			(NumberType::F32, NumberBinaryOperator::Multiply) => "multiply_f32",
+			(NumberType::F32, NumberBinaryOperator::Divide) => "divide_f32",
+			(NumberType::F32, NumberBinaryOperator::Minimum) => "minimum_f32",
+			(NumberType::F32, NumberBinaryOperator::Maximum) => "maximum_f32",
+			(NumberType::F32, NumberBinaryOperator::CopySign) => "copy_sign_f32",
+			(NumberType::F64, NumberBinaryOperator::Add) => "add_f64",
+			(NumberType::F64, NumberBinaryOperator::Subtract) => "subtract_f64",
+			(NumberType::F64, NumberBinaryOperator::Multiply) => "multiply_f64",
+			(NumberType::F64, NumberBinaryOperator::Divide) => "divide_f64",
+			(NumberType::F64, NumberBinaryOperator::Minimum) => "minimum_f64",
+			(NumberType::F64, NumberBinaryOperator::Maximum) => "maximum_f64",
+			(NumberType::F64, NumberBinaryOperator::CopySign) => "copy_sign_f64",
+		}
+	}
+}
+
+impl NeedsName for NumberCompareOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			r#type, operator, ..
+		} = *self;
+
+		match (r#type, operator) {
+			(NumberType::F32, NumberCompareOperator::Equal) => "equal_f32",
+			(NumberType::F32, NumberCompareOperator::NotEqual) => "not_equal_f32",
+			(NumberType::F32, NumberCompareOperator::LessThan) => "less_than_f32",
+			(NumberType::F32, NumberCompareOperator::GreaterThan) => "greater_than_f32",
+			(NumberType::F32, NumberCompareOperator::LessThanEqual) => "less_than_equal_f32",
+			(NumberType::F32, NumberCompareOperator::GreaterThanEqual) => "greater_than_equal_f32",
+			(NumberType::F64, NumberCompareOperator::Equal) => "equal_f64",
+			(NumberType::F64, NumberCompareOperator::NotEqual) => "not_equal_f64",
+			(NumberType::F64, NumberCompareOperator::LessThan) => "less_than_f64",
+			(NumberType::F64, NumberCompareOperator::GreaterThan) => "greater_than_f64",
+			(NumberType::F64, NumberCompareOperator::LessThanEqual) => "less_than_equal_f64",
+			(NumberType::F64, NumberCompareOperator::GreaterThanEqual) => "greater_than_equal_f64",
+		}
+	}
+}
+
+impl NeedsName for NumberNarrow {
+	fn needs_name(&self) -> &'static str {
+		"narrow_f64"
+	}
+}
+
+impl NeedsName for NumberWiden {
+	fn needs_name(&self) -> &'static str {
+		"widen_f32"
+	}
+}
+
+impl NeedsName for NumberTruncateToInteger {
+	fn needs_name(&self) -> &'static str {
+		let Self {
+			signed,
+			saturate,
+			to,
+			from,
+			..
+		} = *self;
+
+		match (from, to, signed, saturate) {
+			(NumberType::F32, IntegerType::I32, true, true) => "saturate_f32_to_s32",
+			(NumberType::F32, IntegerType::I32, true, false) => "truncate_f32_to_s32",
+			(NumberType::F32, IntegerType::I32, false, true) => "saturate_f32_to_u32",
+			(NumberType::F32, IntegerType::I32, false, false) => "truncate_f32_to_u32",
+			(NumberType::F32, IntegerType::I64, true, true) => "saturate_f32_to_s64",
+			(NumberType::F32, IntegerType::I64, true, false) => "truncate_f32_to_s64",
+			(NumberType::F32, IntegerType::I64, false, true) => "saturate_f32_to_u64",
+			(NumberType::F32, IntegerType::I64, false, false) => "truncate_f32_to_u64",
+			(NumberType::F64, IntegerType::I32, true, true) => "saturate_f64_to_s32",
+			(NumberType::F64, IntegerType::I32, true, false) => "truncate_f64_to_s32",
+			(NumberType::F64, IntegerType::I32, false, true) => "saturate_f64_to_u32",
+			(NumberType::F64, IntegerType::I32, false, false) => "truncate_f64_to_u32",
+			(NumberType::F64, IntegerType::I64, true, true) => "saturate_f64_to_s64",
+			(NumberType::F64, IntegerType::I64, true, false) => "truncate_f64_to_s64",
+			(NumberType::F64, IntegerType::I64, false, true) => "saturate_f64_to_u64",
+			(NumberType::F64, IntegerType::I64, false, false) => "truncate_f64_to_u64",
+		}
+	}
+}
+
+impl NeedsName for NumberTransmuteToInteger {
+	fn needs_name(&self) -> &'static str {
+		let Self { from, .. } = *self;
+
+		match from {
+			NumberType::F32 => "transmute_f32_to_i32",
+			NumberType::F64 => "transmute_f64_to_i64",
+		}
+	}
+}
+
+impl NeedsName for V128UnaryOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self { operator, .. } = *self;
+
+		match operator {
+			V128UnaryOperator::Not => "not_v128",
+			V128UnaryOperator::CountOnes => "count_ones_i8x16",
+			V128UnaryOperator::Absolute(LaneType::I8) => "absolute_i8x16",
+			V128UnaryOperator::Absolute(LaneType::I16) => "absolute_i16x8",
+			V128UnaryOperator::Absolute(LaneType::I32) => "absolute_i32x4",
+			V128UnaryOperator::Absolute(LaneType::I64) => "absolute_i64x2",
+			V128UnaryOperator::Absolute(LaneType::F32) => "absolute_f32x4",
+			V128UnaryOperator::Absolute(LaneType::F64) => "absolute_f64x2",
+			V128UnaryOperator::Negate(LaneType::I8) => "negate_i8x16",
+			V128UnaryOperator::Negate(LaneType::I16) => "negate_i16x8",
+			V128UnaryOperator::Negate(LaneType::I32) => "negate_i32x4",
+			V128UnaryOperator::Negate(LaneType::I64) => "negate_i64x2",
+			V128UnaryOperator::Negate(LaneType::F32) => "negate_f32x4",
+			V128UnaryOperator::Negate(LaneType::F64) => "negate_f64x2",
+			V128UnaryOperator::SquareRoot(LaneType::F32) => "square_root_f32x4",
+			V128UnaryOperator::SquareRoot(LaneType::F64) => "square_root_f64x2",
+			V128UnaryOperator::RoundUp(LaneType::F32) => "round_up_f32x4",
+			V128UnaryOperator::RoundUp(LaneType::F64) => "round_up_f64x2",
+			V128UnaryOperator::RoundDown(LaneType::F32) => "round_down_f32x4",
+			V128UnaryOperator::RoundDown(LaneType::F64) => "round_down_f64x2",
+			V128UnaryOperator::Truncate(LaneType::F32) => "truncate_f32x4",
+			V128UnaryOperator::Truncate(LaneType::F64) => "truncate_f64x2",
+			V128UnaryOperator::Nearest(LaneType::F32) => "nearest_f32x4",
+			V128UnaryOperator::Nearest(LaneType::F64) => "nearest_f64x2",
+			V128UnaryOperator::ExtendLow {
+				from: LaneType::I8,
+				signed: true,
+			} => "extend_low_s8x16",
+			V128UnaryOperator::ExtendLow {
+				from: LaneType::I8,
+				signed: false,
+			} => "extend_low_u8x16",
+			V128UnaryOperator::ExtendLow {
+				from: LaneType::I16,
+				signed: true,
+			} => "extend_low_s16x8",
+			V128UnaryOperator::ExtendLow {
+				from: LaneType::I16,
+				signed: false,
+			} => "extend_low_u16x8",
+			V128UnaryOperator::ExtendLow {
+				from: LaneType::I32,
+				signed: true,
+			} => "extend_low_s32x4",
+			V128UnaryOperator::ExtendLow {
+				from: LaneType::I32,
+				signed: false,
+			} => "extend_low_u32x4",
+			V128UnaryOperator::ExtendHigh {
+				from: LaneType::I8,
+				signed: true,
+			} => "extend_high_s8x16",
+			V128UnaryOperator::ExtendHigh {
+				from: LaneType::I8,
+				signed: false,
+			} => "extend_high_u8x16",
+			V128UnaryOperator::ExtendHigh {
+				from: LaneType::I16,
+				signed: true,
+			} => "extend_high_s16x8",
+			V128UnaryOperator::ExtendHigh {
+				from: LaneType::I16,
+				signed: false,
+			} => "extend_high_u16x8",
+			V128UnaryOperator::ExtendHigh {
+				from: LaneType::I32,
+				signed: true,
+			} => "extend_high_s32x4",
+			V128UnaryOperator::ExtendHigh {
+				from: LaneType::I32,
+				signed: false,
+			} => "extend_high_u32x4",
+			V128UnaryOperator::ExtendAddPairwise {
+				from: LaneType::I8,
+				signed: true,
+			} => "extend_add_pairwise_s8x16",
+			V128UnaryOperator::ExtendAddPairwise {
+				from: LaneType::I8,
+				signed: false,
+			} => "extend_add_pairwise_u8x16",
+			V128UnaryOperator::ExtendAddPairwise {
+				from: LaneType::I16,
+				signed: true,
+			} => "extend_add_pairwise_s16x8",
+			V128UnaryOperator::ExtendAddPairwise {
+				from: LaneType::I16,
+				signed: false,
+			} => "extend_add_pairwise_u16x8",
+			V128UnaryOperator::Saturate {
+				from: LaneType::F32,
+				signed: true,
+			} => "saturate_f32x4_to_s32x4",
+			V128UnaryOperator::Saturate {
+				from: LaneType::F32,
+				signed: false,
+			} => "saturate_f32x4_to_u32x4",
+			V128UnaryOperator::Saturate {
+				from: LaneType::F64,
+				signed: true,
+			} => "saturate_f64x2_to_s32x4",
+			V128UnaryOperator::Saturate {
+				from: LaneType::F64,
+				signed: false,
+			} => "saturate_f64x2_to_u32x4",
+			V128UnaryOperator::Convert {
+				to: LaneType::F32,
+				signed: true,
+			} => "convert_s32x4_to_f32x4",
+			V128UnaryOperator::Convert {
+				to: LaneType::F32,
+				signed: false,
+			} => "convert_u32x4_to_f32x4",
+			V128UnaryOperator::Convert {
+				to: LaneType::F64,
+				signed: true,
+			} => "convert_s32x4_to_f64x2",
+			V128UnaryOperator::Convert {
+				to: LaneType::F64,
+				signed: false,
+			} => "convert_u32x4_to_f64x2",
+			V128UnaryOperator::Narrow => "narrow_f64x2",
+			V128UnaryOperator::Widen => "widen_f32x4",
+
+			_ => unreachable!(),
+		}
+	}
+}
+
+impl NeedsName for V128BinaryOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self { operator, .. } = *self;
+
+		match operator {
+			V128BinaryOperator::And => "and_v128",
+			V128BinaryOperator::AndNot => "and_not_v128",
+			V128BinaryOperator::Or => "or_v128",
+			V128BinaryOperator::ExclusiveOr => "exclusive_or_v128",
+			V128BinaryOperator::Swizzle => "swizzle_i8x16",
+			V128BinaryOperator::DotProduct => "dot_product_s16x8",
+			V128BinaryOperator::MultiplyRoundSaturate => "multiply_round_saturate_s16x8",
+			V128BinaryOperator::Add(LaneType::I8) => "add_i8x16",
+			V128BinaryOperator::Add(LaneType::I16) => "add_i16x8",
+			V128BinaryOperator::Add(LaneType::I32) => "add_i32x4",
+			V128BinaryOperator::Add(LaneType::I64) => "add_i64x2",
+			V128BinaryOperator::Add(LaneType::F32) => "add_f32x4",
+			V128BinaryOperator::Add(LaneType::F64) => "add_f64x2",
+			V128BinaryOperator::Subtract(LaneType::I8) => "subtract_i8x16",
+			V128BinaryOperator::Subtract(LaneType::I16) => "subtract_i16x8",
+			V128BinaryOperator::Subtract(LaneType::I32) => "subtract_i32x4",
+			V128BinaryOperator::Subtract(LaneType::I64) => "subtract_i64x2",
+			V128BinaryOperator::Subtract(LaneType::F32) => "subtract_f32x4",
+			V128BinaryOperator::Subtract(LaneType::F64) => "subtract_f64x2",
+			V128BinaryOperator::Multiply(LaneType::I16) => "multiply_i16x8",
+			V128BinaryOperator::Multiply(LaneType::I32) => "multiply_i32x4",
+			V128BinaryOperator::Multiply(LaneType::I64) => "multiply_i64x2",
+			V128BinaryOperator::Multiply(LaneType::F32) => "multiply_f32x4",
+			V128BinaryOperator::Multiply(LaneType::F64) => "multiply_f64x2",
+			V128BinaryOperator::Divide(LaneType::F32) => "divide_f32x4",
+			V128BinaryOperator::Divide(LaneType::F64) => "divide_f64x2",
+			V128BinaryOperator::AddSaturate {
+				r#type: LaneType::I8,
+				signed: true,
+			} => "add_saturate_s8x16",
+			V128BinaryOperator::AddSaturate {
+				r#type: LaneType::I8,
+				signed: false,
+			} => "add_saturate_u8x16",
+			V128BinaryOperator::AddSaturate {
+				r#type: LaneType::I16,
+				signed: true,
+			} => "add_saturate_s16x8",
+			V128BinaryOperator::AddSaturate {
+				r#type: LaneType::I16,
+				signed: false,
+			} => "add_saturate_u16x8",
+			V128BinaryOperator::SubtractSaturate {
+				r#type: LaneType::I8,
+				signed: true,
+			} => "subtract_saturate_s8x16",
+			V128BinaryOperator::SubtractSaturate {
+				r#type: LaneType::I8,
+				signed: false,
+			} => "subtract_saturate_u8x16",
+			V128BinaryOperator::SubtractSaturate {
+				r#type: LaneType::I16,
+				signed: true,
+			} => "subtract_saturate_s16x8",
+			V128BinaryOperator::SubtractSaturate {
+				r#type: LaneType::I16,
+				signed: false,
+			} => "subtract_saturate_u16x8",
+			V128BinaryOperator::IntegerMinimum {
+				r#type: LaneType::I8,
+				signed: true,
+			} => "minimum_s8x16",
+			V128BinaryOperator::IntegerMinimum {
+				r#type: LaneType::I8,
+				signed: false,
+			} => "minimum_u8x16",
+			V128BinaryOperator::IntegerMinimum {
+				r#type: LaneType::I16,
+				signed: true,
+			} => "minimum_s16x8",
+			V128BinaryOperator::IntegerMinimum {
+				r#type: LaneType::I16,
+				signed: false,
+			} => "minimum_u16x8",
+			V128BinaryOperator::IntegerMinimum {
+				r#type: LaneType::I32,
+				signed: true,
+			} => "minimum_s32x4",
+			V128BinaryOperator::IntegerMinimum {
+				r#type: LaneType::I32,
+				signed: false,
+			} => "minimum_u32x4",
+			V128BinaryOperator::IntegerMaximum {
+				r#type: LaneType::I8,
+				signed: true,
+			} => "maximum_s8x16",
+			V128BinaryOperator::IntegerMaximum {
+				r#type: LaneType::I8,
+				signed: false,
+			} => "maximum_u8x16",
+			V128BinaryOperator::IntegerMaximum {
+				r#type: LaneType::I16,
+				signed: true,
+			} => "maximum_s16x8",
+			V128BinaryOperator::IntegerMaximum {
+				r#type: LaneType::I16,
+				signed: false,
+			} => "maximum_u16x8",
+			V128BinaryOperator::IntegerMaximum {
+				r#type: LaneType::I32,
+				signed: true,
+			} => "maximum_s32x4",
+			V128BinaryOperator::IntegerMaximum {
+				r#type: LaneType::I32,
+				signed: false,
+			} => "maximum_u32x4",
+			V128BinaryOperator::NumberMinimum(LaneType::F32) => "minimum_f32x4",
+			V128BinaryOperator::NumberMinimum(LaneType::F64) => "minimum_f64x2",
+			V128BinaryOperator::NumberMaximum(LaneType::F32) => "maximum_f32x4",
+			V128BinaryOperator::NumberMaximum(LaneType::F64) => "maximum_f64x2",
+			V128BinaryOperator::PseudoMinimum(LaneType::F32) => "pseudo_minimum_f32x4",
+			V128BinaryOperator::PseudoMinimum(LaneType::F64) => "pseudo_minimum_f64x2",
+			V128BinaryOperator::PseudoMaximum(LaneType::F32) => "pseudo_maximum_f32x4",
+			V128BinaryOperator::PseudoMaximum(LaneType::F64) => "pseudo_maximum_f64x2",
+			V128BinaryOperator::Average(LaneType::I8) => "average_u8x16",
+			V128BinaryOperator::Average(LaneType::I16) => "average_u16x8",
+			V128BinaryOperator::ShiftLeft(LaneType::I8) => "shift_left_i8x16",
+			V128BinaryOperator::ShiftLeft(LaneType::I16) => "shift_left_i16x8",
+			V128BinaryOperator::ShiftLeft(LaneType::I32) => "shift_left_i32x4",
+			V128BinaryOperator::ShiftLeft(LaneType::I64) => "shift_left_i64x2",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I8,
+				signed: true,
+			} => "shift_right_s8x16",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I8,
+				signed: false,
+			} => "shift_right_u8x16",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I16,
+				signed: true,
+			} => "shift_right_s16x8",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I16,
+				signed: false,
+			} => "shift_right_u16x8",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I32,
+				signed: true,
+			} => "shift_right_s32x4",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I32,
+				signed: false,
+			} => "shift_right_u32x4",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I64,
+				signed: true,
+			} => "shift_right_s64x2",
+			V128BinaryOperator::ShiftRight {
+				r#type: LaneType::I64,
+				signed: false,
+			} => "shift_right_u64x2",
+			V128BinaryOperator::ExtendMultiplyLow {
+				from: LaneType::I8,
+				signed: true,
+			} => "extend_multiply_low_s8x16",
+			V128BinaryOperator::ExtendMultiplyLow {
+				from: LaneType::I8,
+				signed: false,
+			} => "extend_multiply_low_u8x16",
+			V128BinaryOperator::ExtendMultiplyLow {
+				from: LaneType::I16,
+				signed: true,
+			} => "extend_multiply_low_s16x8",
+			V128BinaryOperator::ExtendMultiplyLow {
+				from: LaneType::I16,
+				signed: false,
+			} => "extend_multiply_low_u16x8",
+			V128BinaryOperator::ExtendMultiplyLow {
+				from: LaneType::I32,
+				signed: true,
+			} => "extend_multiply_low_s32x4",
+			V128BinaryOperator::ExtendMultiplyLow {
+				from: LaneType::I32,
+				signed: false,
+			} => "extend_multiply_low_u32x4",
+			V128BinaryOperator::ExtendMultiplyHigh {
+				from: LaneType::I8,
+				signed: true,
+			} => "extend_multiply_high_s8x16",
+			V128BinaryOperator::ExtendMultiplyHigh {
+				from: LaneType::I8,
+				signed: false,
+			} => "extend_multiply_high_u8x16",
+			V128BinaryOperator::ExtendMultiplyHigh {
+				from: LaneType::I16,
+				signed: true,
+			} => "extend_multiply_high_s16x8",
+			V128BinaryOperator::ExtendMultiplyHigh {
+				from: LaneType::I16,
+				signed: false,
+			} => "extend_multiply_high_u16x8",
+			V128BinaryOperator::ExtendMultiplyHigh {
+				from: LaneType::I32,
+				signed: true,
+			} => "extend_multiply_high_s32x4",
+			V128BinaryOperator::ExtendMultiplyHigh {
+				from: LaneType::I32,
+				signed: false,
+			} => "extend_multiply_high_u32x4",
+			V128BinaryOperator::Narrow {
+				from: LaneType::I16,
+				signed: true,
+			} => "narrow_s16x8",
+			V128BinaryOperator::Narrow {
+				from: LaneType::I16,
+				signed: false,
+			} => "narrow_u16x8",
+			V128BinaryOperator::Narrow {
+				from: LaneType::I32,
+				signed: true,
+			} => "narrow_s32x4",
+			V128BinaryOperator::Narrow {
+				from: LaneType::I32,
+				signed: false,
+			} => "narrow_u32x4",
+			V128BinaryOperator::IntegerCompare(LaneType::I8, IntegerCompareOperator::Equal) => {
+				"equal_i8x16"
+			}
+			V128BinaryOperator::IntegerCompare(LaneType::I8, IntegerCompareOperator::NotEqual) => {
+				"not_equal_i8x16"
+			}
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::LessThan { signed: true },
+			) => "less_than_s8x16",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::LessThan { signed: false },
+			) => "less_than_u8x16",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::GreaterThan { signed: true },
+			) => "greater_than_s8x16",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::GreaterThan { signed: false },
+			) => "greater_than_u8x16",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::LessThanEqual { signed: true },
+			) => "less_than_equal_s8x16",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::LessThanEqual { signed: false },
+			) => "less_than_equal_u8x16",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::GreaterThanEqual { signed: true },
+			) => "greater_than_equal_s8x16",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I8,
+				IntegerCompareOperator::GreaterThanEqual { signed: false },
+			) => "greater_than_equal_u8x16",
+			V128BinaryOperator::IntegerCompare(LaneType::I16, IntegerCompareOperator::Equal) => {
+				"equal_i16x8"
+			}
+			V128BinaryOperator::IntegerCompare(LaneType::I16, IntegerCompareOperator::NotEqual) => {
+				"not_equal_i16x8"
+			}
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::LessThan { signed: true },
+			) => "less_than_s16x8",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::LessThan { signed: false },
+			) => "less_than_u16x8",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::GreaterThan { signed: true },
+			) => "greater_than_s16x8",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::GreaterThan { signed: false },
+			) => "greater_than_u16x8",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::LessThanEqual { signed: true },
+			) => "less_than_equal_s16x8",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::LessThanEqual { signed: false },
+			) => "less_than_equal_u16x8",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::GreaterThanEqual { signed: true },
+			) => "greater_than_equal_s16x8",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I16,
+				IntegerCompareOperator::GreaterThanEqual { signed: false },
+			) => "greater_than_equal_u16x8",
+			V128BinaryOperator::IntegerCompare(LaneType::I32, IntegerCompareOperator::Equal) => {
+				"equal_i32x4"
+			}
+			V128BinaryOperator::IntegerCompare(LaneType::I32, IntegerCompareOperator::NotEqual) => {
+				"not_equal_i32x4"
+			}
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::LessThan { signed: true },
+			) => "less_than_s32x4",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::LessThan { signed: false },
+			) => "less_than_u32x4",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::GreaterThan { signed: true },
+			) => "greater_than_s32x4",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::GreaterThan { signed: false },
+			) => "greater_than_u32x4",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::LessThanEqual { signed: true },
+			) => "less_than_equal_s32x4",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::LessThanEqual { signed: false },
+			) => "less_than_equal_u32x4",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::GreaterThanEqual { signed: true },
+			) => "greater_than_equal_s32x4",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I32,
+				IntegerCompareOperator::GreaterThanEqual { signed: false },
+			) => "greater_than_equal_u32x4",
+			V128BinaryOperator::IntegerCompare(LaneType::I64, IntegerCompareOperator::Equal) => {
+				"equal_i64x2"
+			}
+			V128BinaryOperator::IntegerCompare(LaneType::I64, IntegerCompareOperator::NotEqual) => {
+				"not_equal_i64x2"
+			}
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I64,
+				IntegerCompareOperator::LessThan { signed: true },
+			) => "less_than_s64x2",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I64,
+				IntegerCompareOperator::GreaterThan { signed: true },
+			) => "greater_than_s64x2",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I64,
+				IntegerCompareOperator::LessThanEqual { signed: true },
+			) => "less_than_equal_s64x2",
+			V128BinaryOperator::IntegerCompare(
+				LaneType::I64,
+				IntegerCompareOperator::GreaterThanEqual { signed: true },
+			) => "greater_than_equal_s64x2",
+			V128BinaryOperator::NumberCompare(LaneType::F32, NumberCompareOperator::Equal) => {
+				"equal_f32x4"
+			}
+			V128BinaryOperator::NumberCompare(LaneType::F32, NumberCompareOperator::NotEqual) => {
+				"not_equal_f32x4"
+			}
+			V128BinaryOperator::NumberCompare(LaneType::F32, NumberCompareOperator::LessThan) => {
+				"less_than_f32x4"
+			}
+			V128BinaryOperator::NumberCompare(
+				LaneType::F32,
+				NumberCompareOperator::GreaterThan,
+			) => "greater_than_f32x4",
+			V128BinaryOperator::NumberCompare(
+				LaneType::F32,
+				NumberCompareOperator::LessThanEqual,
+			) => "less_than_equal_f32x4",
+			V128BinaryOperator::NumberCompare(
+				LaneType::F32,
+				NumberCompareOperator::GreaterThanEqual,
+			) => "greater_than_equal_f32x4",
+			V128BinaryOperator::NumberCompare(LaneType::F64, NumberCompareOperator::Equal) => {
+				"equal_f64x2"
+			}
+			V128BinaryOperator::NumberCompare(LaneType::F64, NumberCompareOperator::NotEqual) => {
+				"not_equal_f64x2"
+			}
+			V128BinaryOperator::NumberCompare(LaneType::F64, NumberCompareOperator::LessThan) => {
+				"less_than_f64x2"
+			}
+			V128BinaryOperator::NumberCompare(
+				LaneType::F64,
+				NumberCompareOperator::GreaterThan,
+			) => "greater_than_f64x2",
+			V128BinaryOperator::NumberCompare(
+				LaneType::F64,
+				NumberCompareOperator::LessThanEqual,
+			) => "less_than_equal_f64x2",
+			V128BinaryOperator::NumberCompare(
+				LaneType::F64,
+				NumberCompareOperator::GreaterThanEqual,
+			) => "greater_than_equal_f64x2",
+
+			_ => unreachable!(),
+		}
+	}
+}
+
+impl NeedsName for V128ReduceOperation {
+	fn needs_name(&self) -> &'static str {
+		let Self { operator, .. } = *self;
+
+		match operator {
+			V128ReduceOperator::AnyTrue => "any_true_v128",
+			V128ReduceOperator::AllTrue(LaneType::I8) => "all_true_i8x16",
+			V128ReduceOperator::AllTrue(LaneType::I16) => "all_true_i16x8",
+			V128ReduceOperator::AllTrue(LaneType::I32) => "all_true_i32x4",
+			V128ReduceOperator::AllTrue(LaneType::I64) => "all_true_i64x2",
+			V128ReduceOperator::BitMask(LaneType::I8) => "bit_mask_i8x16",
+			V128ReduceOperator::BitMask(LaneType::I16) => "bit_mask_i16x8",
+			V128ReduceOperator::BitMask(LaneType::I32) => "bit_mask_i32x4",
+			V128ReduceOperator::BitMask(LaneType::I64) => "bit_mask_i64x2",
+
+			_ => unreachable!(),
+		}
+	}
+}
+
+impl NeedsName for V128Splat {
+	fn needs_name(&self) -> &'static str {
+		let Self { r#type, .. } = *self;
+
+		match r#type {
+			LaneType::I8 => "splat_i8x16",
+			LaneType::I16 => "splat_i16x8",
+			LaneType::I32 => "splat_i32x4",
+			LaneType::I64 => "splat_i64x2",
+			LaneType::F32 => "splat_f32x4",
+			LaneType::F64 => "splat_f64x2",
+		}
+	}
+}
+
+impl NeedsName for V128ExtractLane {
+	fn needs_name(&self) -> &'static str {
+		let Self { r#type, signed, .. } = *self;
+
+		match (r#type, signed) {
+			(LaneType::I8, true) => "extract_lane_s8x16",
+			(LaneType::I8, false) => "extract_lane_u8x16",
+			(LaneType::I16, true) => "extract_lane_s16x8",
+			(LaneType::I16, false) => "extract_lane_u16x8",
+			(LaneType::I32, _) => "extract_lane_i32x4",
+			(LaneType::I64, _) => "extract_lane_i64x2",
+			(LaneType::F32, _) => "extract_lane_f32x4",
+			(LaneType::F64, _) => "extract_lane_f64x2",
+		}
+	}
+}
+
+impl NeedsName for V128ReplaceLane {
+	fn needs_name(&self) -> &'static str {
+		let Self { r#type, .. } = *self;
+
+		match r#type {
+			LaneType::I8 => "replace_lane_i8x16",
+			LaneType::I16 => "replace_lane_i16x8",
+			LaneType::I32 => "replace_lane_i32x4",
+			LaneType::I64 => "replace_lane_i64x2",
+			LaneType::F32 => "replace_lane_f32x4",
+			LaneType::F64 => "replace_lane_f64x2",
+		}
+	}
+}
+
+impl NeedsName for V128Shuffle {
+	fn needs_name(&self) -> &'static str {
+		"shuffle_i8x16"
+	}
+}
+
+impl NeedsName for V128BitSelect {
+	fn needs_name(&self) -> &'static str {
+		"bit_select_v128"
+	}
+}
+
+impl NeedsName for GlobalNew {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for GlobalGet {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for TableNew {
+	fn needs_name(&self) -> &'static str {
+		"table_new"
+	}
+}
+
+impl NeedsName for TableGet {
+	fn needs_name(&self) -> &'static str {
+		"table_get"
+	}
+}
+
+impl NeedsName for TableSize {
+	fn needs_name(&self) -> &'static str {
+		"table_size"
+	}
+}
+
+impl NeedsName for TableGrow {
+	fn needs_name(&self) -> &'static str {
+		"table_grow"
+	}
+}
+
+impl NeedsName for ElementsNew {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for MemoryNew {
+	fn needs_name(&self) -> &'static str {
+		"memory_new"
+	}
+}
+
+impl NeedsName for MemoryLoad {
+	fn needs_name(&self) -> &'static str {
+		let Self { r#type, .. } = *self;
+
+		match r#type {
+			LoadType::I32_S8 => "load_i32_from_s8",
+			LoadType::I32_U8 => "load_i32_from_u8",
+			LoadType::I32_S16 => "load_i32_from_s16",
+			LoadType::I32_U16 => "load_i32_from_u16",
+			LoadType::I32 => "load_i32",
+			LoadType::I64_S8 => "load_i64_from_s8",
+			LoadType::I64_U8 => "load_i64_from_u8",
+			LoadType::I64_S16 => "load_i64_from_s16",
+			LoadType::I64_U16 => "load_i64_from_u16",
+			LoadType::I64_S32 => "load_i64_from_s32",
+			LoadType::I64_U32 => "load_i64_from_u32",
+			LoadType::I64 => "load_i64",
+			LoadType::F32 => "load_f32",
+			LoadType::F64 => "load_f64",
+			LoadType::V128 => "load_v128",
+		}
+	}
+}
+
+impl NeedsName for MemorySize {
+	fn needs_name(&self) -> &'static str {
+		"memory_size"
+	}
+}
+
+impl NeedsName for FunctionNew {
+	fn needs_name(&self) -> &'static str {
+		"function_new"
+	}
+}
+
+impl NeedsName for FunctionCheck {
+	fn needs_name(&self) -> &'static str {
+		"function_check"
+	}
+}
+
+impl NeedsName for MemoryGrow {
+	fn needs_name(&self) -> &'static str {
+		"memory_grow"
+	}
+}
+
+impl NeedsName for DataNew {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for ExceptionNew {
+	fn needs_name(&self) -> &'static str {
+		"exception_new"
+	}
+}
+
+impl NeedsName for ExceptionTest {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for ExceptionGet {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for Expression {
+	fn needs_name(&self) -> &'static str {
+		match self {
+			Self::Function(function) => function.needs_name(),
+			Self::Scoped(scoped) => scoped.function.needs_name(),
+
+			Self::Import(_) => "import",
+
+			Self::Match(_)
+			| Self::Trap
+			| Self::Null
+			| Self::Local(_)
+			| Self::Call(_)
+			| Self::RefIsNull(_) => "",
+
+			Self::FunctionNew(function_new) => function_new.needs_name(),
+			Self::FunctionCheck(function_check) => function_check.needs_name(),
+			Self::I32(i32) => i32.needs_name(),
+			Self::I64(i64) => i64.needs_name(),
+			Self::F32(f32) => f32.needs_name(),
+			Self::F64(f64) => f64.needs_name(),
+			Self::V128(v128) => v128.needs_name(),
+
+			Self::IntegerUnaryOperation(integer_unary_operation) => {
+				integer_unary_operation.needs_name()
+			}
+			Self::IntegerBinaryOperation(integer_binary_operation) => {
+				integer_binary_operation.needs_name()
+			}
+			Self::IntegerCompareOperation(integer_compare_operation) => {
+				integer_compare_operation.needs_name()
+			}
+			Self::IntegerNarrow(integer_narrow) => integer_narrow.needs_name(),
+			Self::IntegerWiden(integer_widen) => integer_widen.needs_name(),
+			Self::IntegerExtend(integer_extend) => integer_extend.needs_name(),
+			Self::IntegerConvertToNumber(integer_convert_to_number) => {
+				integer_convert_to_number.needs_name()
+			}
+			Self::IntegerTransmuteToNumber(integer_transmute_to_number) => {
+				integer_transmute_to_number.needs_name()
+			}
+			Self::NumberUnaryOperation(number_unary_operation) => {
+				number_unary_operation.needs_name()
+			}
+			Self::NumberBinaryOperation(number_binary_operation) => {
+				number_binary_operation.needs_name()
+			}
+			Self::NumberCompareOperation(number_compare_operation) => {
+				number_compare_operation.needs_name()
+			}
+			Self::NumberNarrow(number_narrow) => number_narrow.needs_name(),
+			Self::NumberWiden(number_widen) => number_widen.needs_name(),
+			Self::NumberTruncateToInteger(number_truncate_to_integer) => {
+				number_truncate_to_integer.needs_name()
+			}
+			Self::NumberTransmuteToInteger(number_transmute_to_integer) => {
+				number_transmute_to_integer.needs_name()
+			}
+			Self::V128UnaryOperation(v128_unary_operation) => v128_unary_operation.needs_name(),
+			Self::V128BinaryOperation(v128_binary_operation) => v128_binary_operation.needs_name(),
+			Self::V128ReduceOperation(v128_reduce_operation) => v128_reduce_operation.needs_name(),
+			Self::V128Splat(v128_splat) => v128_splat.needs_name(),
+			Self::V128ExtractLane(v128_extract_lane) => v128_extract_lane.needs_name(),
+			Self::V128ReplaceLane(v128_replace_lane) => v128_replace_lane.needs_name(),
+			Self::V128Shuffle(v128_shuffle) => v128_shuffle.needs_name(),
+			Self::V128BitSelect(v128_bit_select) => v128_bit_select.needs_name(),
+			Self::GlobalNew(global_new) => global_new.needs_name(),
+			Self::GlobalGet(global_get) => global_get.needs_name(),
+			Self::TableNew(table_new) => table_new.needs_name(),
+			Self::TableGet(table_get) => table_get.needs_name(),
+			Self::TableSize(table_size) => table_size.needs_name(),
+			Self::TableGrow(table_grow) => table_grow.needs_name(),
+			Self::ElementsNew(elements_new) => elements_new.needs_name(),
+			Self::MemoryNew(memory_new) => memory_new.needs_name(),
+			Self::MemoryLoad(memory_load) => memory_load.needs_name(),
+			Self::MemorySize(memory_size) => memory_size.needs_name(),
+			Self::MemoryGrow(memory_grow) => memory_grow.needs_name(),
+			Self::DataNew(data_new) => data_new.needs_name(),
+			Self::TagNew => "",
+			Self::ExceptionNew(exception_new) => exception_new.needs_name(),
+			Self::ExceptionTest(exception_test) => exception_test.needs_name(),
+			Self::ExceptionGet(exception_get) => exception_get.needs_name(),
+		}
+	}
+}
+
+impl NeedsName for Call {
+	fn needs_name(&self) -> &'static str {
+		if self.tail {
+			"tail_call"
+		} else if self.catch {
+			"exception_call"
+		} else {
+			""
+		}
+	}
+}
+
+impl NeedsName for GlobalSet {
+	fn needs_name(&self) -> &'static str {
+		""
+	}
+}
+
+impl NeedsName for TableSet {
+	fn needs_name(&self) -> &'static str {
+		"table_set"
+	}
+}
+
+impl NeedsName for TableFill {
+	fn needs_name(&self) -> &'static str {
+		"table_fill"
+	}
+}
+
+impl NeedsName for TableCopy {
+	fn needs_name(&self) -> &'static str {
+		"table_copy"
+	}
+}
+
+impl NeedsName for TableInit {
+	fn needs_name(&self) -> &'static str {
+		"table_init"
+	}
+}
+
+impl NeedsName for ElementsDrop {
+	fn needs_name(&self) -> &'static str {
+		"elements_drop"
+	}
+}
+
+impl NeedsName for MemoryStore {
+	fn needs_name(&self) -> &'static str {
+		let Self { r#type, .. } = *self;
+
+		match r#type {
+			StoreType::I32_I8 => "store_i32_into_i8",
+			StoreType::I32_I16 => "store_i32_into_i16",
+			StoreType::I32 => "store_i32",
+			StoreType::I64_I8 => "store_i64_into_i8",
+			StoreType::I64_I16 => "store_i64_into_i16",
+			StoreType::I64_I32 => "store_i64_into_i32",
+			StoreType::I64 => "store_i64",
+			StoreType::F32 => "store_f32",
+			StoreType::F64 => "store_f64",
+			StoreType::V128 => "store_v128",
+		}
+	}
+}
+
+impl NeedsName for MemoryFill {
+	fn needs_name(&self) -> &'static str {
+		"memory_fill"
+	}
+}
+
+impl NeedsName for MemoryCopy {
+	fn needs_name(&self) -> &'static str {
+		"memory_copy"
+	}
+}
+
+impl NeedsName for MemoryInit {
+	fn needs_name(&self) -> &'static str {
+		"memory_init"
+	}
+}
+
+impl NeedsName for DataDrop {
+	fn needs_name(&self) -> &'static str {
+		"data_drop"
+	}
+}
+
+impl NeedsName for Throw {
+	fn needs_name(&self) -> &'static str {
+		"exception_throw"
+	}
+}
+
+impl NeedsName for Statement {
+	fn needs_name(&self) -> &'static str {
+		match self {
+			Self::Match(_)
+			| Self::Repeat(_)
+			| Self::FastDefine(_)
+			| Self::SlowDefine(_)
+			| Self::Assign(_)
+			| Self::AssignAll(_) => "",
+
+			Self::Call(call) => call.needs_name(),
+
+			Self::GlobalSet(global_set) => global_set.needs_name(),
+			Self::TableSet(table_set) => table_set.needs_name(),
+			Self::TableFill(table_fill) => table_fill.needs_name(),
+			Self::TableCopy(table_copy) => table_copy.needs_name(),
+			Self::TableInit(table_init) => table_init.needs_name(),
+			Self::ElementsDrop(elements_drop) => elements_drop.needs_name(),
+			Self::MemoryStore(memory_store) => memory_store.needs_name(),
+			Self::MemoryFill(memory_fill) => memory_fill.needs_name(),
+			Self::MemoryCopy(memory_copy) => memory_copy.needs_name(),
+			Self::MemoryInit(memory_init) => memory_init.needs_name(),
+			Self::DataDrop(data_drop) => data_drop.needs_name(),
+			Self::Throw(throw) => throw.needs_name(),
+		}
+	}
+}
+
+pub struct NamesFinder<'names> {
+	names: &'names mut Vec<&'static str>,
+}
+
+impl<'names> NamesFinder<'names> {
+	pub const fn new(names: &'names mut Vec<&'static str>) -> Self {
+		Self { names }
+	}
+
+	pub fn run(&mut self, tree: &LuauTree) {
+		tree.accept(self)
+			.continue_value()
+			.expect("names finder must not fail");
+	}
+
+	/// Finds the names needed by only the `statements` and `exports`, such
+	/// as those of a single chunk when the module is split.
+	pub fn run_partial(&mut self, statements: &[Statement], exports: &[Export]) {
+		statements
+			.iter()
+			.try_for_each(|statement| statement.accept(self))
+			.continue_value()
+			.expect("names finder must not fail");
+
+		exports
+			.iter()
+			.try_for_each(|export| export.accept(self))
+			.continue_value()
+			.expect("names finder must not fail");
+	}
+}
+
+impl Visitor for NamesFinder<'_> {
+	type Output = ();
+
+	fn visit_expression(&mut self, expression: &Expression) -> ControlFlow<Self::Output> {
+		let name = expression.needs_name();
+
+		if !name.is_empty() {
+			self.names.push(name);
+		}
+
+		ControlFlow::Continue(())
+	}
+
+	fn visit_statement(&mut self, statement: &Statement) -> ControlFlow<Self::Output> {
+		let name = statement.needs_name();
+
+		if !name.is_empty() {
+			self.names.push(name);
+		}
+
+		ControlFlow::Continue(())
+	}
+}
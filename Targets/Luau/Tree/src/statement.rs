@@ -1,11 +1,13 @@
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 
-use crate::expression::{Expression, Local, Location, Name};
+use crate::expression::{Expression, Local, Location, Name, Signature, ValueType};
 
 pub use data_flow_graph::mvp::StoreType;
 
+#[derive(Default, Debug)]
 pub struct Sequence {
 	pub list: Vec<Statement>,
+	pub positions: Vec<(usize, u32)>,
 }
 
 impl Sequence {
@@ -32,32 +34,38 @@ impl Sequence {
 	}
 }
 
+#[derive(Debug)]
 pub struct Match {
 	pub branches: Vec<Sequence>,
 	pub condition: Expression,
 }
 
+#[derive(Debug)]
 pub struct Repeat {
 	pub code: Sequence,
 	pub post: AssignAll,
 	pub condition: Expression,
 }
 
+#[derive(Debug)]
 pub struct FastDefine {
 	pub name: Name,
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct SlowDefine {
 	pub name: Name,
 	pub len: u32,
 }
 
+#[derive(Debug)]
 pub struct Assign {
 	pub local: Local,
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct AssignAll {
 	pub assignments: Vec<(Local, Local)>,
 }
@@ -80,72 +88,92 @@ impl AssignAll {
 	}
 }
 
+#[derive(Debug)]
 pub struct Call {
 	pub function: Expression,
 	pub results: Vec<Local>,
 	pub arguments: Vec<Expression>,
+	pub tail: bool,
+	pub catch: bool,
 }
 
+#[derive(Debug)]
 pub struct GlobalSet {
 	pub destination: Expression,
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct TableSet {
 	pub destination: Location,
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct TableFill {
 	pub destination: Location,
 	pub source: Expression,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
 pub struct TableCopy {
 	pub destination: Location,
 	pub source: Location,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
 pub struct TableInit {
 	pub destination: Location,
 	pub source: Location,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
 pub struct ElementsDrop {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
 pub struct MemoryStore {
 	pub destination: Location,
 	pub source: Expression,
 	pub r#type: StoreType,
 }
 
+#[derive(Debug)]
 pub struct MemoryFill {
 	pub destination: Location,
 	pub byte: Expression,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
 pub struct MemoryCopy {
 	pub destination: Location,
 	pub source: Location,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
 pub struct MemoryInit {
 	pub destination: Location,
 	pub source: Location,
 	pub size: Expression,
 }
 
+#[derive(Debug)]
 pub struct DataDrop {
 	pub source: Expression,
 }
 
+#[derive(Debug)]
+pub struct Throw {
+	pub source: Expression,
+}
+
+#[derive(Debug)]
 pub enum Statement {
 	Match(Box<Match>),
 	Repeat(Box<Repeat>),
@@ -172,9 +200,25 @@ pub enum Statement {
 	MemoryInit(Box<MemoryInit>),
 
 	DataDrop(Box<DataDrop>),
+
+	Throw(Box<Throw>),
+}
+
+/// The kind of an item passed into or out of a module, along with its
+/// type where known.
+#[derive(Clone, Debug)]
+pub enum ExportType {
+	Function(Signature),
+	Global(Option<ValueType>),
+	Table,
+	Memory,
+	Unknown,
 }
 
+#[derive(Debug)]
 pub struct Export {
+	pub instance: Option<Arc<str>>,
 	pub identifier: Arc<str>,
 	pub source: Expression,
+	pub r#type: ExportType,
 }
@@ -3,18 +3,20 @@ use core::ops::ControlFlow;
 use crate::{
 	LuauTree,
 	expression::{
-		Call as ExpressionCall, ElementsNew, Expression, Function, GlobalGet, GlobalNew, Import,
-		IntegerBinaryOperation, IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend,
-		IntegerNarrow, IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Location,
+		Call as ExpressionCall, ElementsNew, ExceptionGet, ExceptionNew, ExceptionTest, Expression,
+		Function, FunctionCheck, FunctionNew, GlobalGet, GlobalNew, Import, IntegerBinaryOperation,
+		IntegerCompareOperation, IntegerConvertToNumber, IntegerExtend, IntegerNarrow,
+		IntegerTransmuteToNumber, IntegerUnaryOperation, IntegerWiden, Location,
 		Match as ExpressionMatch, MemoryGrow, MemoryLoad, MemorySize, NumberBinaryOperation,
 		NumberCompareOperation, NumberNarrow, NumberTransmuteToInteger, NumberTruncateToInteger,
 		NumberUnaryOperation, NumberWiden, RefIsNull, Scoped, TableGet, TableGrow, TableNew,
-		TableSize,
+		TableSize, V128BinaryOperation, V128BitSelect, V128ExtractLane, V128ReduceOperation,
+		V128ReplaceLane, V128Shuffle, V128Splat, V128UnaryOperation,
 	},
 	statement::{
 		Assign, Call as StatementCall, DataDrop, ElementsDrop, Export, FastDefine, GlobalSet,
 		Match as StatementMatch, MemoryCopy, MemoryFill, MemoryInit, MemoryStore, Repeat, Sequence,
-		Statement, TableCopy, TableFill, TableInit, TableSet,
+		Statement, TableCopy, TableFill, TableInit, TableSet, Throw,
 	},
 };
 
@@ -32,6 +34,8 @@ impl Function {
 			arguments: _,
 			code,
 			returns: _,
+			signature: _,
+			tail: _,
 		} = self;
 
 		code.accept(visitor)
@@ -42,7 +46,7 @@ impl Scoped {
 	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
 		let Self { locals, function } = self;
 
-		locals.iter().try_for_each(|local| local.accept(visitor))?;
+		locals.accept(visitor)?;
 		function.accept(visitor)
 	}
 }
@@ -67,6 +71,7 @@ impl Import {
 			environment,
 			namespace: _,
 			identifier: _,
+			r#type: _,
 		} = self;
 
 		environment.accept(visitor)
@@ -95,6 +100,28 @@ impl RefIsNull {
 	}
 }
 
+impl FunctionNew {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		source.accept(visitor)
+	}
+}
+
+impl FunctionCheck {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			source,
+			signature: _,
+		} = self;
+
+		source.accept(visitor)
+	}
+}
+
 impl IntegerUnaryOperation {
 	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
 		let Self {
@@ -258,6 +285,99 @@ impl NumberTransmuteToInteger {
 	}
 }
 
+impl V128UnaryOperation {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		source.accept(visitor)
+	}
+}
+
+impl V128BinaryOperation {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			lhs,
+			rhs,
+			operator: _,
+		} = self;
+
+		lhs.accept(visitor)?;
+		rhs.accept(visitor)
+	}
+}
+
+impl V128ReduceOperation {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			source,
+			operator: _,
+		} = self;
+
+		source.accept(visitor)
+	}
+}
+
+impl V128Splat {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self { source, r#type: _ } = self;
+
+		source.accept(visitor)
+	}
+}
+
+impl V128ExtractLane {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			source,
+			r#type: _,
+			lane: _,
+			signed: _,
+		} = self;
+
+		source.accept(visitor)
+	}
+}
+
+impl V128ReplaceLane {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			lhs,
+			rhs,
+			r#type: _,
+			lane: _,
+		} = self;
+
+		lhs.accept(visitor)?;
+		rhs.accept(visitor)
+	}
+}
+
+impl V128Shuffle {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self { lhs, rhs, lanes: _ } = self;
+
+		lhs.accept(visitor)?;
+		rhs.accept(visitor)
+	}
+}
+
+impl V128BitSelect {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self {
+			lhs,
+			rhs,
+			condition,
+		} = self;
+
+		lhs.accept(visitor)?;
+		rhs.accept(visitor)?;
+		condition.accept(visitor)
+	}
+}
+
 impl Location {
 	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
 		let Self { reference, offset } = self;
@@ -360,6 +480,34 @@ impl MemoryGrow {
 	}
 }
 
+impl ExceptionNew {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self { tag, arguments } = self;
+
+		tag.accept(visitor)?;
+		arguments
+			.iter()
+			.try_for_each(|argument| argument.accept(visitor))
+	}
+}
+
+impl ExceptionTest {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self { source, tag } = self;
+
+		source.accept(visitor)?;
+		tag.accept(visitor)
+	}
+}
+
+impl ExceptionGet {
+	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self { source, index: _ } = self;
+
+		source.accept(visitor)
+	}
+}
+
 impl Expression {
 	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
 		visitor.visit_expression(self)?;
@@ -372,8 +520,10 @@ impl Expression {
 			| Self::I64(_)
 			| Self::F32(_)
 			| Self::F64(_)
+			| Self::V128(_)
 			| Self::MemoryNew(_)
-			| Self::DataNew(_) => ControlFlow::Continue(()),
+			| Self::DataNew(_)
+			| Self::TagNew => ControlFlow::Continue(()),
 
 			Self::Function(function) => function.accept(visitor),
 			Self::Scoped(scoped) => scoped.accept(visitor),
@@ -381,6 +531,8 @@ impl Expression {
 			Self::Import(import) => import.accept(visitor),
 			Self::Call(call) => call.accept(visitor),
 			Self::RefIsNull(ref_is_null) => ref_is_null.accept(visitor),
+			Self::FunctionNew(function_new) => function_new.accept(visitor),
+			Self::FunctionCheck(function_check) => function_check.accept(visitor),
 			Self::IntegerUnaryOperation(integer_unary_operation) => {
 				integer_unary_operation.accept(visitor)
 			}
@@ -416,6 +568,18 @@ impl Expression {
 			Self::NumberTransmuteToInteger(number_transmute_to_integer) => {
 				number_transmute_to_integer.accept(visitor)
 			}
+			Self::V128UnaryOperation(v128_unary_operation) => v128_unary_operation.accept(visitor),
+			Self::V128BinaryOperation(v128_binary_operation) => {
+				v128_binary_operation.accept(visitor)
+			}
+			Self::V128ReduceOperation(v128_reduce_operation) => {
+				v128_reduce_operation.accept(visitor)
+			}
+			Self::V128Splat(v128_splat) => v128_splat.accept(visitor),
+			Self::V128ExtractLane(v128_extract_lane) => v128_extract_lane.accept(visitor),
+			Self::V128ReplaceLane(v128_replace_lane) => v128_replace_lane.accept(visitor),
+			Self::V128Shuffle(v128_shuffle) => v128_shuffle.accept(visitor),
+			Self::V128BitSelect(v128_bit_select) => v128_bit_select.accept(visitor),
 			Self::GlobalNew(global_new) => global_new.accept(visitor),
 			Self::GlobalGet(global_get) => global_get.accept(visitor),
 			Self::TableNew(table_new) => table_new.accept(visitor),
@@ -426,13 +590,16 @@ impl Expression {
 			Self::MemoryLoad(memory_load) => memory_load.accept(visitor),
 			Self::MemorySize(memory_size) => memory_size.accept(visitor),
 			Self::MemoryGrow(memory_grow) => memory_grow.accept(visitor),
+			Self::ExceptionNew(exception_new) => exception_new.accept(visitor),
+			Self::ExceptionTest(exception_test) => exception_test.accept(visitor),
+			Self::ExceptionGet(exception_get) => exception_get.accept(visitor),
 		}
 	}
 }
 
 impl Sequence {
 	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
-		let Self { list } = self;
+		let Self { list, positions: _ } = self;
 
 		list.iter()
 			.try_for_each(|statement| statement.accept(visitor))
@@ -489,6 +656,8 @@ impl StatementCall {
 			function,
 			results: _,
 			arguments,
+			tail: _,
+			catch: _,
 		} = self;
 
 		function.accept(visitor)?;
@@ -635,8 +804,16 @@ impl DataDrop {
 	}
 }
 
-impl Statement {
+impl Throw {
 	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+		let Self { source } = self;
+
+		source.accept(visitor)
+	}
+}
+
+impl Statement {
+	pub fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
 		visitor.visit_statement(self)?;
 
 		match self {
@@ -658,15 +835,18 @@ impl Statement {
 			Self::MemoryCopy(memory_copy) => memory_copy.accept(visitor),
 			Self::MemoryInit(memory_init) => memory_init.accept(visitor),
 			Self::DataDrop(data_drop) => data_drop.accept(visitor),
+			Self::Throw(throw) => throw.accept(visitor),
 		}
 	}
 }
 
 impl Export {
-	fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
+	pub fn accept<T: Visitor>(&self, visitor: &mut T) -> ControlFlow<T::Output> {
 		let Self {
+			instance: _,
 			identifier: _,
 			source,
+			r#type: _,
 		} = self;
 
 		source.accept(visitor)
@@ -679,6 +859,7 @@ impl LuauTree {
 			environment: _,
 			code,
 			exports,
+			names: _,
 		} = self;
 
 		code.accept(visitor)?;